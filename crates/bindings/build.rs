@@ -58,6 +58,50 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("cargo:rustc-link-search={}", out_path.display());
     println!("cargo:rustc-link-lib=dylib={}", lib);
 
+    #[cfg(feature = "encrypted-app-ticket")]
+    {
+        // Only shipped with the full SDK, under public/steam/lib rather than redistributable_bin
+        let mut ticket_lib = "sdkencryptedappticket";
+        let mut ticket_path = sdk_loc.join("public").join("steam").join("lib");
+        if triple.contains("windows") {
+            if triple.contains("i686") {
+                ticket_path.push("win32");
+            } else {
+                ticket_lib = "sdkencryptedappticket64";
+                ticket_path.push("win64");
+            }
+        } else if triple.contains("linux") {
+            if triple.contains("i686") {
+                ticket_path.push("linux32");
+            } else {
+                ticket_path.push("linux64");
+            }
+        } else {
+            ticket_path.push("osx");
+        }
+
+        let files = if triple.contains("windows") {
+            vec![format!("{}.dll", ticket_lib), format!("{}.lib", ticket_lib)]
+        } else if triple.contains("darwin") {
+            vec![format!("lib{}.dylib", ticket_lib)]
+        } else {
+            vec![format!("lib{}.so", ticket_lib)]
+        };
+        for file in files {
+            let source = ticket_path.join(&file);
+            if !source.exists() {
+                panic!(
+                    "the encrypted-app-ticket feature needs {}, which only ships with the full \
+                     Steamworks SDK. Point STEAM_SDK_LOCATION at the SDK's sdk directory.",
+                    source.display()
+                );
+            }
+            fs::copy(source, out_path.join(file))?;
+        }
+
+        println!("cargo:rustc-link-lib=dylib={}", ticket_lib);
+    }
+
     #[cfg(feature = "rebuild-bindings")]
     {
         let target_os = if triple.contains("windows") {
@@ -85,6 +129,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             .clang_arg("-std=c++11")
             .clang_arg("-stdlib=libc++")
             .clang_arg(format!("-I{}", sdk_loc.join("public").display()))
+            // Only keep declarations from the SDK, not the platform's libc headers
+            .allowlist_file(".*[/\\\\]steam[/\\\\].*")
             .rustfmt_bindings(true)
             .default_enum_style(bindgen::EnumVariation::Rust {
                 non_exhaustive: true,
@@ -92,10 +138,39 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             .generate()
             .expect("Unable to generate bindings");
 
-        bindings
-            .write_to_file(binding_path)
-            .expect("Couldn't write bindings!");
+        let mut code = bindings.to_string();
+        if target_os != "windows" {
+            code = pack_interfaces(code);
+        }
+        fs::write(binding_path, code).expect("Couldn't write bindings!");
     }
 
     Ok(())
 }
+
+/// Interfaces declared inside the SDK's `VALVE_CALLBACK_PACK_SMALL` region
+#[cfg(feature = "rebuild-bindings")]
+const SMALL_PACK_INTERFACES: &[&str] = &[
+    "ISteamParties",
+    "ISteamUGC",
+    "ISteamInventory",
+    "ISteamVideo",
+];
+
+/// Packs the interfaces that only hold a vtable pointer to 4 bytes, bindgen drops the
+/// `#pragma pack` for them on some clang versions
+#[cfg(feature = "rebuild-bindings")]
+fn pack_interfaces(mut code: String) -> String {
+    for name in SMALL_PACK_INTERFACES {
+        let unpacked = format!(
+            "#[repr(C)]\n#[derive(Debug, Copy, Clone)]\npub struct {} {{",
+            name
+        );
+        let packed = format!(
+            "#[repr(C, packed(4))]\n#[derive(Debug, Copy, Clone)]\npub struct {} {{",
+            name
+        );
+        code = code.replace(&unpacked, &packed);
+    }
+    code
+}
@@ -1,5 +1,6 @@
 function smake.build()
     smake.macos()
+    smake.linux()
     smake.windows()
 end
 
@@ -8,6 +9,11 @@ function smake.windows()
     run('cargo build --target x86_64-pc-windows-gnu --features "rebuild-bindings"')
 end
 
+function smake.linux()
+    smake.clean()
+    run('cargo build --target x86_64-unknown-linux-gnu --features "rebuild-bindings"')
+end
+
 function smake.macos()
     smake.clean()
     run('cargo build --target x86_64-apple-darwin --features "rebuild-bindings"')
@@ -0,0 +1,40 @@
+//! Declarations from `steamencryptedappticket.h`, which lives in its own library
+//! (`sdkencryptedappticket`) so backend services can use it without the Steam client.
+
+use super::{uint32, uint8, AppId_t, CSteamID, RTime32};
+
+pub const k_nSteamEncryptedAppTicketSymmetricKeyLen: usize = 32;
+
+extern "C" {
+    pub fn SteamEncryptedAppTicket_BDecryptTicket(
+        rgubTicketEncrypted: *const uint8,
+        cubTicketEncrypted: uint32,
+        rgubTicketDecrypted: *mut uint8,
+        pcubTicketDecrypted: *mut uint32,
+        rgubKey: *const uint8,
+        cubKey: ::std::os::raw::c_int,
+    ) -> bool;
+    pub fn SteamEncryptedAppTicket_BIsTicketForApp(
+        rgubTicketDecrypted: *mut uint8,
+        cubTicketDecrypted: uint32,
+        nAppID: AppId_t,
+    ) -> bool;
+    pub fn SteamEncryptedAppTicket_GetTicketIssueTime(
+        rgubTicketDecrypted: *mut uint8,
+        cubTicketDecrypted: uint32,
+    ) -> RTime32;
+    pub fn SteamEncryptedAppTicket_GetTicketSteamID(
+        rgubTicketDecrypted: *mut uint8,
+        cubTicketDecrypted: uint32,
+        psteamID: *mut CSteamID,
+    );
+    pub fn SteamEncryptedAppTicket_GetTicketAppID(
+        rgubTicketDecrypted: *mut uint8,
+        cubTicketDecrypted: uint32,
+    ) -> AppId_t;
+    pub fn SteamEncryptedAppTicket_GetUserVariableData(
+        rgubTicketDecrypted: *mut uint8,
+        cubTicketDecrypted: uint32,
+        pcubUserData: *mut uint32,
+    ) -> *const uint8;
+}
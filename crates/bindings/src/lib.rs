@@ -1,6 +1,8 @@
 #![allow(non_camel_case_types)]
 #![allow(non_upper_case_globals)]
 #![allow(non_snake_case)]
+#![allow(clippy::missing_safety_doc)]
+#![allow(clippy::useless_transmute)]
 
 #[cfg(target_os = "windows")]
 include!("windows_bindings.rs");
@@ -10,3 +12,11 @@ include!("macos_bindings.rs");
 
 #[cfg(target_os = "linux")]
 include!("linux_bindings.rs");
+
+#[cfg(feature = "encrypted-app-ticket")]
+mod encrypted_app_ticket;
+#[cfg(feature = "encrypted-app-ticket")]
+pub use encrypted_app_ticket::*;
+
+mod web_api_ticket;
+pub use web_api_ticket::*;
@@ -7273,7 +7273,7 @@ pub enum ESteamPartyBeaconLocationData {
 }
 #[repr(C)]
 pub struct ISteamParties__bindgen_vtable(::std::os::raw::c_void);
-#[repr(C)]
+#[repr(C, packed(4))]
 #[derive(Debug, Copy, Clone)]
 pub struct ISteamParties {
     pub vtable_: *const ISteamParties__bindgen_vtable,
@@ -15808,7 +15808,7 @@ fn bindgen_test_layout_SteamUGCDetails_t() {
 }
 #[repr(C)]
 pub struct ISteamUGC__bindgen_vtable(::std::os::raw::c_void);
-#[repr(C)]
+#[repr(C, packed(4))]
 #[derive(Debug, Copy, Clone)]
 pub struct ISteamUGC {
     pub vtable_: *const ISteamUGC__bindgen_vtable,
@@ -18815,7 +18815,7 @@ pub type SteamInventoryUpdateHandle_t = uint64;
 pub const k_SteamInventoryUpdateHandleInvalid: SteamInventoryUpdateHandle_t = 18446744073709551615;
 #[repr(C)]
 pub struct ISteamInventory__bindgen_vtable(::std::os::raw::c_void);
-#[repr(C)]
+#[repr(C, packed(4))]
 #[derive(Debug, Copy, Clone)]
 pub struct ISteamInventory {
     pub vtable_: *const ISteamInventory__bindgen_vtable,
@@ -19152,7 +19152,7 @@ fn bindgen_test_layout_SteamInventoryRequestPricesResult_t() {
 }
 #[repr(C)]
 pub struct ISteamVideo__bindgen_vtable(::std::os::raw::c_void);
-#[repr(C)]
+#[repr(C, packed(4))]
 #[derive(Debug, Copy, Clone)]
 pub struct ISteamVideo {
     pub vtable_: *const ISteamVideo__bindgen_vtable,
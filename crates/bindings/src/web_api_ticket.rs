@@ -0,0 +1,30 @@
+//! Declarations for Web API tickets, which are newer than the SDK the bindings were
+//! generated from (`SteamUser023`, SDK 1.57).
+//!
+//! The callback layout is always available. The functions need the `web-api-ticket`
+//! feature and a new enough SDK in `STEAM_SDK_LOCATION`.
+
+#[cfg(feature = "web-api-ticket")]
+use super::ISteamUser;
+use super::{uint8, EResult, HAuthTicket};
+
+pub const GetTicketForWebApiResponse_t_k_iCallback: i32 = 168;
+pub const GetTicketForWebApiResponse_t_k_nCubTicketMaxLength: usize = 2560;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct GetTicketForWebApiResponse_t {
+    pub m_hAuthTicket: HAuthTicket,
+    pub m_eResult: EResult,
+    pub m_cubTicket: ::std::os::raw::c_int,
+    pub m_rgubTicket: [uint8; GetTicketForWebApiResponse_t_k_nCubTicketMaxLength],
+}
+
+#[cfg(feature = "web-api-ticket")]
+extern "C" {
+    pub fn SteamAPI_SteamUser_v023() -> *mut ISteamUser;
+    pub fn SteamAPI_ISteamUser_GetAuthTicketForWebApi(
+        self_: *mut ISteamUser,
+        pchIdentity: *const ::std::os::raw::c_char,
+    ) -> HAuthTicket;
+}
@@ -0,0 +1,92 @@
+//! The SDK packs callback structs to 4 bytes on Linux/macOS (`VALVE_CALLBACK_PACK_SMALL`)
+//! and to 8 bytes on Windows (`VALVE_CALLBACK_PACK_LARGE`). Every platform's bindings are
+//! included here so their layouts are checked no matter which host runs the tests.
+
+use std::mem::{align_of, size_of};
+
+macro_rules! platform_bindings {
+    ($name:ident, $path:literal) => {
+        #[allow(
+            non_camel_case_types,
+            non_upper_case_globals,
+            non_snake_case,
+            dead_code,
+            clashing_extern_declarations,
+            clippy::all
+        )]
+        mod $name {
+            include!($path);
+        }
+    };
+}
+
+platform_bindings!(linux, "../src/linux_bindings.rs");
+platform_bindings!(macos, "../src/macos_bindings.rs");
+platform_bindings!(windows, "../src/windows_bindings.rs");
+
+macro_rules! assert_small_packing {
+    ($platform:ident) => {{
+        use $platform::*;
+
+        assert_eq!(size_of::<CallbackMsg_t>(), 20);
+        assert_eq!(align_of::<CallbackMsg_t>(), 4);
+
+        assert_eq!(size_of::<SteamAPICallCompleted_t>(), 16);
+        assert_eq!(align_of::<SteamAPICallCompleted_t>(), 4);
+
+        assert_eq!(size_of::<LobbyEnter_t>(), 20);
+        assert_eq!(align_of::<LobbyEnter_t>(), 4);
+
+        // Declared inside the small packing region, see `pack_interfaces` in build.rs
+        assert_eq!(align_of::<ISteamParties>(), 4);
+        assert_eq!(align_of::<ISteamUGC>(), 4);
+        assert_eq!(align_of::<ISteamInventory>(), 4);
+        assert_eq!(align_of::<ISteamVideo>(), 4);
+    }};
+}
+
+#[test]
+fn linux_callback_structs_use_small_packing() {
+    assert_small_packing!(linux);
+}
+
+#[test]
+fn macos_callback_structs_use_small_packing() {
+    assert_small_packing!(macos);
+}
+
+#[test]
+fn windows_callback_structs_use_large_packing() {
+    use windows::*;
+
+    assert_eq!(size_of::<CallbackMsg_t>(), 24);
+    assert_eq!(align_of::<CallbackMsg_t>(), 8);
+
+    assert_eq!(size_of::<SteamAPICallCompleted_t>(), 16);
+    assert_eq!(align_of::<SteamAPICallCompleted_t>(), 8);
+
+    assert_eq!(size_of::<LobbyEnter_t>(), 24);
+    assert_eq!(align_of::<LobbyEnter_t>(), 8);
+}
+
+#[test]
+fn callback_ids_match_across_platforms() {
+    assert_eq!(linux::SteamAPICallCompleted_t_k_iCallback as i32, 703);
+    assert_eq!(macos::SteamAPICallCompleted_t_k_iCallback as i32, 703);
+    assert_eq!(windows::SteamAPICallCompleted_t_k_iCallback as i32, 703);
+
+    assert_eq!(linux::LobbyEnter_t_k_iCallback as i32, 504);
+    assert_eq!(macos::LobbyEnter_t_k_iCallback as i32, 504);
+    assert_eq!(windows::LobbyEnter_t_k_iCallback as i32, 504);
+}
+
+#[test]
+fn steam_id_is_tightly_packed() {
+    // CSteamID is wrapped in `#pragma pack(push, 1)` on every platform
+    assert_eq!(size_of::<linux::CSteamID>(), 8);
+    assert_eq!(align_of::<linux::CSteamID>(), 1);
+    assert_eq!(size_of::<macos::CSteamID>(), 8);
+    assert_eq!(align_of::<macos::CSteamID>(), 1);
+    assert_eq!(size_of::<windows::CSteamID>(), 8);
+    assert_eq!(align_of::<windows::CSteamID>(), 1);
+}
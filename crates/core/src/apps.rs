@@ -1,30 +1,208 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
 use super::*;
-use crate::bindings;
+
+/// The id of a depot, the unit steam downloads an app's content in
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DepotId(pub u32);
+
+/// A DLC of the running app
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DlcInfo {
+    pub app_id: AppId,
+    /// Whether the DLC can be bought or used, not whether the user owns it
+    pub available: bool,
+    pub name: String,
+}
+
+/// How long the user can play a timed trial of the app for
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TimedTrial {
+    pub allowed: Duration,
+    pub played: Duration,
+}
 
 #[derive(Clone)]
 pub struct Apps {
-    pub(crate) apps: *mut bindings::ISteamApps,
+    pub(crate) backend: Arc<dyn Backend>,
 }
 
-unsafe impl Send for Apps {}
-unsafe impl Sync for Apps {}
-
 impl Apps {
-    pub(crate) fn new() -> Self {
-        Self {
-            apps: unsafe { bindings::SteamAPI_SteamApps_v008() },
-        }
+    pub(crate) fn new(backend: Arc<dyn Backend>) -> Self {
+        Self { backend }
     }
 
     pub fn is_subscribed(&self) -> bool {
-        {
-            unsafe { bindings::SteamAPI_ISteamApps_BIsSubscribed(self.apps) }
-        }
+        self.backend.apps().is_subscribed()
     }
 
     pub fn is_subscribed_app(&self, id: AppId) -> bool {
-        {
-            unsafe { bindings::SteamAPI_ISteamApps_BIsSubscribedApp(self.apps, id.0) }
+        self.backend.apps().is_subscribed_app(id)
+    }
+
+    pub fn is_app_installed(&self, id: AppId) -> bool {
+        self.backend.apps().is_app_installed(id)
+    }
+
+    /// Number of DLCs of the running app, including ones the user doesn't own
+    pub fn get_dlc_count(&self) -> u32 {
+        self.backend.apps().get_dlc_count().max(0) as u32
+    }
+
+    pub fn get_dlc_by_index(&self, idx: u32) -> Option<DlcInfo> {
+        self.backend.apps().get_dlc_data_by_index(idx as i32)
+    }
+
+    /// Every DLC of the running app
+    pub fn get_dlcs(&self) -> Vec<DlcInfo> {
+        (0..self.get_dlc_count())
+            .filter_map(|idx| self.get_dlc_by_index(idx))
+            .collect()
+    }
+
+    /// Starts downloading an owned DLC, `DlcInstalled` is sent once it's done
+    pub fn install_dlc(&self, id: AppId) {
+        self.backend.apps().install_dlc(id)
+    }
+
+    pub fn uninstall_dlc(&self, id: AppId) {
+        self.backend.apps().uninstall_dlc(id)
+    }
+
+    /// Returns true if the user owns the DLC and it is installed
+    pub fn is_dlc_installed(&self, id: AppId) -> bool {
+        self.backend.apps().is_dlc_installed(id)
+    }
+
+    /// The beta branch the app is running from, if it isn't the default one
+    pub fn get_current_beta_name(&self) -> Option<String> {
+        self.backend.apps().get_current_beta_name()
+    }
+
+    /// The language the user picked for the game, e.g. `english`
+    pub fn get_current_game_language(&self) -> String {
+        self.backend.apps().get_current_game_language()
+    }
+
+    /// Every language the game supports
+    pub fn get_available_game_languages(&self) -> Vec<String> {
+        self.backend
+            .apps()
+            .get_available_game_languages()
+            .split(',')
+            .map(str::trim)
+            .filter(|language| !language.is_empty())
+            .map(str::to_owned)
+            .collect()
+    }
+
+    /// Where an app is installed, `None` if it isn't
+    pub fn get_app_install_dir(&self, id: AppId) -> Option<PathBuf> {
+        self.backend.apps().get_app_install_dir(id)
+    }
+
+    /// The build the running app was installed from
+    pub fn get_app_build_id(&self) -> i32 {
+        self.backend.apps().get_app_build_id()
+    }
+
+    /// The depots of an app that are installed, in mount order
+    pub fn get_installed_depots(&self, id: AppId) -> Vec<DepotId> {
+        self.backend.apps().get_installed_depots(id)
+    }
+
+    /// Who owns the running app, differs from the user when it is borrowed through
+    /// family sharing
+    pub fn get_app_owner(&self) -> SteamId {
+        self.backend.apps().get_app_owner()
+    }
+
+    pub fn is_subscribed_from_family_sharing(&self) -> bool {
+        self.backend.apps().is_subscribed_from_family_sharing()
+    }
+
+    pub fn is_subscribed_from_free_weekend(&self) -> bool {
+        self.backend.apps().is_subscribed_from_free_weekend()
+    }
+
+    /// How long the user can play for, if the app is a timed trial
+    pub fn get_timed_trial(&self) -> Option<TimedTrial> {
+        let (allowed, played) = self.backend.apps().is_timed_trial()?;
+        Some(TimedTrial {
+            allowed: Duration::from_secs(allowed as u64),
+            played: Duration::from_secs(played as u64),
+        })
+    }
+
+    /// Returns true if the user has a VAC ban on their account
+    pub fn is_vac_banned(&self) -> bool {
+        self.backend.apps().is_vac_banned()
+    }
+
+    /// A parameter of the steam://run URL the game was launched with, e.g. `connect`
+    /// from `steam://run/480//?connect=1.2.3.4`
+    pub fn get_launch_query_param(&self, key: &str) -> Option<String> {
+        self.backend.apps().get_launch_query_param(key)
+    }
+
+    /// The arguments of the steam://run URL the game was launched with
+    ///
+    /// Quotes group arguments containing spaces, `\"` is a literal quote.
+    pub fn get_launch_command_line(&self) -> Vec<String> {
+        split_command_line(&self.backend.apps().get_launch_command_line())
+    }
+
+    /// The process's own arguments followed by the ones from
+    /// [`get_launch_command_line`](Self::get_launch_command_line)
+    ///
+    /// Invalid UTF-8 in the process's arguments is replaced with `U+FFFD`.
+    pub fn get_launch_args(&self) -> Vec<String> {
+        self.backend
+            .apps()
+            .get_process_args()
+            .into_iter()
+            .map(|arg| arg.to_string_lossy().into_owned())
+            .chain(self.get_launch_command_line())
+            .collect()
+    }
+}
+
+fn split_command_line(line: &str) -> Vec<String> {
+    let mut args = Vec::new();
+    let mut arg = String::new();
+    // Whether an argument has started, so `""` still counts as one
+    let mut in_arg = false;
+    let mut quoted = false;
+
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' if chars.peek() == Some(&'"') => {
+                chars.next();
+                arg.push('"');
+                in_arg = true;
+            }
+            '"' => {
+                quoted = !quoted;
+                in_arg = true;
+            }
+            c if c.is_whitespace() && !quoted => {
+                if in_arg {
+                    args.push(std::mem::take(&mut arg));
+                    in_arg = false;
+                }
+            }
+            c => {
+                arg.push(c);
+                in_arg = true;
+            }
         }
     }
+    if in_arg {
+        args.push(arg);
+    }
+
+    args
 }
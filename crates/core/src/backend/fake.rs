@@ -0,0 +1,3521 @@
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use super::*;
+use crate::bindings;
+use crate::callbacks::{
+    AvatarImageLoaded, CreateItemResult, DlcInstalled, DownloadItemResult,
+    EncryptedAppTicketResponse, FriendRichPresenceUpdate, GSPolicyResponse,
+    GamepadTextInputDismissed, GetAuthSessionTicketResponse, ItemInstalled, LeaderboardFindResult,
+    LeaderboardScoreUploaded, LeaderboardScoresDownloaded, LobbyChatMsg, LobbyChatUpdate,
+    LobbyCreated, LobbyDataUpdate, LobbyEnter, LobbyMatchList, NetConnectionStatusChanged,
+    NetworkingMessagesSessionFailed, NetworkingMessagesSessionRequest, NewUrlLaunchParameters,
+    PersonaStateChange, RemoteStorageSubscribePublishedFileResult,
+    RemoteStorageUnsubscribePublishedFileResult, SteamServerConnectFailure, SteamServersConnected,
+    SteamServersDisconnected, SteamUGCQueryCompleted, SubmitItemUpdateResult,
+    UserAchievementStored, UserStatsReceived, UserStatsStored, ValidateAuthTicketResponse,
+};
+use crate::result::SteamResult;
+use crate::{
+    AuthSessionResponse, AuthTicketHandle, ChatEntryType, ChatMemberStateChange,
+    ChatRoomEnterResponse, ConnectionHandle, FloatingGamepadTextInputMode,
+    GamepadTextInputLineMode, GamepadTextInputMode, Image, Ipv6ConnectivityProtocol,
+    Ipv6ConnectivityState, ListenSocketHandle, NetworkingConnectionState, PersonaChange,
+    ServerMode, TextFieldRect, Universe, UpdateStatus, MAX_RICH_PRESENCE_KEYS,
+};
+
+/// A friend known to a [`FakeBackend`]
+#[derive(Clone, Debug)]
+pub struct FakeFriend {
+    pub steam_id: SteamId,
+    pub persona_name: String,
+    pub flags: FriendFlags,
+    pub persona_state: PersonaState,
+    pub relationship: FriendRelationship,
+    pub steam_level: u32,
+    pub nickname: Option<String>,
+    pub game: Option<FriendGame>,
+    pub rich_presence: Vec<(String, String)>,
+}
+
+impl FakeFriend {
+    pub fn new(steam_id: SteamId, persona_name: impl Into<String>) -> Self {
+        Self {
+            steam_id,
+            persona_name: persona_name.into(),
+            flags: FriendFlags::IMMEDIATE,
+            persona_state: PersonaState::Online,
+            relationship: FriendRelationship::Friend,
+            steam_level: 1,
+            nickname: None,
+            game: None,
+            rich_presence: Vec::new(),
+        }
+    }
+
+    pub fn with_rich_presence(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        set_rich_presence(&mut self.rich_presence, key.into(), value.into());
+        self
+    }
+}
+
+/// Sets or, given an empty value, removes a rich presence key
+fn set_rich_presence(presence: &mut Vec<(String, String)>, key: String, value: String) {
+    let existing = presence.iter().position(|(k, _)| *k == key);
+    match (existing, value.is_empty()) {
+        (Some(idx), true) => {
+            presence.remove(idx);
+        }
+        (Some(idx), false) => presence[idx].1 = value,
+        (None, true) => {}
+        (None, false) => presence.push((key, value)),
+    }
+}
+
+// Fake messages simply own their bytes
+impl MessageBuffer for Vec<u8> {
+    fn data(&self) -> &[u8] {
+        self
+    }
+}
+
+/// The machine and client a [`FakeBackend`] pretends to run on
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FakeEnvironment {
+    /// Follows the system clock when `None`
+    pub server_time: Option<SystemTime>,
+    pub ip_country: String,
+    pub ui_language: String,
+    pub universe: Universe,
+    pub seconds_since_app_active: u32,
+    pub seconds_since_computer_active: u32,
+    /// `None` when running on AC power
+    pub battery_power: Option<u8>,
+    pub steam_deck: bool,
+    pub big_picture: bool,
+    pub vr: bool,
+    pub china_launcher: bool,
+    pub ipv6_http: Ipv6ConnectivityState,
+    pub ipv6_udp: Ipv6ConnectivityState,
+}
+
+impl Default for FakeEnvironment {
+    fn default() -> Self {
+        Self {
+            server_time: None,
+            ip_country: String::from("US"),
+            ui_language: String::from("english"),
+            universe: Universe::Public,
+            seconds_since_app_active: 0,
+            seconds_since_computer_active: 0,
+            battery_power: None,
+            steam_deck: false,
+            big_picture: false,
+            vr: false,
+            china_launcher: false,
+            ipv6_http: Ipv6ConnectivityState::Unknown,
+            ipv6_udp: Ipv6ConnectivityState::Unknown,
+        }
+    }
+}
+
+/// An on-screen keyboard opened through a [`FakeBackend`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FakeTextInput {
+    pub mode: GamepadTextInputMode,
+    pub line_mode: GamepadTextInputLineMode,
+    pub description: String,
+    pub max_chars: u32,
+    pub existing: String,
+}
+
+/// A DLC of the running app, known to a [`FakeBackend`]
+#[derive(Clone, Debug)]
+pub struct FakeDlc {
+    pub app_id: AppId,
+    pub name: String,
+    /// Whether the user owns it
+    pub available: bool,
+    pub installed: bool,
+}
+
+impl FakeDlc {
+    pub fn new(app_id: AppId, name: impl Into<String>) -> Self {
+        Self {
+            app_id,
+            name: name.into(),
+            available: true,
+            installed: false,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum FakeStat {
+    Int(i32),
+    Float(f32),
+}
+
+/// A workshop item known to a [`FakeBackend`]
+#[derive(Clone, Debug)]
+pub struct FakeWorkshopItem {
+    pub id: PublishedFileId,
+    pub owner: SteamId,
+    pub title: String,
+    pub description: String,
+    pub tags: Vec<String>,
+    pub visibility: Visibility,
+    pub subscribed: bool,
+    /// Set once the item has been downloaded
+    pub installed: Option<PathBuf>,
+}
+
+impl FakeWorkshopItem {
+    pub fn new(id: PublishedFileId, owner: SteamId, title: impl Into<String>) -> Self {
+        Self {
+            id,
+            owner,
+            title: title.into(),
+            description: String::new(),
+            tags: Vec::new(),
+            visibility: Visibility::Public,
+            subscribed: false,
+            installed: None,
+        }
+    }
+
+    fn details(&self, app_id: AppId) -> PublishedFileDetails {
+        PublishedFileDetails {
+            published_file_id: self.id,
+            result: SteamResult::Ok,
+            file_type: WorkshopFileType::Community,
+            creator_app_id: app_id,
+            consumer_app_id: app_id,
+            title: self.title.clone(),
+            description: self.description.clone(),
+            owner: self.owner,
+            time_created: 0,
+            time_updated: 0,
+            visibility: self.visibility,
+            banned: false,
+            accepted_for_use: true,
+            tags: self.tags.clone(),
+            tags_truncated: false,
+            file_name: String::new(),
+            file_size: 0,
+            url: String::new(),
+            votes_up: 0,
+            votes_down: 0,
+            score: 0.0,
+        }
+    }
+}
+
+// Steam returns this many results per query page
+const UGC_RESULTS_PER_PAGE: usize = 50;
+
+struct FakeQuery {
+    // The user and list to match, if this is a user query
+    user_list: Option<(u32, UserList)>,
+    page: u32,
+    required_tags: Vec<String>,
+    excluded_tags: Vec<String>,
+    match_any_tag: bool,
+    search_text: Option<String>,
+    // Filled in once the query has been sent
+    results: Vec<PublishedFileDetails>,
+}
+
+impl FakeQuery {
+    fn new(user_list: Option<(u32, UserList)>, page: u32) -> Self {
+        Self {
+            user_list,
+            page,
+            required_tags: Vec::new(),
+            excluded_tags: Vec::new(),
+            match_any_tag: false,
+            search_text: None,
+            results: Vec::new(),
+        }
+    }
+
+    fn matches(&self, item: &FakeWorkshopItem) -> bool {
+        let on_list = match self.user_list {
+            None => true,
+            Some((account_id, UserList::Published)) => item.owner.account_id() == account_id,
+            Some((_, UserList::Subscribed)) => item.subscribed,
+            Some(_) => false,
+        };
+        let has_tag = |tag: &String| item.tags.contains(tag);
+        let required = if self.match_any_tag && !self.required_tags.is_empty() {
+            self.required_tags.iter().any(has_tag)
+        } else {
+            self.required_tags.iter().all(has_tag)
+        };
+        let searched = self.search_text.as_ref().is_none_or(|text| {
+            let text = text.to_lowercase();
+            item.title.to_lowercase().contains(&text)
+                || item.description.to_lowercase().contains(&text)
+        });
+
+        on_list && required && !self.excluded_tags.iter().any(has_tag) && searched
+    }
+}
+
+struct FakeItemUpdate {
+    item: PublishedFileId,
+    title: Option<String>,
+    description: Option<String>,
+    tags: Option<Vec<String>>,
+    visibility: Option<Visibility>,
+    submitted: bool,
+}
+
+struct FakeLeaderboard {
+    name: String,
+    sort_method: LeaderboardSortMethod,
+    // Kept in rank order
+    entries: Vec<LeaderboardEntry>,
+}
+
+impl FakeLeaderboard {
+    fn new(name: &str, sort_method: LeaderboardSortMethod) -> Self {
+        Self {
+            name: name.to_owned(),
+            sort_method,
+            entries: Vec::new(),
+        }
+    }
+
+    fn ranks_better(&self, score: i32, than: i32) -> bool {
+        match self.sort_method {
+            LeaderboardSortMethod::Ascending => score < than,
+            LeaderboardSortMethod::Descending => score > than,
+        }
+    }
+
+    fn entry(&self, steam_id: SteamId) -> Option<&LeaderboardEntry> {
+        self.entries.iter().find(|entry| entry.steam_id == steam_id)
+    }
+
+    fn set_entry(&mut self, steam_id: SteamId, score: i32, details: &[i32]) {
+        self.entries.retain(|entry| entry.steam_id != steam_id);
+        self.entries.push(LeaderboardEntry {
+            steam_id,
+            global_rank: 0,
+            score,
+            details: details.to_vec(),
+        });
+
+        match self.sort_method {
+            LeaderboardSortMethod::Ascending => self.entries.sort_by_key(|entry| entry.score),
+            LeaderboardSortMethod::Descending => self
+                .entries
+                .sort_by_key(|entry| std::cmp::Reverse(entry.score)),
+        }
+        for (idx, entry) in self.entries.iter_mut().enumerate() {
+            entry.global_rank = idx as i32 + 1;
+        }
+    }
+}
+
+struct FakeFile {
+    data: Vec<u8>,
+    timestamp: i64,
+}
+
+impl FakeFile {
+    fn new(data: Vec<u8>) -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |since| since.as_secs() as i64);
+        Self { data, timestamp }
+    }
+}
+
+enum FakeListenSocket {
+    Ip(SocketAddr),
+    P2p(i32),
+}
+
+struct FakeConnection {
+    // The other end, while both ends are open
+    peer: Option<u32>,
+    listen_socket: Option<u32>,
+    remote_identity: NetworkingIdentity,
+    remote_address: Option<SocketAddr>,
+    state: NetworkingConnectionState,
+    end_reason: i32,
+    end_debug: String,
+    poll_group: Option<u32>,
+    inbound: VecDeque<NetworkingMessage>,
+    next_message_number: i64,
+}
+
+impl FakeConnection {
+    fn new(
+        listen_socket: Option<u32>,
+        remote_identity: NetworkingIdentity,
+        remote_address: Option<SocketAddr>,
+    ) -> Self {
+        Self {
+            peer: None,
+            listen_socket,
+            remote_identity,
+            remote_address,
+            state: NetworkingConnectionState::None,
+            end_reason: 0,
+            end_debug: String::new(),
+            poll_group: None,
+            inbound: VecDeque::new(),
+            next_message_number: 1,
+        }
+    }
+
+    fn info(&self) -> NetConnectionInfo {
+        NetConnectionInfo {
+            remote_identity: self.remote_identity.clone(),
+            user_data: -1,
+            listen_socket: self.listen_socket.map(ListenSocketHandle),
+            remote_address: self.remote_address,
+            state: self.state,
+            end_reason: self.end_reason,
+            end_debug: self.end_debug.clone(),
+            description: String::new(),
+        }
+    }
+}
+
+struct FakeSession {
+    accepted: bool,
+    channels: Vec<i32>,
+    // Messages that arrived before the session was accepted
+    pending: Vec<NetworkingMessage>,
+    next_message_number: i64,
+}
+
+impl FakeSession {
+    fn new(accepted: bool) -> Self {
+        Self {
+            accepted,
+            channels: Vec::new(),
+            pending: Vec::new(),
+            next_message_number: 1,
+        }
+    }
+
+    fn info(&self, identity: &NetworkingIdentity) -> NetConnectionInfo {
+        NetConnectionInfo {
+            remote_identity: identity.clone(),
+            user_data: -1,
+            listen_socket: None,
+            remote_address: None,
+            state: match self.accepted {
+                true => NetworkingConnectionState::Connected,
+                false => NetworkingConnectionState::Connecting,
+            },
+            end_reason: 0,
+            end_debug: String::new(),
+            description: String::new(),
+        }
+    }
+}
+
+/// A lobby known to a [`FakeBackend`]
+#[derive(Clone, Debug)]
+pub struct FakeLobby {
+    pub id: SteamId,
+    pub owner: SteamId,
+    pub lobby_type: LobbyType,
+    pub max_members: u32,
+    pub joinable: bool,
+    pub members: Vec<SteamId>,
+    pub data: BTreeMap<String, String>,
+    member_data: HashMap<(SteamId, String), String>,
+    // A chat entry's id is its index
+    chat: Vec<LobbyChatEntry>,
+}
+
+impl FakeLobby {
+    /// A public lobby with room for 4, with `owner` as its only member
+    pub fn new(id: SteamId, owner: SteamId) -> Self {
+        Self {
+            id,
+            owner,
+            lobby_type: LobbyType::Public,
+            max_members: 4,
+            joinable: true,
+            members: vec![owner],
+            data: BTreeMap::new(),
+            member_data: HashMap::new(),
+            chat: Vec::new(),
+        }
+    }
+
+    pub fn with_data(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.data.insert(key.into(), value.into());
+        self
+    }
+
+    fn push_chat(&mut self, sender: SteamId, data: Vec<u8>) -> LobbyChatMsg {
+        self.chat.push(LobbyChatEntry {
+            sender,
+            entry_type: ChatEntryType::ChatMsg,
+            data,
+        });
+        LobbyChatMsg {
+            lobby: self.id,
+            user: sender,
+            entry_type: ChatEntryType::ChatMsg,
+            chat_id: self.chat.len() as u32 - 1,
+        }
+    }
+}
+
+// Lobby ids are chat steam ids, with the lobby instance flag set
+const LOBBY_ID_BASE: u64 = 0x0186_0000_0000_0000;
+
+/// Filters added since the last lobby list request
+#[derive(Default)]
+struct FakeLobbyFilters {
+    strings: Vec<(String, String, LobbyComparison)>,
+    numbers: Vec<(String, i32, LobbyComparison)>,
+    near: Vec<(String, i32)>,
+    slots_available: Option<u32>,
+    max_results: Option<usize>,
+}
+
+impl FakeLobbyFilters {
+    fn matches(&self, lobby: &FakeLobby) -> bool {
+        let value = |key: &str| lobby.data.get(key).map_or("", String::as_str);
+        let strings = self
+            .strings
+            .iter()
+            .all(|(key, filter, cmp)| compare(value(key), filter.as_str(), *cmp));
+        let numbers = self.numbers.iter().all(|(key, filter, cmp)| {
+            value(key)
+                .parse::<i32>()
+                .is_ok_and(|value| compare(value, *filter, *cmp))
+        });
+        let free_slots = (lobby.max_members as usize).saturating_sub(lobby.members.len());
+        let slots = self
+            .slots_available
+            .is_none_or(|slots| free_slots >= slots as usize);
+
+        lobby.lobby_type == LobbyType::Public && lobby.joinable && strings && numbers && slots
+    }
+
+    /// How far a lobby is from each near filter, in priority order
+    fn distances(&self, lobby: &FakeLobby) -> Vec<i64> {
+        self.near
+            .iter()
+            .map(|(key, target)| {
+                lobby
+                    .data
+                    .get(key)
+                    .and_then(|value| value.parse::<i32>().ok())
+                    .map_or(i64::MAX, |value| (value as i64 - *target as i64).abs())
+            })
+            .collect()
+    }
+}
+
+fn compare<T: Ord>(value: T, filter: T, comparison: LobbyComparison) -> bool {
+    match comparison {
+        LobbyComparison::EqualToOrLessThan => value <= filter,
+        LobbyComparison::LessThan => value < filter,
+        LobbyComparison::Equal => value == filter,
+        LobbyComparison::GreaterThan => value > filter,
+        LobbyComparison::EqualToOrGreaterThan => value >= filter,
+        LobbyComparison::NotEqual => value != filter,
+    }
+}
+
+// Fake session tickets are this prefix, the issuing user and the ticket handle. Web api
+// tickets add their identity
+const AUTH_TICKET_PREFIX: &[u8] = b"fake-auth-ticket";
+
+fn fake_auth_ticket(steam_id: SteamId, handle: u32) -> Vec<u8> {
+    let mut ticket = AUTH_TICKET_PREFIX.to_vec();
+    ticket.extend_from_slice(&steam_id.0.to_le_bytes());
+    ticket.extend_from_slice(&handle.to_le_bytes());
+    ticket
+}
+
+/// Starts an auth session like `BeginAuthSession`, returning the response to report
+/// once the ticket has been checked
+fn begin_fake_auth_session(
+    sessions: &mut Vec<SteamId>,
+    ticket: &[u8],
+    steam_id: SteamId,
+) -> Result<ValidateAuthTicketResponse, SteamResult> {
+    let issuer = ticket
+        .strip_prefix(AUTH_TICKET_PREFIX)
+        .and_then(|rest| rest.get(..8)?.try_into().ok())
+        .map(|id| SteamId(u64::from_le_bytes(id)));
+    let Some(issuer) = issuer else {
+        return Err(
+            bindings::EBeginAuthSessionResult::k_EBeginAuthSessionResultInvalidTicket.into(),
+        );
+    };
+    if sessions.contains(&steam_id) {
+        return Err(
+            bindings::EBeginAuthSessionResult::k_EBeginAuthSessionResultDuplicateRequest.into(),
+        );
+    }
+
+    sessions.push(steam_id);
+    Ok(ValidateAuthTicketResponse {
+        steam_id,
+        response: match issuer == steam_id {
+            true => AuthSessionResponse::Ok,
+            false => AuthSessionResponse::AuthTicketInvalid,
+        },
+        owner_steam_id: steam_id,
+    })
+}
+
+struct FakeState {
+    steam_id: SteamId,
+    persona_name: String,
+    app_id: AppId,
+    owned_apps: Vec<AppId>,
+    friends: Vec<FakeFriend>,
+    environment: FakeEnvironment,
+    // The open gamepad keyboard, and the text last submitted through it
+    text_input: Option<FakeTextInput>,
+    entered_text: Option<String>,
+    floating_text_input: Option<(FloatingGamepadTextInputMode, TextFieldRect)>,
+    // The current user's own rich presence
+    rich_presence: Vec<(String, String)>,
+    // An image's handle is its index + 1
+    images: Vec<Image>,
+    // Avatar handles by user, -1 while still loading
+    avatars: HashMap<SteamId, i32>,
+    dlcs: Vec<FakeDlc>,
+    beta_name: Option<String>,
+    language: String,
+    available_languages: Vec<String>,
+    install_dirs: HashMap<AppId, PathBuf>,
+    installed_depots: HashMap<AppId, Vec<DepotId>>,
+    build_id: i32,
+    // Who lent us the app through family sharing
+    family_sharing_owner: Option<SteamId>,
+    free_weekend: bool,
+    // Seconds allowed and played
+    timed_trial: Option<(u32, u32)>,
+    vac_banned: bool,
+    launch_query_params: HashMap<String, String>,
+    launch_command_line: String,
+    process_args: Vec<OsString>,
+    encrypted_app_ticket: Option<Vec<u8>>,
+    // The data included in the last ticket request
+    encrypted_app_ticket_data: Vec<u8>,
+    // Session tickets that haven't been cancelled
+    auth_tickets: Vec<u32>,
+    auth_sessions: Vec<SteamId>,
+    stats: HashMap<String, FakeStat>,
+    achievements: HashMap<String, bool>,
+    // Achievements unlocked since the last `store_stats`
+    unstored_achievements: Vec<String>,
+    // A leaderboard's handle is its index + 1
+    leaderboards: Vec<FakeLeaderboard>,
+    downloaded_entries: HashMap<u64, Vec<LeaderboardEntry>>,
+    cloud_files: BTreeMap<String, FakeFile>,
+    cloud_quota: u64,
+    // Open write streams, by handle
+    write_streams: HashMap<u64, (String, Vec<u8>)>,
+    workshop_items: Vec<FakeWorkshopItem>,
+    // Open queries and item updates, by handle
+    ugc_queries: HashMap<u64, FakeQuery>,
+    item_updates: HashMap<u64, FakeItemUpdate>,
+    // Every socket, connection and poll group shares one handle space
+    listen_sockets: HashMap<u32, FakeListenSocket>,
+    connections: BTreeMap<u32, FakeConnection>,
+    poll_groups: Vec<u32>,
+    sessions: HashMap<NetworkingIdentity, FakeSession>,
+    session_messages: VecDeque<NetworkingMessage>,
+    sent_session_messages: Vec<(NetworkingIdentity, i32, Vec<u8>)>,
+    lobbies: Vec<FakeLobby>,
+    lobby_filters: FakeLobbyFilters,
+    // Results of the last lobby list request
+    lobby_list: Vec<SteamId>,
+    callbacks: VecDeque<CallbackMessage>,
+    // Whether a thread is taking a callback off the queue, and how often another
+    // one tried at the same time
+    pumping: bool,
+    overlapping_pumps: usize,
+    next_api_call: SteamAPICall,
+}
+
+/// An in-process backend that doesn't need a steam client
+///
+/// Clones share the same state, so keep one around after passing it to
+/// [`steam_api::init_with`](crate::steam_api::init_with) to queue callbacks from tests.
+#[derive(Clone)]
+pub struct FakeBackend {
+    state: Arc<Mutex<FakeState>>,
+}
+
+impl Default for FakeBackend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FakeBackend {
+    pub fn new() -> Self {
+        Self {
+            state: Arc::new(Mutex::new(FakeState {
+                steam_id: SteamId(76561197960265729),
+                persona_name: String::from("Player"),
+                app_id: AppId(480),
+                owned_apps: vec![AppId(480)],
+                friends: Vec::new(),
+                environment: FakeEnvironment::default(),
+                text_input: None,
+                entered_text: None,
+                floating_text_input: None,
+                rich_presence: Vec::new(),
+                images: Vec::new(),
+                avatars: HashMap::new(),
+                dlcs: Vec::new(),
+                beta_name: None,
+                language: String::from("english"),
+                available_languages: vec![String::from("english")],
+                install_dirs: HashMap::new(),
+                installed_depots: HashMap::new(),
+                build_id: 0,
+                family_sharing_owner: None,
+                free_weekend: false,
+                timed_trial: None,
+                vac_banned: false,
+                launch_query_params: HashMap::new(),
+                launch_command_line: String::new(),
+                process_args: Vec::new(),
+                encrypted_app_ticket: None,
+                encrypted_app_ticket_data: Vec::new(),
+                auth_tickets: Vec::new(),
+                auth_sessions: Vec::new(),
+                stats: HashMap::new(),
+                achievements: HashMap::new(),
+                unstored_achievements: Vec::new(),
+                leaderboards: Vec::new(),
+                downloaded_entries: HashMap::new(),
+                cloud_files: BTreeMap::new(),
+                cloud_quota: 100 * 1024 * 1024,
+                write_streams: HashMap::new(),
+                workshop_items: Vec::new(),
+                ugc_queries: HashMap::new(),
+                item_updates: HashMap::new(),
+                listen_sockets: HashMap::new(),
+                connections: BTreeMap::new(),
+                poll_groups: Vec::new(),
+                sessions: HashMap::new(),
+                session_messages: VecDeque::new(),
+                sent_session_messages: Vec::new(),
+                lobbies: Vec::new(),
+                lobby_filters: FakeLobbyFilters::default(),
+                lobby_list: Vec::new(),
+                callbacks: VecDeque::new(),
+                pumping: false,
+                overlapping_pumps: 0,
+                next_api_call: 1,
+            })),
+        }
+    }
+
+    /// Sets the logged in user
+    pub fn with_user(self, steam_id: SteamId, persona_name: impl Into<String>) -> Self {
+        {
+            let mut state = self.state();
+            state.steam_id = steam_id;
+            state.persona_name = persona_name.into();
+        }
+        self
+    }
+
+    /// Sets the id of the running app, which is also marked as owned
+    pub fn with_app_id(self, id: AppId) -> Self {
+        {
+            let mut state = self.state();
+            state.app_id = id;
+            if !state.owned_apps.contains(&id) {
+                state.owned_apps.push(id);
+            }
+        }
+        self
+    }
+
+    pub fn with_owned_app(self, id: AppId) -> Self {
+        {
+            let mut state = self.state();
+            if !state.owned_apps.contains(&id) {
+                state.owned_apps.push(id);
+            }
+        }
+        self
+    }
+
+    pub fn with_friend(self, friend: FakeFriend) -> Self {
+        self.state().friends.push(friend);
+        self
+    }
+
+    pub fn with_environment(self, environment: FakeEnvironment) -> Self {
+        self.state().environment = environment;
+        self
+    }
+
+    /// The gamepad keyboard currently shown, if any
+    pub fn text_input(&self) -> Option<FakeTextInput> {
+        self.state().text_input.clone()
+    }
+
+    /// The floating keyboard currently shown, if any
+    pub fn floating_text_input(&self) -> Option<(FloatingGamepadTextInputMode, TextFieldRect)> {
+        self.state().floating_text_input
+    }
+
+    /// Submits the gamepad keyboard, queueing a `GamepadTextInputDismissed`
+    ///
+    /// The text is cut off at the keyboard's `max_chars`.
+    pub fn queue_text_input_submitted(&self, text: &str) {
+        let mut state = self.state();
+        let max_chars = state
+            .text_input
+            .take()
+            .map_or(usize::MAX, |input| input.max_chars as usize);
+        let text: String = text.chars().take(max_chars).collect();
+        let app_id = state.app_id;
+        state.push_callback(GamepadTextInputDismissed {
+            submitted: true,
+            text_len: text.len() as u32,
+            app_id,
+        });
+        state.entered_text = Some(text);
+    }
+
+    /// Cancels the gamepad keyboard, queueing a `GamepadTextInputDismissed`
+    pub fn queue_text_input_cancelled(&self) {
+        let mut state = self.state();
+        state.text_input = None;
+        state.entered_text = None;
+        let app_id = state.app_id;
+        state.push_callback(GamepadTextInputDismissed {
+            submitted: false,
+            text_len: 0,
+            app_id,
+        });
+    }
+
+    /// Sets a user's avatar, handed out for every `ImageSize`
+    pub fn with_avatar(self, id: SteamId, image: Image) -> Self {
+        {
+            let mut state = self.state();
+            let handle = state.push_image(image);
+            state.avatars.insert(id, handle);
+        }
+        self
+    }
+
+    /// Makes a user's avatar look like it is still being downloaded until
+    /// `queue_avatar_loaded`
+    pub fn with_loading_avatar(self, id: SteamId) -> Self {
+        self.state().avatars.insert(id, -1);
+        self
+    }
+
+    /// Finishes downloading a user's avatar, queueing an `AvatarImageLoaded`
+    pub fn queue_avatar_loaded(&self, id: SteamId, image: Image) {
+        let mut state = self.state();
+        let (width, height) = (image.width, image.height);
+        let handle = state.push_image(image);
+        state.avatars.insert(id, handle);
+        state.push_callback(AvatarImageLoaded {
+            steam_id: id,
+            image: handle,
+            width,
+            height,
+        });
+    }
+
+    /// Adds or replaces a friend, queueing a `PersonaStateChange` for them
+    pub fn queue_persona_change(&self, friend: FakeFriend, changes: PersonaChange) {
+        let mut state = self.state();
+        let steam_id = friend.steam_id;
+        match state.friends.iter_mut().find(|f| f.steam_id == steam_id) {
+            Some(existing) => *existing = friend,
+            None => state.friends.push(friend),
+        }
+        state.push_callback(PersonaStateChange { steam_id, changes });
+    }
+
+    /// Simulates a friend setting a rich presence key, queueing a `FriendRichPresenceUpdate`
+    pub fn queue_rich_presence_update(&self, id: SteamId, key: &str, value: &str) {
+        let mut state = self.state();
+        let app_id = state.app_id;
+        let Some(friend) = state.friends.iter_mut().find(|f| f.steam_id == id) else {
+            return;
+        };
+        set_rich_presence(&mut friend.rich_presence, key.to_owned(), value.to_owned());
+        state.push_callback(FriendRichPresenceUpdate {
+            steam_id: id,
+            app_id,
+        });
+    }
+
+    pub fn with_dlc(self, dlc: FakeDlc) -> Self {
+        self.state().dlcs.push(dlc);
+        self
+    }
+
+    /// Puts the running app on a beta branch
+    pub fn with_beta(self, name: impl Into<String>) -> Self {
+        self.state().beta_name = Some(name.into());
+        self
+    }
+
+    /// Sets the language the game runs in and the ones it supports
+    pub fn with_languages(self, current: &str, available: &[&str]) -> Self {
+        {
+            let mut state = self.state();
+            state.language = current.to_owned();
+            state.available_languages = available.iter().map(|&l| l.to_owned()).collect();
+        }
+        self
+    }
+
+    /// Marks an app as installed in `dir` with the given depots
+    pub fn with_installed_app(
+        self,
+        id: AppId,
+        dir: impl Into<PathBuf>,
+        depots: impl IntoIterator<Item = DepotId>,
+    ) -> Self {
+        {
+            let mut state = self.state();
+            state.install_dirs.insert(id, dir.into());
+            state
+                .installed_depots
+                .insert(id, depots.into_iter().collect());
+        }
+        self
+    }
+
+    pub fn with_build_id(self, build_id: i32) -> Self {
+        self.state().build_id = build_id;
+        self
+    }
+
+    /// Makes the running app borrowed from `owner` through family sharing
+    pub fn with_family_sharing(self, owner: SteamId) -> Self {
+        self.state().family_sharing_owner = Some(owner);
+        self
+    }
+
+    pub fn with_free_weekend(self) -> Self {
+        self.state().free_weekend = true;
+        self
+    }
+
+    pub fn with_timed_trial(self, seconds_allowed: u32, seconds_played: u32) -> Self {
+        self.state().timed_trial = Some((seconds_allowed, seconds_played));
+        self
+    }
+
+    pub fn with_vac_ban(self) -> Self {
+        self.state().vac_banned = true;
+        self
+    }
+
+    /// Sets a parameter of the steam://run URL the game was launched with
+    pub fn with_launch_query_param(self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.state()
+            .launch_query_params
+            .insert(key.into(), value.into());
+        self
+    }
+
+    /// Sets the command line of the steam://run URL the game was launched with
+    pub fn with_launch_command_line(self, command_line: impl Into<String>) -> Self {
+        self.state().launch_command_line = command_line.into();
+        self
+    }
+
+    /// Sets the arguments the process was started with
+    pub fn with_process_args<I>(self, args: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<OsString>,
+    {
+        self.state().process_args = args.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Simulates the game being launched again through a steam URL while running,
+    /// replacing the launch parameters and queueing a `NewUrlLaunchParameters`
+    pub fn queue_url_launch(&self, query_params: &[(&str, &str)], command_line: &str) {
+        let mut state = self.state();
+        state.launch_query_params = query_params
+            .iter()
+            .map(|&(key, value)| (key.to_owned(), value.to_owned()))
+            .collect();
+        state.launch_command_line = command_line.to_owned();
+        state.push_callback(NewUrlLaunchParameters);
+    }
+
+    /// Sets the ticket handed out after `User::request_encrypted_app_ticket`
+    pub fn with_encrypted_app_ticket(self, ticket: impl Into<Vec<u8>>) -> Self {
+        self.state().encrypted_app_ticket = Some(ticket.into());
+        self
+    }
+
+    /// The data passed to the last `User::request_encrypted_app_ticket`
+    pub fn encrypted_app_ticket_data(&self) -> Vec<u8> {
+        self.state().encrypted_app_ticket_data.clone()
+    }
+
+    /// Tickets handed out by `User::get_auth_session_ticket` or
+    /// `User::get_auth_ticket_for_web_api` that haven't been cancelled
+    pub fn auth_tickets(&self) -> Vec<AuthTicketHandle> {
+        let state = self.state();
+        state
+            .auth_tickets
+            .iter()
+            .map(|handle| AuthTicketHandle(*handle))
+            .collect()
+    }
+
+    /// Defines an integer stat, starting at `value`
+    pub fn with_stat_i32(self, name: impl Into<String>, value: i32) -> Self {
+        self.state().stats.insert(name.into(), FakeStat::Int(value));
+        self
+    }
+
+    /// Defines a float stat, starting at `value`
+    pub fn with_stat_f32(self, name: impl Into<String>, value: f32) -> Self {
+        self.state()
+            .stats
+            .insert(name.into(), FakeStat::Float(value));
+        self
+    }
+
+    /// Defines an achievement, optionally already unlocked
+    pub fn with_achievement(self, name: impl Into<String>, achieved: bool) -> Self {
+        self.state().achievements.insert(name.into(), achieved);
+        self
+    }
+
+    /// Adds an entry to a leaderboard, creating it sorted descending if it doesn't exist
+    pub fn with_leaderboard_score(self, name: &str, steam_id: SteamId, score: i32) -> Self {
+        {
+            let mut state = self.state();
+            let leaderboard = state.leaderboard(name, LeaderboardSortMethod::Descending);
+            if let Some(board) = state.leaderboard_mut(leaderboard) {
+                board.set_entry(steam_id, score, &[]);
+            }
+        }
+        self
+    }
+
+    pub fn with_cloud_file(self, name: impl Into<String>, data: impl Into<Vec<u8>>) -> Self {
+        self.state()
+            .cloud_files
+            .insert(name.into(), FakeFile::new(data.into()));
+        self
+    }
+
+    /// Sets the total cloud storage available to the app, 100 MiB by default
+    pub fn with_cloud_quota(self, total_bytes: u64) -> Self {
+        self.state().cloud_quota = total_bytes;
+        self
+    }
+
+    pub fn with_workshop_item(self, item: FakeWorkshopItem) -> Self {
+        self.state().workshop_items.push(item);
+        self
+    }
+
+    /// How many ugc queries have been created but not released yet
+    pub fn open_ugc_queries(&self) -> usize {
+        self.state().ugc_queries.len()
+    }
+
+    pub fn with_lobby(self, lobby: FakeLobby) -> Self {
+        self.state().lobbies.push(lobby);
+        self
+    }
+
+    /// Simulates another user entering or leaving a lobby, queueing a `LobbyChatUpdate`
+    pub fn queue_lobby_chat_update(
+        &self,
+        lobby: SteamId,
+        user: SteamId,
+        state_change: ChatMemberStateChange,
+    ) {
+        let mut state = self.state();
+        let Some(fake) = state.lobby_mut(lobby) else {
+            return;
+        };
+        if state_change.contains(ChatMemberStateChange::ENTERED) {
+            if !fake.members.contains(&user) {
+                fake.members.push(user);
+            }
+        } else {
+            fake.members.retain(|member| *member != user);
+            match fake.members.first() {
+                Some(first) if fake.owner == user => fake.owner = *first,
+                _ => {}
+            }
+        }
+
+        state.push_callback(LobbyChatUpdate {
+            lobby,
+            user_changed: user,
+            making_change: user,
+            state_change,
+        });
+    }
+
+    /// Simulates a chat message sent to a lobby by another user, queueing a `LobbyChatMsg`
+    pub fn queue_lobby_chat_message(
+        &self,
+        lobby: SteamId,
+        from: SteamId,
+        data: impl Into<Vec<u8>>,
+    ) {
+        let mut state = self.state();
+        let Some(fake) = state.lobby_mut(lobby) else {
+            return;
+        };
+        let message = fake.push_chat(from, data.into());
+        state.push_callback(message);
+    }
+
+    /// Queues a callback to be dispatched by the next `steam_api::run_callbacks`
+    pub fn queue_callback<C: Callback>(&self, callback: C) {
+        self.state().push_callback(callback);
+    }
+
+    /// How often two threads took callbacks off the queue at the same time, which
+    /// steam's manual dispatch doesn't allow
+    pub fn overlapping_pumps(&self) -> usize {
+        self.state().overlapping_pumps
+    }
+
+    /// Queues the result of an api call to be dispatched by the next `steam_api::run_callbacks`
+    pub fn queue_call_result<C: Callback>(&self, api_call: SteamAPICall, result: C, failed: bool) {
+        self.state()
+            .callbacks
+            .push_back(CallbackMessage::CallResult {
+                api_call,
+                id: C::ID,
+                payload: Payload::Value(Box::new(result)),
+                failed,
+            });
+    }
+
+    /// Empties the payload of every queued call result, as steam delivers results it
+    /// couldn't fetch
+    pub fn truncate_queued_call_results(&self, failed: bool) {
+        for message in &mut self.state().callbacks {
+            if let CallbackMessage::CallResult {
+                payload,
+                failed: message_failed,
+                ..
+            } = message
+            {
+                *payload = Payload::Raw(Vec::new());
+                *message_failed = failed;
+            }
+        }
+    }
+
+    /// Simulates a message sent by another user through `NetworkingMessages`
+    ///
+    /// The first message from a user queues a `NetworkingMessagesSessionRequest`, and
+    /// their messages can't be received until the session is accepted.
+    pub fn queue_networking_message(
+        &self,
+        from: NetworkingIdentity,
+        channel: i32,
+        data: impl Into<Vec<u8>>,
+    ) {
+        let mut state = self.state();
+        if !state.sessions.contains_key(&from) {
+            state.sessions.insert(from.clone(), FakeSession::new(false));
+            state.push_callback(NetworkingMessagesSessionRequest {
+                remote_identity: from.clone(),
+            });
+        }
+
+        let FakeState {
+            sessions,
+            session_messages,
+            ..
+        } = &mut *state;
+        let Some(session) = sessions.get_mut(&from) else {
+            return;
+        };
+        if !session.channels.contains(&channel) {
+            session.channels.push(channel);
+        }
+        let message_number = session.next_message_number;
+        session.next_message_number += 1;
+
+        let message = NetworkingMessage::new(
+            Box::<Vec<u8>>::new(data.into()),
+            bindings::k_HSteamNetConnection_Invalid,
+            from,
+            channel,
+            true,
+            message_number,
+        );
+        match session.accepted {
+            true => session_messages.push_back(message),
+            false => session.pending.push(message),
+        }
+    }
+
+    /// Takes every message sent to other users through `NetworkingMessages`, as
+    /// `(recipient, channel, data)`
+    pub fn take_sent_networking_messages(&self) -> Vec<(NetworkingIdentity, i32, Vec<u8>)> {
+        std::mem::take(&mut self.state().sent_session_messages)
+    }
+
+    /// Drops the session with a user as if they had become unreachable
+    pub fn fail_networking_session(&self, identity: &NetworkingIdentity) {
+        let mut state = self.state();
+        let Some(session) = state.sessions.remove(identity) else {
+            return;
+        };
+
+        let mut info = session.info(identity);
+        info.state = NetworkingConnectionState::ProblemDetectedLocally;
+        info.end_reason =
+            bindings::ESteamNetConnectionEnd::k_ESteamNetConnectionEnd_Misc_Timeout as i32;
+        state
+            .session_messages
+            .retain(|message| message.peer() != identity);
+        state.push_callback(NetworkingMessagesSessionFailed { info });
+    }
+
+    fn state(&self) -> MutexGuard<'_, FakeState> {
+        self.state.lock().unwrap()
+    }
+
+    fn next_api_call(state: &mut FakeState) -> SteamAPICall {
+        let api_call = state.next_api_call;
+        state.next_api_call += 1;
+        api_call
+    }
+}
+
+impl Backend for FakeBackend {
+    fn user(&self) -> &dyn UserBackend {
+        self
+    }
+
+    fn apps(&self) -> &dyn AppsBackend {
+        self
+    }
+
+    fn friends(&self) -> &dyn FriendsBackend {
+        self
+    }
+
+    fn utils(&self) -> &dyn UtilsBackend {
+        self
+    }
+
+    fn user_stats(&self) -> &dyn UserStatsBackend {
+        self
+    }
+
+    fn remote_storage(&self) -> &dyn RemoteStorageBackend {
+        self
+    }
+
+    fn ugc(&self) -> &dyn UgcBackend {
+        self
+    }
+
+    fn networking_sockets(&self) -> &dyn NetworkingSocketsBackend {
+        self
+    }
+
+    fn networking_messages(&self) -> &dyn NetworkingMessagesBackend {
+        self
+    }
+
+    fn matchmaking(&self) -> &dyn MatchmakingBackend {
+        self
+    }
+
+    fn run_frame(&self) {}
+
+    fn next_callback(&self) -> Option<CallbackMessage> {
+        // Steam hands out a callback and frees it in two calls, leave the same gap
+        {
+            let mut state = self.state();
+            if state.pumping {
+                state.overlapping_pumps += 1;
+            }
+            state.pumping = true;
+        }
+        std::thread::yield_now();
+
+        let mut state = self.state();
+        state.pumping = false;
+        state.callbacks.pop_front()
+    }
+
+    fn shutdown(&self) {
+        self.state().callbacks.clear();
+    }
+}
+
+impl UserBackend for FakeBackend {
+    fn get_steam_id(&self) -> SteamId {
+        self.state().steam_id
+    }
+
+    fn request_encrypted_app_ticket(&self, data: &[u8]) -> SteamAPICall {
+        let mut state = self.state();
+        let api_call = Self::next_api_call(&mut state);
+        state.encrypted_app_ticket_data = data.to_vec();
+        let result = if state.encrypted_app_ticket.is_some() {
+            bindings::EResult::k_EResultOK
+        } else {
+            bindings::EResult::k_EResultFail
+        };
+        state.callbacks.push_back(CallbackMessage::CallResult {
+            api_call,
+            id: EncryptedAppTicketResponse::ID,
+            payload: Payload::Value(Box::new(EncryptedAppTicketResponse { result })),
+            failed: false,
+        });
+        api_call
+    }
+
+    fn get_encrypted_app_ticket(&self, ticket: &mut [u8]) -> (bool, u32) {
+        let state = self.state();
+        let Some(stored) = state.encrypted_app_ticket.as_ref() else {
+            return (false, 0);
+        };
+        let Some(dest) = ticket.get_mut(..stored.len()) else {
+            return (false, stored.len() as u32);
+        };
+        dest.copy_from_slice(stored);
+        (true, stored.len() as u32)
+    }
+
+    fn get_auth_session_ticket(&self, ticket: &mut [u8]) -> Option<(u32, u32)> {
+        let mut state = self.state();
+        let handle = Self::next_api_call(&mut state) as u32;
+        let data = fake_auth_ticket(state.steam_id, handle);
+        ticket.get_mut(..data.len())?.copy_from_slice(&data);
+
+        state.auth_tickets.push(handle);
+        state.push_callback(GetAuthSessionTicketResponse {
+            ticket: AuthTicketHandle(handle),
+            result: SteamResult::Ok,
+        });
+        Some((handle, data.len() as u32))
+    }
+
+    fn cancel_auth_ticket(&self, handle: u32) {
+        self.state().auth_tickets.retain(|ticket| *ticket != handle);
+    }
+
+    #[cfg(feature = "web-api-ticket")]
+    fn get_auth_ticket_for_web_api(&self, identity: &str) -> Option<u32> {
+        use crate::callbacks::GetTicketForWebApiResponse;
+
+        let mut state = self.state();
+        let handle = Self::next_api_call(&mut state) as u32;
+        let mut data = fake_auth_ticket(state.steam_id, handle);
+        data.extend_from_slice(identity.as_bytes());
+
+        state.auth_tickets.push(handle);
+        state.push_callback(GetTicketForWebApiResponse {
+            ticket: AuthTicketHandle(handle),
+            result: SteamResult::Ok,
+            data,
+        });
+        Some(handle)
+    }
+
+    fn begin_auth_session(&self, ticket: &[u8], steam_id: SteamId) -> SteamResult {
+        let mut state = self.state();
+        match begin_fake_auth_session(&mut state.auth_sessions, ticket, steam_id) {
+            Ok(response) => {
+                state.push_callback(response);
+                SteamResult::Ok
+            }
+            Err(err) => err,
+        }
+    }
+
+    fn end_auth_session(&self, steam_id: SteamId) {
+        self.state().auth_sessions.retain(|id| *id != steam_id);
+    }
+}
+
+impl AppsBackend for FakeBackend {
+    fn is_subscribed(&self) -> bool {
+        let state = self.state();
+        state.owned_apps.contains(&state.app_id)
+    }
+
+    fn is_subscribed_app(&self, id: AppId) -> bool {
+        self.state().owned_apps.contains(&id)
+    }
+
+    fn is_app_installed(&self, id: AppId) -> bool {
+        self.state().install_dirs.contains_key(&id)
+    }
+
+    fn get_dlc_count(&self) -> i32 {
+        self.state().dlcs.len() as i32
+    }
+
+    fn get_dlc_data_by_index(&self, idx: i32) -> Option<DlcInfo> {
+        let state = self.state();
+        let dlc = state.dlcs.get(usize::try_from(idx).ok()?)?;
+        Some(DlcInfo {
+            app_id: dlc.app_id,
+            available: dlc.available,
+            name: dlc.name.clone(),
+        })
+    }
+
+    fn install_dlc(&self, id: AppId) {
+        let mut state = self.state();
+        let Some(dlc) = state
+            .dlcs
+            .iter_mut()
+            .find(|dlc| dlc.app_id == id && dlc.available)
+        else {
+            return;
+        };
+        if !dlc.installed {
+            // Steam finishes the download instantly
+            dlc.installed = true;
+            state.push_callback(DlcInstalled { app_id: id });
+        }
+    }
+
+    fn uninstall_dlc(&self, id: AppId) {
+        if let Some(dlc) = self.state().dlcs.iter_mut().find(|dlc| dlc.app_id == id) {
+            dlc.installed = false;
+        }
+    }
+
+    fn is_dlc_installed(&self, id: AppId) -> bool {
+        self.state()
+            .dlcs
+            .iter()
+            .any(|dlc| dlc.app_id == id && dlc.installed)
+    }
+
+    fn get_current_beta_name(&self) -> Option<String> {
+        self.state().beta_name.clone()
+    }
+
+    fn get_current_game_language(&self) -> String {
+        self.state().language.clone()
+    }
+
+    fn get_available_game_languages(&self) -> String {
+        self.state().available_languages.join(",")
+    }
+
+    fn get_app_install_dir(&self, id: AppId) -> Option<PathBuf> {
+        self.state().install_dirs.get(&id).cloned()
+    }
+
+    fn get_app_build_id(&self) -> i32 {
+        self.state().build_id
+    }
+
+    fn get_installed_depots(&self, id: AppId) -> Vec<DepotId> {
+        self.state()
+            .installed_depots
+            .get(&id)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    fn get_app_owner(&self) -> SteamId {
+        let state = self.state();
+        state.family_sharing_owner.unwrap_or(state.steam_id)
+    }
+
+    fn is_subscribed_from_family_sharing(&self) -> bool {
+        self.state().family_sharing_owner.is_some()
+    }
+
+    fn is_subscribed_from_free_weekend(&self) -> bool {
+        self.state().free_weekend
+    }
+
+    fn is_timed_trial(&self) -> Option<(u32, u32)> {
+        self.state().timed_trial
+    }
+
+    fn is_vac_banned(&self) -> bool {
+        self.state().vac_banned
+    }
+
+    fn get_launch_query_param(&self, key: &str) -> Option<String> {
+        self.state().launch_query_params.get(key).cloned()
+    }
+
+    fn get_launch_command_line(&self) -> String {
+        self.state().launch_command_line.clone()
+    }
+
+    fn get_process_args(&self) -> Vec<OsString> {
+        self.state().process_args.clone()
+    }
+}
+
+impl FakeState {
+    fn push_callback<C: Callback>(&mut self, callback: C) {
+        self.callbacks.push_back(CallbackMessage::Callback {
+            id: C::ID,
+            payload: Payload::Value(Box::new(callback)),
+        });
+    }
+
+    fn push_call_result<C: Callback>(&mut self, api_call: SteamAPICall, result: C) {
+        self.callbacks.push_back(CallbackMessage::CallResult {
+            api_call,
+            id: C::ID,
+            payload: Payload::Value(Box::new(result)),
+            failed: false,
+        });
+    }
+
+    /// Finds a leaderboard by name, creating it if it doesn't exist
+    fn leaderboard(&mut self, name: &str, sort_method: LeaderboardSortMethod) -> Leaderboard {
+        let idx = match self
+            .leaderboards
+            .iter()
+            .position(|board| board.name == name)
+        {
+            Some(idx) => idx,
+            None => {
+                self.leaderboards
+                    .push(FakeLeaderboard::new(name, sort_method));
+                self.leaderboards.len() - 1
+            }
+        };
+        Leaderboard(idx as u64 + 1)
+    }
+
+    fn cloud_used(&self) -> u64 {
+        self.cloud_files
+            .values()
+            .map(|file| file.data.len() as u64)
+            .sum()
+    }
+
+    /// Stores a file if it fits in the quota, counting the file it replaces as free space
+    fn store_cloud_file(&mut self, name: String, data: Vec<u8>) -> bool {
+        let replaced = self
+            .cloud_files
+            .get(&name)
+            .map_or(0, |file| file.data.len() as u64);
+        if self.cloud_used() - replaced + data.len() as u64 > self.cloud_quota {
+            return false;
+        }
+        self.cloud_files.insert(name, FakeFile::new(data));
+        true
+    }
+
+    fn workshop_item_mut(&mut self, id: PublishedFileId) -> Option<&mut FakeWorkshopItem> {
+        self.workshop_items.iter_mut().find(|item| item.id == id)
+    }
+
+    fn leaderboard_mut(&mut self, leaderboard: Leaderboard) -> Option<&mut FakeLeaderboard> {
+        let idx = (leaderboard.0 as usize).checked_sub(1)?;
+        self.leaderboards.get_mut(idx)
+    }
+
+    fn lobby_mut(&mut self, lobby: SteamId) -> Option<&mut FakeLobby> {
+        self.lobbies.iter_mut().find(|fake| fake.id == lobby)
+    }
+
+    /// A lobby the current user is a member of
+    fn joined_lobby(&self, lobby: SteamId) -> Option<&FakeLobby> {
+        self.lobbies
+            .iter()
+            .find(|fake| fake.id == lobby && fake.members.contains(&self.steam_id))
+    }
+
+    /// A lobby the current user owns
+    fn owned_lobby_mut(&mut self, lobby: SteamId) -> Option<&mut FakeLobby> {
+        let steam_id = self.steam_id;
+        self.lobbies
+            .iter_mut()
+            .find(|fake| fake.id == lobby && fake.owner == steam_id)
+    }
+
+    fn push_lobby_enter(
+        &mut self,
+        api_call: SteamAPICall,
+        lobby: SteamId,
+        response: ChatRoomEnterResponse,
+    ) {
+        let enter = LobbyEnter {
+            lobby,
+            chat_permissions: 0,
+            locked: false,
+            response,
+        };
+        self.push_call_result(api_call, enter.clone());
+        self.push_callback(enter);
+    }
+
+    fn friends_matching(&self, flags: FriendFlags) -> impl Iterator<Item = &FakeFriend> {
+        self.friends
+            .iter()
+            .filter(move |friend| friend.flags.intersects(flags))
+    }
+
+    fn image(&self, handle: i32) -> Option<&Image> {
+        self.images
+            .get(usize::try_from(handle).ok()?.checked_sub(1)?)
+    }
+
+    /// Stores an image, returning its handle
+    fn push_image(&mut self, image: Image) -> i32 {
+        self.images.push(image);
+        self.images.len() as i32
+    }
+
+    fn friend(&self, id: SteamId) -> Option<&FakeFriend> {
+        self.friends.iter().find(|friend| friend.steam_id == id)
+    }
+
+    /// Steam hands out the current user's own rich presence like a friend's
+    fn rich_presence_of(&self, id: SteamId) -> &[(String, String)] {
+        if id == self.steam_id {
+            return &self.rich_presence;
+        }
+        self.friend(id)
+            .map(|friend| friend.rich_presence.as_slice())
+            .unwrap_or_default()
+    }
+}
+
+impl FriendsBackend for FakeBackend {
+    fn get_persona_name(&self) -> String {
+        self.state().persona_name.clone()
+    }
+
+    fn get_friend_count(&self, flags: FriendFlags) -> i32 {
+        self.state().friends_matching(flags).count() as i32
+    }
+
+    fn get_friend_by_index(&self, idx: i32, flags: FriendFlags) -> SteamId {
+        self.state()
+            .friends_matching(flags)
+            .nth(idx as usize)
+            .map(|friend| friend.steam_id)
+            .unwrap_or(SteamId(0))
+    }
+
+    fn get_friend_persona_name(&self, id: SteamId) -> String {
+        self.state()
+            .friends
+            .iter()
+            .find(|friend| friend.steam_id == id)
+            .map(|friend| friend.persona_name.clone())
+            .unwrap_or_default()
+    }
+
+    fn get_small_friend_avatar(&self, id: SteamId) -> i32 {
+        self.state().avatars.get(&id).copied().unwrap_or(0)
+    }
+
+    fn get_medium_friend_avatar(&self, id: SteamId) -> i32 {
+        self.state().avatars.get(&id).copied().unwrap_or(0)
+    }
+
+    fn get_large_friend_avatar(&self, id: SteamId) -> i32 {
+        self.state().avatars.get(&id).copied().unwrap_or(0)
+    }
+
+    fn set_rich_presence(&self, key: &str, value: &str) -> bool {
+        let mut state = self.state();
+        let is_new = !state.rich_presence.iter().any(|(k, _)| k == key);
+        if is_new && !value.is_empty() && state.rich_presence.len() >= MAX_RICH_PRESENCE_KEYS {
+            return false;
+        }
+        set_rich_presence(&mut state.rich_presence, key.to_owned(), value.to_owned());
+        true
+    }
+
+    fn clear_rich_presence(&self) {
+        self.state().rich_presence.clear();
+    }
+
+    fn get_friend_rich_presence(&self, id: SteamId, key: &str) -> Option<String> {
+        self.state()
+            .rich_presence_of(id)
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, value)| value.clone())
+    }
+
+    fn get_friend_rich_presence_key_count(&self, id: SteamId) -> i32 {
+        self.state().rich_presence_of(id).len() as i32
+    }
+
+    fn get_friend_rich_presence_key_by_index(&self, id: SteamId, idx: i32) -> Option<String> {
+        let state = self.state();
+        let (key, _) = state.rich_presence_of(id).get(usize::try_from(idx).ok()?)?;
+        Some(key.clone())
+    }
+
+    fn request_friend_rich_presence(&self, id: SteamId) {
+        let mut state = self.state();
+        if state.friend(id).is_some() {
+            let app_id = state.app_id;
+            state.push_callback(FriendRichPresenceUpdate {
+                steam_id: id,
+                app_id,
+            });
+        }
+    }
+
+    fn get_friend_persona_state(&self, id: SteamId) -> PersonaState {
+        self.state()
+            .friend(id)
+            .map_or(PersonaState::Offline, |friend| friend.persona_state)
+    }
+
+    fn get_friend_game_played(&self, id: SteamId) -> Option<FriendGame> {
+        self.state().friend(id)?.game
+    }
+
+    fn get_friend_steam_level(&self, id: SteamId) -> i32 {
+        self.state()
+            .friend(id)
+            .map_or(0, |friend| friend.steam_level as i32)
+    }
+
+    fn get_player_nickname(&self, id: SteamId) -> Option<String> {
+        self.state().friend(id)?.nickname.clone()
+    }
+
+    fn get_friend_relationship(&self, id: SteamId) -> FriendRelationship {
+        self.state()
+            .friend(id)
+            .map_or(FriendRelationship::None, |friend| friend.relationship)
+    }
+
+    fn request_user_information(&self, id: SteamId, name_only: bool) -> bool {
+        let mut state = self.state();
+        if state.friend(id).is_some() {
+            return false;
+        }
+
+        // Nothing is known about strangers, steam still reports the lookup as done
+        let changes = if name_only {
+            PersonaChange::NAME
+        } else {
+            PersonaChange::NAME | PersonaChange::AVATAR
+        };
+        state.push_callback(PersonaStateChange {
+            steam_id: id,
+            changes,
+        });
+        true
+    }
+}
+
+impl UtilsBackend for FakeBackend {
+    fn get_app_id(&self) -> AppId {
+        self.state().app_id
+    }
+
+    fn get_server_real_time(&self) -> u32 {
+        let time = self
+            .state()
+            .environment
+            .server_time
+            .unwrap_or_else(SystemTime::now);
+        time.duration_since(UNIX_EPOCH)
+            .map_or(0, |since| since.as_secs() as u32)
+    }
+
+    fn get_ip_country(&self) -> String {
+        self.state().environment.ip_country.clone()
+    }
+
+    fn get_steam_ui_language(&self) -> String {
+        self.state().environment.ui_language.clone()
+    }
+
+    fn get_connected_universe(&self) -> Universe {
+        self.state().environment.universe
+    }
+
+    fn get_seconds_since_app_active(&self) -> u32 {
+        self.state().environment.seconds_since_app_active
+    }
+
+    fn get_seconds_since_computer_active(&self) -> u32 {
+        self.state().environment.seconds_since_computer_active
+    }
+
+    fn get_current_battery_power(&self) -> u8 {
+        self.state().environment.battery_power.unwrap_or(u8::MAX)
+    }
+
+    fn is_steam_running_on_steam_deck(&self) -> bool {
+        self.state().environment.steam_deck
+    }
+
+    fn is_steam_in_big_picture_mode(&self) -> bool {
+        self.state().environment.big_picture
+    }
+
+    fn is_steam_running_in_vr(&self) -> bool {
+        self.state().environment.vr
+    }
+
+    fn is_steam_china_launcher(&self) -> bool {
+        self.state().environment.china_launcher
+    }
+
+    fn get_ipv6_connectivity_state(
+        &self,
+        protocol: Ipv6ConnectivityProtocol,
+    ) -> Ipv6ConnectivityState {
+        let state = self.state();
+        match protocol {
+            Ipv6ConnectivityProtocol::Http => state.environment.ipv6_http,
+            Ipv6ConnectivityProtocol::Udp => state.environment.ipv6_udp,
+        }
+    }
+
+    fn show_gamepad_text_input(
+        &self,
+        mode: GamepadTextInputMode,
+        line_mode: GamepadTextInputLineMode,
+        description: &str,
+        max_chars: u32,
+        existing: &str,
+    ) -> bool {
+        let mut state = self.state();
+        if state.text_input.is_some() {
+            return false;
+        }
+
+        state.text_input = Some(FakeTextInput {
+            mode,
+            line_mode,
+            description: description.to_owned(),
+            max_chars,
+            existing: existing.to_owned(),
+        });
+        true
+    }
+
+    fn get_entered_gamepad_text_length(&self) -> u32 {
+        let state = self.state();
+        state
+            .entered_text
+            .as_ref()
+            .map_or(0, |text| text.len() as u32 + 1)
+    }
+
+    fn get_entered_gamepad_text_input(&self, text: &mut [u8]) -> bool {
+        let state = self.state();
+        let Some(entered) = &state.entered_text else {
+            return false;
+        };
+        if text.len() <= entered.len() {
+            return false;
+        }
+
+        text[..entered.len()].copy_from_slice(entered.as_bytes());
+        text[entered.len()] = 0;
+        true
+    }
+
+    fn show_floating_gamepad_text_input(
+        &self,
+        mode: FloatingGamepadTextInputMode,
+        field: TextFieldRect,
+    ) -> bool {
+        self.state().floating_text_input = Some((mode, field));
+        true
+    }
+
+    fn dismiss_floating_gamepad_text_input(&self) -> bool {
+        self.state().floating_text_input.take().is_some()
+    }
+
+    fn get_image_size(&self, handle: i32) -> Option<(u32, u32)> {
+        let state = self.state();
+        let image = state.image(handle)?;
+        Some((image.width, image.height))
+    }
+
+    fn get_image_rgba(&self, handle: i32, dest: &mut [u8]) -> bool {
+        let state = self.state();
+        let Some(image) = state.image(handle) else {
+            return false;
+        };
+        match dest.get_mut(..image.rgba.len()) {
+            Some(dest) => {
+                dest.copy_from_slice(&image.rgba);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl UserStatsBackend for FakeBackend {
+    fn request_current_stats(&self) -> bool {
+        let mut state = self.state();
+        let callback = UserStatsReceived {
+            game_id: state.app_id.0 as u64,
+            result: SteamResult::Ok,
+            steam_id: state.steam_id,
+        };
+        state.push_callback(callback);
+        true
+    }
+
+    fn get_stat_i32(&self, name: &str) -> Option<i32> {
+        match self.state().stats.get(name)? {
+            FakeStat::Int(value) => Some(*value),
+            FakeStat::Float(_) => None,
+        }
+    }
+
+    fn get_stat_f32(&self, name: &str) -> Option<f32> {
+        match self.state().stats.get(name)? {
+            FakeStat::Float(value) => Some(*value),
+            FakeStat::Int(_) => None,
+        }
+    }
+
+    fn set_stat_i32(&self, name: &str, value: i32) -> bool {
+        match self.state().stats.get_mut(name) {
+            Some(FakeStat::Int(stat)) => {
+                *stat = value;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn set_stat_f32(&self, name: &str, value: f32) -> bool {
+        match self.state().stats.get_mut(name) {
+            Some(FakeStat::Float(stat)) => {
+                *stat = value;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn get_achievement(&self, name: &str) -> Option<bool> {
+        self.state().achievements.get(name).copied()
+    }
+
+    fn set_achievement(&self, name: &str) -> bool {
+        let mut state = self.state();
+        match state.achievements.get_mut(name) {
+            Some(achieved) => {
+                if !*achieved {
+                    *achieved = true;
+                    state.unstored_achievements.push(name.to_owned());
+                }
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn clear_achievement(&self, name: &str) -> bool {
+        let mut state = self.state();
+        match state.achievements.get_mut(name) {
+            Some(achieved) => {
+                *achieved = false;
+                state
+                    .unstored_achievements
+                    .retain(|unstored| unstored != name);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn indicate_achievement_progress(&self, name: &str, current: u32, max: u32) -> bool {
+        let mut state = self.state();
+        if state.achievements.get(name) != Some(&false) || current >= max {
+            return false;
+        }
+        let callback = UserAchievementStored {
+            game_id: state.app_id.0 as u64,
+            group_achievement: false,
+            achievement_name: name.to_owned(),
+            current_progress: current,
+            max_progress: max,
+        };
+        state.push_callback(callback);
+        true
+    }
+
+    fn find_or_create_leaderboard(
+        &self,
+        name: &str,
+        sort_method: LeaderboardSortMethod,
+        _display_type: LeaderboardDisplayType,
+    ) -> SteamAPICall {
+        let mut state = self.state();
+        let api_call = Self::next_api_call(&mut state);
+        let leaderboard = state.leaderboard(name, sort_method);
+        state.push_call_result(
+            api_call,
+            LeaderboardFindResult {
+                leaderboard,
+                found: true,
+            },
+        );
+        api_call
+    }
+
+    fn upload_leaderboard_score(
+        &self,
+        leaderboard: Leaderboard,
+        method: UploadScoreMethod,
+        score: i32,
+        details: &[i32],
+    ) -> SteamAPICall {
+        let mut state = self.state();
+        let api_call = Self::next_api_call(&mut state);
+        let steam_id = state.steam_id;
+
+        let mut result = LeaderboardScoreUploaded {
+            success: false,
+            leaderboard,
+            score,
+            score_changed: false,
+            global_rank_new: 0,
+            global_rank_previous: 0,
+        };
+        if let Some(board) = state.leaderboard_mut(leaderboard) {
+            let previous = board.entry(steam_id).cloned();
+            let replace = match (&previous, method) {
+                (None, _) | (_, UploadScoreMethod::ForceUpdate) => true,
+                (Some(previous), UploadScoreMethod::KeepBest) => {
+                    board.ranks_better(score, previous.score)
+                }
+            };
+            if replace {
+                board.set_entry(steam_id, score, details);
+            }
+
+            result.success = true;
+            result.score_changed = replace;
+            result.global_rank_previous = previous.map_or(0, |entry| entry.global_rank);
+            result.global_rank_new = board.entry(steam_id).map_or(0, |entry| entry.global_rank);
+        }
+
+        state.push_call_result(api_call, result);
+        api_call
+    }
+
+    fn download_leaderboard_entries(
+        &self,
+        leaderboard: Leaderboard,
+        range: LeaderboardRange,
+    ) -> SteamAPICall {
+        let mut state = self.state();
+        let api_call = Self::next_api_call(&mut state);
+        let steam_id = state.steam_id;
+        let friends: Vec<SteamId> = state
+            .friends_matching(FriendFlags::IMMEDIATE)
+            .map(|friend| friend.steam_id)
+            .collect();
+
+        let entries: Vec<LeaderboardEntry> = match state.leaderboard_mut(leaderboard) {
+            Some(board) => {
+                let user_rank = board.entry(steam_id).map(|entry| entry.global_rank);
+                board
+                    .entries
+                    .iter()
+                    .filter(|entry| match range {
+                        LeaderboardRange::Global { start, end } => {
+                            (start..=end).contains(&entry.global_rank)
+                        }
+                        LeaderboardRange::AroundUser { before, after } => {
+                            user_rank.is_some_and(|rank| {
+                                (rank - before as i32..=rank + after as i32)
+                                    .contains(&entry.global_rank)
+                            })
+                        }
+                        LeaderboardRange::Friends => {
+                            entry.steam_id == steam_id || friends.contains(&entry.steam_id)
+                        }
+                    })
+                    .cloned()
+                    .collect()
+            }
+            None => Vec::new(),
+        };
+
+        // The call handle doubles as the handle of the downloaded entries
+        let result = LeaderboardScoresDownloaded {
+            leaderboard,
+            entries: api_call,
+            entry_count: entries.len() as i32,
+        };
+        state.downloaded_entries.insert(api_call, entries);
+        state.push_call_result(api_call, result);
+        api_call
+    }
+
+    fn get_downloaded_leaderboard_entry(
+        &self,
+        entries: u64,
+        index: i32,
+    ) -> Option<LeaderboardEntry> {
+        self.state()
+            .downloaded_entries
+            .get(&entries)?
+            .get(usize::try_from(index).ok()?)
+            .cloned()
+    }
+
+    fn store_stats(&self) -> bool {
+        let mut state = self.state();
+        let game_id = state.app_id.0 as u64;
+
+        state.push_callback(UserStatsStored {
+            game_id,
+            result: SteamResult::Ok,
+        });
+        for name in std::mem::take(&mut state.unstored_achievements) {
+            state.push_callback(UserAchievementStored {
+                game_id,
+                group_achievement: false,
+                achievement_name: name,
+                current_progress: 0,
+                max_progress: 0,
+            });
+        }
+        true
+    }
+}
+
+impl RemoteStorageBackend for FakeBackend {
+    fn file_write(&self, name: &str, data: &[u8]) -> bool {
+        self.state()
+            .store_cloud_file(name.to_owned(), data.to_vec())
+    }
+
+    fn file_read(&self, name: &str, dest: &mut [u8]) -> i32 {
+        let state = self.state();
+        let Some(file) = state.cloud_files.get(name) else {
+            return 0;
+        };
+        let len = file.data.len().min(dest.len());
+        dest[..len].copy_from_slice(&file.data[..len]);
+        len as i32
+    }
+
+    fn file_delete(&self, name: &str) -> bool {
+        self.state().cloud_files.remove(name).is_some()
+    }
+
+    fn file_exists(&self, name: &str) -> bool {
+        self.state().cloud_files.contains_key(name)
+    }
+
+    fn get_file_size(&self, name: &str) -> i32 {
+        self.state()
+            .cloud_files
+            .get(name)
+            .map_or(0, |file| file.data.len() as i32)
+    }
+
+    fn get_file_timestamp(&self, name: &str) -> i64 {
+        self.state()
+            .cloud_files
+            .get(name)
+            .map_or(0, |file| file.timestamp)
+    }
+
+    fn get_quota(&self) -> Option<(u64, u64)> {
+        let state = self.state();
+        let available = state.cloud_quota.saturating_sub(state.cloud_used());
+        Some((state.cloud_quota, available))
+    }
+
+    fn get_file_count(&self) -> i32 {
+        self.state().cloud_files.len() as i32
+    }
+
+    fn get_file_name_and_size(&self, idx: i32) -> Option<(String, i32)> {
+        self.state()
+            .cloud_files
+            .iter()
+            .nth(usize::try_from(idx).ok()?)
+            .map(|(name, file)| (name.clone(), file.data.len() as i32))
+    }
+
+    fn file_write_stream_open(&self, name: &str) -> u64 {
+        let mut state = self.state();
+        let handle = Self::next_api_call(&mut state);
+        state
+            .write_streams
+            .insert(handle, (name.to_owned(), Vec::new()));
+        handle
+    }
+
+    fn file_write_stream_write_chunk(&self, handle: u64, data: &[u8]) -> bool {
+        if data.len() > bindings::k_unMaxCloudFileChunkSize as usize {
+            return false;
+        }
+        match self.state().write_streams.get_mut(&handle) {
+            Some((_, written)) => {
+                written.extend_from_slice(data);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn file_write_stream_close(&self, handle: u64) -> bool {
+        let mut state = self.state();
+        match state.write_streams.remove(&handle) {
+            Some((name, data)) => state.store_cloud_file(name, data),
+            None => false,
+        }
+    }
+
+    fn file_write_stream_cancel(&self, handle: u64) -> bool {
+        self.state().write_streams.remove(&handle).is_some()
+    }
+}
+
+impl FakeBackend {
+    fn with_query(&self, handle: u64, f: impl FnOnce(&mut FakeQuery)) -> bool {
+        match self.state().ugc_queries.get_mut(&handle) {
+            Some(query) => {
+                f(query);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn with_item_update(&self, handle: u64, f: impl FnOnce(&mut FakeItemUpdate)) -> bool {
+        match self.state().item_updates.get_mut(&handle) {
+            Some(update) if !update.submitted => {
+                f(update);
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+impl UgcBackend for FakeBackend {
+    fn create_query_user_ugc_request(
+        &self,
+        account_id: u32,
+        list: UserList,
+        _ugc_type: UgcType,
+        _order: UserListOrder,
+        _app_id: AppId,
+        page: u32,
+    ) -> u64 {
+        let mut state = self.state();
+        let handle = Self::next_api_call(&mut state);
+        state
+            .ugc_queries
+            .insert(handle, FakeQuery::new(Some((account_id, list)), page));
+        handle
+    }
+
+    fn create_query_all_ugc_request(
+        &self,
+        _query_type: UgcQueryType,
+        _ugc_type: UgcType,
+        _app_id: AppId,
+        page: u32,
+    ) -> u64 {
+        let mut state = self.state();
+        let handle = Self::next_api_call(&mut state);
+        state.ugc_queries.insert(handle, FakeQuery::new(None, page));
+        handle
+    }
+
+    fn add_required_tag(&self, handle: u64, tag: &str) -> bool {
+        self.with_query(handle, |query| query.required_tags.push(tag.to_owned()))
+    }
+
+    fn add_excluded_tag(&self, handle: u64, tag: &str) -> bool {
+        self.with_query(handle, |query| query.excluded_tags.push(tag.to_owned()))
+    }
+
+    fn set_match_any_tag(&self, handle: u64, match_any: bool) -> bool {
+        self.with_query(handle, |query| query.match_any_tag = match_any)
+    }
+
+    fn set_search_text(&self, handle: u64, text: &str) -> bool {
+        self.with_query(handle, |query| query.search_text = Some(text.to_owned()))
+    }
+
+    fn set_return_long_description(&self, handle: u64, _long_description: bool) -> bool {
+        self.with_query(handle, |_| {})
+    }
+
+    fn send_query_ugc_request(&self, handle: u64) -> SteamAPICall {
+        let mut guard = self.state();
+        let state = &mut *guard;
+        let Some(query) = state.ugc_queries.get_mut(&handle) else {
+            return bindings::k_uAPICallInvalid;
+        };
+
+        let matching: Vec<_> = state
+            .workshop_items
+            .iter()
+            .filter(|item| query.matches(item))
+            .collect();
+        let skip = (query.page.max(1) as usize - 1) * UGC_RESULTS_PER_PAGE;
+        query.results = matching
+            .iter()
+            .skip(skip)
+            .take(UGC_RESULTS_PER_PAGE)
+            .map(|item| item.details(state.app_id))
+            .collect();
+
+        let result = SteamUGCQueryCompleted {
+            handle,
+            result: SteamResult::Ok,
+            results_returned: query.results.len() as u32,
+            total_matching_results: matching.len() as u32,
+            cached_data: false,
+        };
+        let api_call = Self::next_api_call(state);
+        state.push_call_result(api_call, result);
+        api_call
+    }
+
+    fn get_query_ugc_result(&self, handle: u64, index: u32) -> Option<PublishedFileDetails> {
+        self.state()
+            .ugc_queries
+            .get(&handle)?
+            .results
+            .get(index as usize)
+            .cloned()
+    }
+
+    fn release_query_ugc_request(&self, handle: u64) -> bool {
+        self.state().ugc_queries.remove(&handle).is_some()
+    }
+
+    fn create_item(&self, _app_id: AppId, _file_type: WorkshopFileType) -> SteamAPICall {
+        let mut state = self.state();
+        let api_call = Self::next_api_call(&mut state);
+        let id = PublishedFileId(
+            state
+                .workshop_items
+                .iter()
+                .map(|item| item.id.0 + 1)
+                .max()
+                .unwrap_or(1),
+        );
+        let owner = state.steam_id;
+        state
+            .workshop_items
+            .push(FakeWorkshopItem::new(id, owner, String::new()));
+        state.push_call_result(
+            api_call,
+            CreateItemResult {
+                result: SteamResult::Ok,
+                published_file_id: id,
+                needs_to_accept_agreement: false,
+            },
+        );
+        api_call
+    }
+
+    fn start_item_update(&self, _app_id: AppId, id: PublishedFileId) -> u64 {
+        let mut state = self.state();
+        let steam_id = state.steam_id;
+        if !state
+            .workshop_items
+            .iter()
+            .any(|item| item.id == id && item.owner == steam_id)
+        {
+            return bindings::k_UGCUpdateHandleInvalid;
+        }
+
+        let handle = Self::next_api_call(&mut state);
+        state.item_updates.insert(
+            handle,
+            FakeItemUpdate {
+                item: id,
+                title: None,
+                description: None,
+                tags: None,
+                visibility: None,
+                submitted: false,
+            },
+        );
+        handle
+    }
+
+    fn set_item_title(&self, handle: u64, title: &str) -> bool {
+        self.with_item_update(handle, |update| update.title = Some(title.to_owned()))
+    }
+
+    fn set_item_description(&self, handle: u64, description: &str) -> bool {
+        self.with_item_update(handle, |update| {
+            update.description = Some(description.to_owned())
+        })
+    }
+
+    fn set_item_content(&self, handle: u64, folder: &Path) -> bool {
+        folder.is_dir() && self.with_item_update(handle, |_| {})
+    }
+
+    fn set_item_preview(&self, handle: u64, file: &Path) -> bool {
+        file.is_file() && self.with_item_update(handle, |_| {})
+    }
+
+    fn set_item_tags(&self, handle: u64, tags: &[&str]) -> bool {
+        self.with_item_update(handle, |update| {
+            update.tags = Some(tags.iter().map(|tag| tag.to_string()).collect())
+        })
+    }
+
+    fn set_item_visibility(&self, handle: u64, visibility: Visibility) -> bool {
+        self.with_item_update(handle, |update| update.visibility = Some(visibility))
+    }
+
+    fn submit_item_update(&self, handle: u64, _change_note: Option<&str>) -> SteamAPICall {
+        let mut guard = self.state();
+        let state = &mut *guard;
+        let Some(update) = state.item_updates.get_mut(&handle) else {
+            return bindings::k_uAPICallInvalid;
+        };
+        if update.submitted {
+            return bindings::k_uAPICallInvalid;
+        }
+        update.submitted = true;
+
+        let id = update.item;
+        let (title, description, tags, visibility) = (
+            update.title.take(),
+            update.description.take(),
+            update.tags.take(),
+            update.visibility.take(),
+        );
+        if let Some(item) = state.workshop_item_mut(id) {
+            item.title = title.unwrap_or_else(|| item.title.clone());
+            item.description = description.unwrap_or_else(|| item.description.clone());
+            item.tags = tags.unwrap_or_else(|| item.tags.clone());
+            item.visibility = visibility.unwrap_or(item.visibility);
+        }
+
+        let api_call = Self::next_api_call(state);
+        state.push_call_result(
+            api_call,
+            SubmitItemUpdateResult {
+                result: SteamResult::Ok,
+                needs_to_accept_agreement: false,
+                published_file_id: id,
+            },
+        );
+        api_call
+    }
+
+    fn get_item_update_progress(&self, handle: u64) -> UpdateProgress {
+        let submitted = self
+            .state()
+            .item_updates
+            .get(&handle)
+            .is_some_and(|update| update.submitted);
+        UpdateProgress {
+            status: if submitted {
+                UpdateStatus::CommittingChanges
+            } else {
+                UpdateStatus::Invalid
+            },
+            bytes_processed: 0,
+            bytes_total: 0,
+        }
+    }
+
+    fn subscribe_item(&self, id: PublishedFileId) -> SteamAPICall {
+        let mut state = self.state();
+        let api_call = Self::next_api_call(&mut state);
+        let result = match state.workshop_item_mut(id) {
+            Some(item) => {
+                item.subscribed = true;
+                SteamResult::Ok
+            }
+            None => SteamResult::FileNotFound,
+        };
+        state.push_call_result(
+            api_call,
+            RemoteStorageSubscribePublishedFileResult {
+                result,
+                published_file_id: id,
+            },
+        );
+        api_call
+    }
+
+    fn unsubscribe_item(&self, id: PublishedFileId) -> SteamAPICall {
+        let mut state = self.state();
+        let api_call = Self::next_api_call(&mut state);
+        let result = match state.workshop_item_mut(id) {
+            Some(item) => {
+                item.subscribed = false;
+                SteamResult::Ok
+            }
+            None => SteamResult::FileNotFound,
+        };
+        state.push_call_result(
+            api_call,
+            RemoteStorageUnsubscribePublishedFileResult {
+                result,
+                published_file_id: id,
+            },
+        );
+        api_call
+    }
+
+    fn download_item(&self, id: PublishedFileId, _high_priority: bool) -> bool {
+        let mut state = self.state();
+        let app_id = state.app_id;
+        let Some(item) = state.workshop_item_mut(id) else {
+            return false;
+        };
+        if !item.subscribed {
+            return false;
+        }
+
+        item.installed = Some(
+            ["steamapps", "workshop", "content"]
+                .iter()
+                .collect::<PathBuf>()
+                .join(app_id.0.to_string())
+                .join(id.0.to_string()),
+        );
+        state.push_callback(DownloadItemResult {
+            app_id,
+            published_file_id: id,
+            result: SteamResult::Ok,
+        });
+        state.push_callback(ItemInstalled {
+            app_id,
+            published_file_id: id,
+        });
+        true
+    }
+
+    fn get_item_state(&self, id: PublishedFileId) -> ItemState {
+        let mut state = ItemState::NONE;
+        if let Some(item) = self.state().workshop_item_mut(id) {
+            state.set(ItemState::SUBSCRIBED, item.subscribed);
+            state.set(ItemState::INSTALLED, item.installed.is_some());
+        }
+        state
+    }
+
+    fn get_item_install_info(&self, id: PublishedFileId) -> Option<InstallInfo> {
+        let mut state = self.state();
+        let folder = state.workshop_item_mut(id)?.installed.clone()?;
+        Some(InstallInfo {
+            folder,
+            size_on_disk: 0,
+            timestamp: 0,
+        })
+    }
+
+    fn get_item_download_info(&self, _id: PublishedFileId) -> Option<(u64, u64)> {
+        None
+    }
+
+    fn get_subscribed_items(&self) -> Vec<PublishedFileId> {
+        self.state()
+            .workshop_items
+            .iter()
+            .filter(|item| item.subscribed)
+            .map(|item| item.id)
+            .collect()
+    }
+}
+
+impl FakeState {
+    fn set_connection_state(&mut self, conn: u32, state: NetworkingConnectionState) {
+        let Some(connection) = self.connections.get_mut(&conn) else {
+            return;
+        };
+        let old_state = connection.state;
+        connection.state = state;
+        let info = connection.info();
+
+        self.push_callback(NetConnectionStatusChanged {
+            connection: ConnectionHandle(conn),
+            info,
+            old_state,
+        });
+    }
+
+    /// Opens a connection to a local listen socket, or one that fails if there is none
+    fn connect(
+        &mut self,
+        listen_socket: Option<u32>,
+        remote_identity: NetworkingIdentity,
+        remote_address: Option<SocketAddr>,
+    ) -> u32 {
+        let client = FakeBackend::next_api_call(self) as u32;
+        self.connections.insert(
+            client,
+            FakeConnection::new(None, remote_identity, remote_address),
+        );
+        self.set_connection_state(client, NetworkingConnectionState::Connecting);
+
+        let Some(listen_socket) = listen_socket else {
+            if let Some(connection) = self.connections.get_mut(&client) {
+                connection.end_reason =
+                    bindings::ESteamNetConnectionEnd::k_ESteamNetConnectionEnd_Misc_Timeout as i32;
+                connection.end_debug = String::from("Timed out attempting to connect");
+            }
+            self.set_connection_state(client, NetworkingConnectionState::ProblemDetectedLocally);
+            return client;
+        };
+
+        let server = FakeBackend::next_api_call(self) as u32;
+        let mut connection = FakeConnection::new(Some(listen_socket), self.steam_id.into(), None);
+        connection.peer = Some(client);
+        self.connections.insert(server, connection);
+        if let Some(connection) = self.connections.get_mut(&client) {
+            connection.peer = Some(server);
+        }
+        self.set_connection_state(server, NetworkingConnectionState::Connecting);
+        client
+    }
+
+    /// Frees a connection, telling the other end it was closed
+    fn close_connection(&mut self, conn: u32, reason: i32, debug: &str) -> bool {
+        let Some(connection) = self.connections.remove(&conn) else {
+            return false;
+        };
+
+        let Some(peer) = connection.peer else {
+            return true;
+        };
+        if let Some(other) = self.connections.get_mut(&peer) {
+            other.peer = None;
+            other.end_reason = reason;
+            other.end_debug = debug.to_owned();
+            self.set_connection_state(peer, NetworkingConnectionState::ClosedByPeer);
+        }
+        true
+    }
+
+    fn take_messages(&mut self, conns: &[u32], max: usize) -> Vec<NetworkingMessage> {
+        let mut messages = Vec::new();
+        for conn in conns {
+            let Some(connection) = self.connections.get_mut(conn) else {
+                continue;
+            };
+            while messages.len() < max {
+                match connection.inbound.pop_front() {
+                    Some(message) => messages.push(message),
+                    None => break,
+                }
+            }
+        }
+        messages
+    }
+}
+
+impl NetworkingSocketsBackend for FakeBackend {
+    fn create_listen_socket_ip(&self, addr: SocketAddr) -> u32 {
+        let mut state = self.state();
+        let taken = state.listen_sockets.values().any(|socket| match socket {
+            FakeListenSocket::Ip(bound) => bound.port() == addr.port(),
+            FakeListenSocket::P2p(_) => false,
+        });
+        if taken {
+            return bindings::k_HSteamListenSocket_Invalid;
+        }
+
+        let socket = Self::next_api_call(&mut state) as u32;
+        state
+            .listen_sockets
+            .insert(socket, FakeListenSocket::Ip(addr));
+        socket
+    }
+
+    fn create_listen_socket_p2p(&self, virtual_port: i32) -> u32 {
+        let mut state = self.state();
+        let taken = state.listen_sockets.values().any(|socket| match socket {
+            FakeListenSocket::Ip(_) => false,
+            FakeListenSocket::P2p(port) => *port == virtual_port,
+        });
+        if taken {
+            return bindings::k_HSteamListenSocket_Invalid;
+        }
+
+        let socket = Self::next_api_call(&mut state) as u32;
+        state
+            .listen_sockets
+            .insert(socket, FakeListenSocket::P2p(virtual_port));
+        socket
+    }
+
+    fn get_listen_socket_address(&self, socket: u32) -> Option<SocketAddr> {
+        match self.state().listen_sockets.get(&socket)? {
+            FakeListenSocket::Ip(addr) => Some(*addr),
+            FakeListenSocket::P2p(_) => None,
+        }
+    }
+
+    fn close_listen_socket(&self, socket: u32) -> bool {
+        let mut state = self.state();
+        if state.listen_sockets.remove(&socket).is_none() {
+            return false;
+        }
+
+        let accepted: Vec<u32> = state
+            .connections
+            .iter()
+            .filter(|(_, connection)| connection.listen_socket == Some(socket))
+            .map(|(conn, _)| *conn)
+            .collect();
+        for conn in accepted {
+            state.close_connection(conn, 0, "Listen socket closed");
+        }
+        true
+    }
+
+    fn connect_by_ip_address(&self, addr: SocketAddr) -> u32 {
+        let mut state = self.state();
+        // Only loopback connections can reach a fake socket
+        let listen_socket = state
+            .listen_sockets
+            .iter()
+            .find(|(_, socket)| match socket {
+                FakeListenSocket::Ip(bound) => {
+                    bound.port() == addr.port()
+                        && addr.ip().is_loopback()
+                        && (bound.ip().is_unspecified() || bound.ip().is_loopback())
+                }
+                FakeListenSocket::P2p(_) => false,
+            })
+            .map(|(socket, _)| *socket);
+
+        let identity = NetworkingIdentity::IpAddress(addr);
+        state.connect(listen_socket, identity, Some(addr))
+    }
+
+    fn connect_p2p(&self, identity: &NetworkingIdentity, virtual_port: i32) -> u32 {
+        let mut state = self.state();
+        let listen_socket = state
+            .listen_sockets
+            .iter()
+            .find(|(_, socket)| matches!(socket, FakeListenSocket::P2p(port) if *port == virtual_port))
+            .map(|(socket, _)| *socket)
+            .filter(|_| *identity == NetworkingIdentity::SteamId(state.steam_id));
+
+        state.connect(listen_socket, identity.clone(), None)
+    }
+
+    fn accept_connection(&self, conn: u32) -> SteamResult {
+        let mut state = self.state();
+        let Some(connection) = state.connections.get(&conn) else {
+            return SteamResult::InvalidParameter;
+        };
+        if connection.listen_socket.is_none()
+            || connection.state != NetworkingConnectionState::Connecting
+        {
+            return SteamResult::InvalidState;
+        }
+
+        let peer = connection.peer;
+        state.set_connection_state(conn, NetworkingConnectionState::Connected);
+        if let Some(peer) = peer {
+            state.set_connection_state(peer, NetworkingConnectionState::Connected);
+        }
+        SteamResult::Ok
+    }
+
+    fn close_connection(&self, conn: u32, reason: i32, debug: &str, _linger: bool) -> bool {
+        self.state().close_connection(conn, reason, debug)
+    }
+
+    fn get_connection_info(&self, conn: u32) -> Option<NetConnectionInfo> {
+        self.state()
+            .connections
+            .get(&conn)
+            .map(|connection| connection.info())
+    }
+
+    fn send_message_to_connection(&self, conn: u32, data: &[u8], flags: SendFlags) -> SResult<i64> {
+        let mut state = self.state();
+        let connection = state
+            .connections
+            .get_mut(&conn)
+            .ok_or(SteamResult::InvalidParameter)?;
+        if connection.state != NetworkingConnectionState::Connected {
+            return Err(SteamResult::InvalidState);
+        }
+        let peer = connection.peer.ok_or(SteamResult::NoConnection)?;
+        let message_number = connection.next_message_number;
+        connection.next_message_number += 1;
+
+        let receiver = state
+            .connections
+            .get_mut(&peer)
+            .ok_or(SteamResult::NoConnection)?;
+        let message = NetworkingMessage::new(
+            Box::new(data.to_vec()),
+            peer,
+            receiver.remote_identity.clone(),
+            0,
+            flags.contains(SendFlags::RELIABLE),
+            message_number,
+        );
+        receiver.inbound.push_back(message);
+        Ok(message_number)
+    }
+
+    fn flush_messages_on_connection(&self, conn: u32) -> SteamResult {
+        match self.state().connections.contains_key(&conn) {
+            true => SteamResult::Ok,
+            false => SteamResult::InvalidParameter,
+        }
+    }
+
+    fn receive_messages_on_connection(&self, conn: u32, max: usize) -> Vec<NetworkingMessage> {
+        self.state().take_messages(&[conn], max)
+    }
+
+    fn create_poll_group(&self) -> u32 {
+        let mut state = self.state();
+        let group = Self::next_api_call(&mut state) as u32;
+        state.poll_groups.push(group);
+        group
+    }
+
+    fn destroy_poll_group(&self, group: u32) -> bool {
+        let mut state = self.state();
+        let Some(idx) = state.poll_groups.iter().position(|&g| g == group) else {
+            return false;
+        };
+        state.poll_groups.remove(idx);
+        for connection in state.connections.values_mut() {
+            if connection.poll_group == Some(group) {
+                connection.poll_group = None;
+            }
+        }
+        true
+    }
+
+    fn set_connection_poll_group(&self, conn: u32, group: u32) -> bool {
+        let mut state = self.state();
+        let group = match group {
+            bindings::k_HSteamNetPollGroup_Invalid => None,
+            group if state.poll_groups.contains(&group) => Some(group),
+            _ => return false,
+        };
+        match state.connections.get_mut(&conn) {
+            Some(connection) => {
+                connection.poll_group = group;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn receive_messages_on_poll_group(&self, group: u32, max: usize) -> Vec<NetworkingMessage> {
+        let mut state = self.state();
+        let members: Vec<u32> = state
+            .connections
+            .iter()
+            .filter(|(_, connection)| connection.poll_group == Some(group))
+            .map(|(conn, _)| *conn)
+            .collect();
+        state.take_messages(&members, max)
+    }
+}
+
+impl NetworkingMessagesBackend for FakeBackend {
+    fn send_message_to_user(
+        &self,
+        identity: &NetworkingIdentity,
+        data: &[u8],
+        flags: SendFlags,
+        channel: i32,
+    ) -> SteamResult {
+        let mut state = self.state();
+        if *identity == NetworkingIdentity::Invalid {
+            return SteamResult::InvalidParameter;
+        }
+        let local_identity = NetworkingIdentity::SteamId(state.steam_id);
+
+        // Talking to a user accepts any session they requested
+        let FakeState {
+            sessions,
+            session_messages,
+            sent_session_messages,
+            ..
+        } = &mut *state;
+        let session = sessions
+            .entry(identity.clone())
+            .or_insert_with(|| FakeSession::new(true));
+        session.accepted = true;
+        session_messages.extend(session.pending.drain(..));
+        if !session.channels.contains(&channel) {
+            session.channels.push(channel);
+        }
+
+        // Messages to ourselves loop straight back
+        if *identity == local_identity {
+            let message_number = session.next_message_number;
+            session.next_message_number += 1;
+            session_messages.push_back(NetworkingMessage::new(
+                Box::new(data.to_vec()),
+                bindings::k_HSteamNetConnection_Invalid,
+                local_identity,
+                channel,
+                flags.contains(SendFlags::RELIABLE),
+                message_number,
+            ));
+        } else {
+            sent_session_messages.push((identity.clone(), channel, data.to_vec()));
+        }
+        SteamResult::Ok
+    }
+
+    fn receive_messages_on_channel(&self, channel: i32, max: usize) -> Vec<NetworkingMessage> {
+        let mut state = self.state();
+        let mut messages = Vec::new();
+        let mut remaining = VecDeque::new();
+        for message in state.session_messages.drain(..) {
+            if message.channel() == channel && messages.len() < max {
+                messages.push(message);
+            } else {
+                remaining.push_back(message);
+            }
+        }
+        state.session_messages = remaining;
+        messages
+    }
+
+    fn accept_session_with_user(&self, identity: &NetworkingIdentity) -> bool {
+        let mut state = self.state();
+        let FakeState {
+            sessions,
+            session_messages,
+            ..
+        } = &mut *state;
+        match sessions.get_mut(identity) {
+            Some(session) if !session.accepted => {
+                session.accepted = true;
+                session_messages.extend(session.pending.drain(..));
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn close_session_with_user(&self, identity: &NetworkingIdentity) -> bool {
+        let mut state = self.state();
+        if state.sessions.remove(identity).is_none() {
+            return false;
+        }
+        state
+            .session_messages
+            .retain(|message| message.peer() != identity);
+        true
+    }
+
+    fn close_channel_with_user(&self, identity: &NetworkingIdentity, channel: i32) -> bool {
+        let mut state = self.state();
+        let Some(session) = state.sessions.get_mut(identity) else {
+            return false;
+        };
+        let Some(idx) = session.channels.iter().position(|&c| c == channel) else {
+            return false;
+        };
+        session.channels.remove(idx);
+        if session.channels.is_empty() {
+            state.sessions.remove(identity);
+        }
+        state
+            .session_messages
+            .retain(|message| message.peer() != identity || message.channel() != channel);
+        true
+    }
+
+    fn get_session_connection_info(
+        &self,
+        identity: &NetworkingIdentity,
+    ) -> Option<NetConnectionInfo> {
+        self.state()
+            .sessions
+            .get(identity)
+            .map(|session| session.info(identity))
+    }
+}
+
+impl MatchmakingBackend for FakeBackend {
+    fn add_request_lobby_list_string_filter(
+        &self,
+        key: &str,
+        value: &str,
+        comparison: LobbyComparison,
+    ) {
+        self.state()
+            .lobby_filters
+            .strings
+            .push((key.to_owned(), value.to_owned(), comparison));
+    }
+
+    fn add_request_lobby_list_numerical_filter(
+        &self,
+        key: &str,
+        value: i32,
+        comparison: LobbyComparison,
+    ) {
+        self.state()
+            .lobby_filters
+            .numbers
+            .push((key.to_owned(), value, comparison));
+    }
+
+    fn add_request_lobby_list_near_value_filter(&self, key: &str, value: i32) {
+        self.state()
+            .lobby_filters
+            .near
+            .push((key.to_owned(), value));
+    }
+
+    fn add_request_lobby_list_filter_slots_available(&self, slots: i32) {
+        self.state().lobby_filters.slots_available = Some(slots.max(0) as u32);
+    }
+
+    // Every fake lobby is in the same region
+    fn add_request_lobby_list_distance_filter(&self, _distance: LobbyDistanceFilter) {}
+
+    fn add_request_lobby_list_result_count_filter(&self, max_results: i32) {
+        self.state().lobby_filters.max_results = Some(max_results.max(0) as usize);
+    }
+
+    fn request_lobby_list(&self) -> SteamAPICall {
+        let mut state = self.state();
+        let api_call = Self::next_api_call(&mut state);
+        let filters = std::mem::take(&mut state.lobby_filters);
+
+        let mut matching: Vec<&FakeLobby> = state
+            .lobbies
+            .iter()
+            .filter(|lobby| filters.matches(lobby))
+            .collect();
+        matching.sort_by_cached_key(|lobby| filters.distances(lobby));
+        if let Some(max_results) = filters.max_results {
+            matching.truncate(max_results);
+        }
+
+        state.lobby_list = matching.iter().map(|lobby| lobby.id).collect();
+        let lobbies_matching = state.lobby_list.len() as u32;
+        state.push_call_result(api_call, LobbyMatchList { lobbies_matching });
+        api_call
+    }
+
+    fn get_lobby_by_index(&self, idx: i32) -> SteamId {
+        let state = self.state();
+        state
+            .lobby_list
+            .get(idx as usize)
+            .copied()
+            .unwrap_or(SteamId(0))
+    }
+
+    fn create_lobby(&self, lobby_type: LobbyType, max_members: i32) -> SteamAPICall {
+        let mut state = self.state();
+        let api_call = Self::next_api_call(&mut state);
+        let lobby = SteamId(LOBBY_ID_BASE + api_call);
+
+        let mut fake = FakeLobby::new(lobby, state.steam_id);
+        fake.lobby_type = lobby_type;
+        fake.max_members = max_members.max(0) as u32;
+        state.lobbies.push(fake);
+
+        state.push_call_result(
+            api_call,
+            LobbyCreated {
+                result: SteamResult::Ok,
+                lobby,
+            },
+        );
+        state.push_lobby_enter(api_call, lobby, ChatRoomEnterResponse::Success);
+        api_call
+    }
+
+    fn join_lobby(&self, lobby: SteamId) -> SteamAPICall {
+        let mut state = self.state();
+        let api_call = Self::next_api_call(&mut state);
+        let steam_id = state.steam_id;
+
+        let response = match state.lobby_mut(lobby) {
+            None => ChatRoomEnterResponse::DoesntExist,
+            Some(fake) if fake.members.contains(&steam_id) => ChatRoomEnterResponse::Success,
+            Some(fake)
+                if !fake.joinable
+                    || matches!(
+                        fake.lobby_type,
+                        LobbyType::Private | LobbyType::PrivateUnique
+                    ) =>
+            {
+                ChatRoomEnterResponse::NotAllowed
+            }
+            Some(fake) if fake.members.len() >= fake.max_members as usize => {
+                ChatRoomEnterResponse::Full
+            }
+            Some(fake) => {
+                fake.members.push(steam_id);
+                ChatRoomEnterResponse::Success
+            }
+        };
+
+        state.push_lobby_enter(api_call, lobby, response);
+        api_call
+    }
+
+    fn leave_lobby(&self, lobby: SteamId) {
+        let mut state = self.state();
+        let steam_id = state.steam_id;
+        let Some(fake) = state.lobby_mut(lobby) else {
+            return;
+        };
+
+        fake.members.retain(|member| *member != steam_id);
+        match fake.members.first() {
+            Some(first) if fake.owner == steam_id => fake.owner = *first,
+            Some(_) => {}
+            None => state.lobbies.retain(|fake| fake.id != lobby),
+        }
+    }
+
+    fn get_num_lobby_members(&self, lobby: SteamId) -> i32 {
+        let state = self.state();
+        state
+            .joined_lobby(lobby)
+            .map_or(0, |fake| fake.members.len() as i32)
+    }
+
+    fn get_lobby_member_by_index(&self, lobby: SteamId, idx: i32) -> SteamId {
+        let state = self.state();
+        state
+            .joined_lobby(lobby)
+            .and_then(|fake| fake.members.get(idx as usize).copied())
+            .unwrap_or(SteamId(0))
+    }
+
+    fn get_lobby_owner(&self, lobby: SteamId) -> SteamId {
+        let state = self.state();
+        state
+            .joined_lobby(lobby)
+            .map_or(SteamId(0), |fake| fake.owner)
+    }
+
+    fn set_lobby_owner(&self, lobby: SteamId, owner: SteamId) -> bool {
+        let mut state = self.state();
+        let Some(fake) = state.owned_lobby_mut(lobby) else {
+            return false;
+        };
+        if !fake.members.contains(&owner) {
+            return false;
+        }
+
+        fake.owner = owner;
+        state.push_callback(LobbyDataUpdate {
+            lobby,
+            member: lobby,
+            success: true,
+        });
+        true
+    }
+
+    fn get_lobby_member_limit(&self, lobby: SteamId) -> i32 {
+        let mut state = self.state();
+        state
+            .lobby_mut(lobby)
+            .map_or(0, |fake| fake.max_members as i32)
+    }
+
+    fn set_lobby_member_limit(&self, lobby: SteamId, max_members: i32) -> bool {
+        let mut state = self.state();
+        let Some(fake) = state.owned_lobby_mut(lobby) else {
+            return false;
+        };
+        fake.max_members = max_members.max(0) as u32;
+        true
+    }
+
+    fn set_lobby_type(&self, lobby: SteamId, lobby_type: LobbyType) -> bool {
+        let mut state = self.state();
+        let Some(fake) = state.owned_lobby_mut(lobby) else {
+            return false;
+        };
+        fake.lobby_type = lobby_type;
+        true
+    }
+
+    fn set_lobby_joinable(&self, lobby: SteamId, joinable: bool) -> bool {
+        let mut state = self.state();
+        let Some(fake) = state.owned_lobby_mut(lobby) else {
+            return false;
+        };
+        fake.joinable = joinable;
+        true
+    }
+
+    fn get_lobby_data(&self, lobby: SteamId, key: &str) -> String {
+        let mut state = self.state();
+        state
+            .lobby_mut(lobby)
+            .and_then(|fake| fake.data.get(key).cloned())
+            .unwrap_or_default()
+    }
+
+    fn set_lobby_data(&self, lobby: SteamId, key: &str, value: &str) -> bool {
+        let mut state = self.state();
+        let Some(fake) = state.owned_lobby_mut(lobby) else {
+            return false;
+        };
+
+        fake.data.insert(key.to_owned(), value.to_owned());
+        state.push_callback(LobbyDataUpdate {
+            lobby,
+            member: lobby,
+            success: true,
+        });
+        true
+    }
+
+    fn delete_lobby_data(&self, lobby: SteamId, key: &str) -> bool {
+        let mut state = self.state();
+        let Some(fake) = state.owned_lobby_mut(lobby) else {
+            return false;
+        };
+
+        if fake.data.remove(key).is_some() {
+            state.push_callback(LobbyDataUpdate {
+                lobby,
+                member: lobby,
+                success: true,
+            });
+        }
+        true
+    }
+
+    fn get_lobby_data_count(&self, lobby: SteamId) -> i32 {
+        let mut state = self.state();
+        state
+            .lobby_mut(lobby)
+            .map_or(0, |fake| fake.data.len() as i32)
+    }
+
+    fn get_lobby_data_by_index(&self, lobby: SteamId, idx: i32) -> Option<(String, String)> {
+        let mut state = self.state();
+        let (key, value) = state.lobby_mut(lobby)?.data.iter().nth(idx as usize)?;
+        Some((key.clone(), value.clone()))
+    }
+
+    fn request_lobby_data(&self, lobby: SteamId) -> bool {
+        let mut state = self.state();
+        let success = state.lobby_mut(lobby).is_some();
+        state.push_callback(LobbyDataUpdate {
+            lobby,
+            member: lobby,
+            success,
+        });
+        true
+    }
+
+    fn get_lobby_member_data(&self, lobby: SteamId, member: SteamId, key: &str) -> String {
+        let state = self.state();
+        state
+            .joined_lobby(lobby)
+            .and_then(|fake| fake.member_data.get(&(member, key.to_owned())).cloned())
+            .unwrap_or_default()
+    }
+
+    fn set_lobby_member_data(&self, lobby: SteamId, key: &str, value: &str) {
+        let mut state = self.state();
+        let steam_id = state.steam_id;
+        let Some(fake) = state.lobby_mut(lobby) else {
+            return;
+        };
+        if !fake.members.contains(&steam_id) {
+            return;
+        }
+
+        fake.member_data
+            .insert((steam_id, key.to_owned()), value.to_owned());
+        state.push_callback(LobbyDataUpdate {
+            lobby,
+            member: steam_id,
+            success: true,
+        });
+    }
+
+    fn send_lobby_chat_msg(&self, lobby: SteamId, data: &[u8]) -> bool {
+        let mut state = self.state();
+        let steam_id = state.steam_id;
+        let Some(fake) = state.lobby_mut(lobby) else {
+            return false;
+        };
+        if !fake.members.contains(&steam_id) {
+            return false;
+        }
+
+        let message = fake.push_chat(steam_id, data.to_vec());
+        state.push_callback(message);
+        true
+    }
+
+    fn get_lobby_chat_entry(&self, lobby: SteamId, chat_id: i32) -> Option<LobbyChatEntry> {
+        let state = self.state();
+        state
+            .joined_lobby(lobby)?
+            .chat
+            .get(chat_id as usize)
+            .cloned()
+    }
+}
+
+/// What a [`FakeServer`] was told to advertise about itself
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FakeServerDetails {
+    pub product: String,
+    pub game_description: String,
+    pub dedicated: bool,
+    pub server_name: String,
+    pub map_name: String,
+    pub max_players: u32,
+    pub advertised: bool,
+}
+
+// Game server ids, with the account type set to persistent or anonymous game server
+const GAME_SERVER_ID_BASE: u64 = 0x0130_0000_0000_0000;
+const ANON_GAME_SERVER_ID_BASE: u64 = 0x0140_0000_0000_0000;
+
+struct FakeServerState {
+    mode: ServerMode,
+    login_tokens: Vec<String>,
+    // Set while logged on
+    steam_id: Option<SteamId>,
+    details: FakeServerDetails,
+    auth_sessions: Vec<SteamId>,
+    callbacks: VecDeque<CallbackMessage>,
+}
+
+impl FakeServerState {
+    fn push_callback<C: Callback>(&mut self, callback: C) {
+        self.callbacks.push_back(CallbackMessage::Callback {
+            id: C::ID,
+            payload: Payload::Value(Box::new(callback)),
+        });
+    }
+
+    fn connect(&mut self, steam_id: SteamId) {
+        self.steam_id = Some(steam_id);
+        let secure = self.mode == ServerMode::AuthenticationAndSecure;
+        self.push_callback(SteamServersConnected);
+        self.push_callback(GSPolicyResponse { secure });
+    }
+}
+
+/// An in-process game server backend, the [`FakeBackend`] of `steam_api::init_server_with`
+///
+/// Clones share the same state, so keep one around to inspect the server from tests.
+#[derive(Clone)]
+pub struct FakeServer {
+    state: Arc<Mutex<FakeServerState>>,
+}
+
+impl Default for FakeServer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FakeServer {
+    pub fn new() -> Self {
+        Self {
+            state: Arc::new(Mutex::new(FakeServerState {
+                mode: ServerMode::Authentication,
+                login_tokens: Vec::new(),
+                steam_id: None,
+                details: FakeServerDetails::default(),
+                auth_sessions: Vec::new(),
+                callbacks: VecDeque::new(),
+            })),
+        }
+    }
+
+    /// Sets the mode the server was initialized with, `Authentication` by default
+    pub fn with_mode(self, mode: ServerMode) -> Self {
+        self.state().mode = mode;
+        self
+    }
+
+    /// Adds a login token accepted by `Server::log_on`, any other token is denied
+    pub fn with_login_token(self, token: impl Into<String>) -> Self {
+        self.state().login_tokens.push(token.into());
+        self
+    }
+
+    pub fn details(&self) -> FakeServerDetails {
+        self.state().details.clone()
+    }
+
+    /// Users with an open auth session, in the order they were started
+    pub fn auth_sessions(&self) -> Vec<SteamId> {
+        self.state().auth_sessions.clone()
+    }
+
+    /// Queues the result of a game server api call, see `FakeBackend::queue_call_result`
+    pub fn queue_call_result<C: Callback>(&self, api_call: SteamAPICall, result: C, failed: bool) {
+        self.state()
+            .callbacks
+            .push_back(CallbackMessage::CallResult {
+                api_call,
+                id: C::ID,
+                payload: Payload::Value(Box::new(result)),
+                failed,
+            });
+    }
+
+    fn state(&self) -> MutexGuard<'_, FakeServerState> {
+        self.state.lock().unwrap()
+    }
+}
+
+impl ServerBackend for FakeServer {
+    fn game_server(&self) -> &dyn GameServerBackend {
+        self
+    }
+
+    fn run_frame(&self) {}
+
+    fn next_callback(&self) -> Option<CallbackMessage> {
+        self.state().callbacks.pop_front()
+    }
+
+    fn shutdown(&self) {
+        self.state().callbacks.clear();
+    }
+}
+
+impl GameServerBackend for FakeServer {
+    fn get_steam_id(&self) -> SteamId {
+        self.state().steam_id.unwrap_or(SteamId(0))
+    }
+
+    fn log_on(&self, token: &str) {
+        let mut state = self.state();
+        match state.login_tokens.iter().position(|known| known == token) {
+            Some(idx) => state.connect(SteamId(GAME_SERVER_ID_BASE + idx as u64 + 1)),
+            None => state.push_callback(SteamServerConnectFailure {
+                result: SteamResult::GSLTDenied,
+                still_retrying: false,
+            }),
+        }
+    }
+
+    fn log_on_anonymous(&self) {
+        self.state().connect(SteamId(ANON_GAME_SERVER_ID_BASE + 1));
+    }
+
+    fn log_off(&self) {
+        let mut state = self.state();
+        if state.steam_id.take().is_some() {
+            state.push_callback(SteamServersDisconnected {
+                result: SteamResult::Ok,
+            });
+        }
+    }
+
+    fn logged_on(&self) -> bool {
+        self.state().steam_id.is_some()
+    }
+
+    fn secure(&self) -> bool {
+        let state = self.state();
+        state.steam_id.is_some() && state.mode == ServerMode::AuthenticationAndSecure
+    }
+
+    fn set_product(&self, product: &str) {
+        self.state().details.product = product.to_owned();
+    }
+
+    fn set_game_description(&self, description: &str) {
+        self.state().details.game_description = description.to_owned();
+    }
+
+    fn set_dedicated_server(&self, dedicated: bool) {
+        self.state().details.dedicated = dedicated;
+    }
+
+    fn set_server_name(&self, name: &str) {
+        self.state().details.server_name = name.to_owned();
+    }
+
+    fn set_map_name(&self, map: &str) {
+        self.state().details.map_name = map.to_owned();
+    }
+
+    fn set_max_player_count(&self, max_players: i32) {
+        self.state().details.max_players = max_players.max(0) as u32;
+    }
+
+    fn set_advertise_server_active(&self, active: bool) {
+        self.state().details.advertised = active;
+    }
+
+    fn begin_auth_session(&self, ticket: &[u8], steam_id: SteamId) -> SteamResult {
+        let mut state = self.state();
+        match begin_fake_auth_session(&mut state.auth_sessions, ticket, steam_id) {
+            Ok(response) => {
+                state.push_callback(response);
+                SteamResult::Ok
+            }
+            Err(err) => err,
+        }
+    }
+
+    fn end_auth_session(&self, steam_id: SteamId) {
+        self.state().auth_sessions.retain(|id| *id != steam_id);
+    }
+}
@@ -0,0 +1,430 @@
+//! The layer between the safe wrappers and whatever actually answers their calls.
+//!
+//! [`SteamBackend`] talks to a running Steam client through the flat C API, while
+//! [`FakeBackend`] keeps everything in memory so games can be tested without Steam.
+//! Pass either one to [`steam_api::init_with`](crate::steam_api::init_with).
+
+use std::any::Any;
+use std::ffi::{c_void, OsString};
+
+use crate::callbacks::Callback;
+use std::path::{Path, PathBuf};
+
+use std::net::SocketAddr;
+
+use crate::result::SteamResult;
+use crate::{
+    AppId, DepotId, DlcInfo, FloatingGamepadTextInputMode, FriendFlags, FriendGame,
+    FriendRelationship, GamepadTextInputLineMode, GamepadTextInputMode, InstallInfo,
+    Ipv6ConnectivityProtocol, Ipv6ConnectivityState, ItemState, Leaderboard,
+    LeaderboardDisplayType, LeaderboardEntry, LeaderboardRange, LeaderboardSortMethod,
+    LobbyChatEntry, LobbyComparison, LobbyDistanceFilter, LobbyType, NetConnectionInfo,
+    NetworkingIdentity, NetworkingMessage, PersonaState, PublishedFileDetails, PublishedFileId,
+    SResult, SendFlags, SteamId, TextFieldRect, UgcQueryType, UgcType, Universe, UpdateProgress,
+    UploadScoreMethod, UserList, UserListOrder, Visibility, WorkshopFileType,
+};
+
+pub mod fake;
+pub mod steam;
+
+pub use fake::*;
+pub use steam::*;
+
+/// Handle of an asynchronous steam api call
+pub type SteamAPICall = u64;
+
+/// A source of steam interfaces and callbacks
+pub trait Backend: Send + Sync + 'static {
+    fn user(&self) -> &dyn UserBackend;
+    fn apps(&self) -> &dyn AppsBackend;
+    fn friends(&self) -> &dyn FriendsBackend;
+    fn utils(&self) -> &dyn UtilsBackend;
+    fn user_stats(&self) -> &dyn UserStatsBackend;
+    fn remote_storage(&self) -> &dyn RemoteStorageBackend;
+    fn ugc(&self) -> &dyn UgcBackend;
+    fn networking_sockets(&self) -> &dyn NetworkingSocketsBackend;
+    fn networking_messages(&self) -> &dyn NetworkingMessagesBackend;
+    fn matchmaking(&self) -> &dyn MatchmakingBackend;
+
+    /// Gives the backend a chance to do per-frame work before callbacks are polled
+    fn run_frame(&self);
+    /// Pops the next pending callback, if there is one
+    fn next_callback(&self) -> Option<CallbackMessage>;
+    fn shutdown(&self);
+}
+
+/// A source of game server interfaces and callbacks, kept apart from the client's
+pub trait ServerBackend: Send + Sync + 'static {
+    fn game_server(&self) -> &dyn GameServerBackend;
+
+    /// Gives the backend a chance to do per-frame work before callbacks are polled
+    fn run_frame(&self);
+    /// Pops the next pending callback, if there is one
+    fn next_callback(&self) -> Option<CallbackMessage>;
+    fn shutdown(&self);
+}
+
+pub trait UserBackend {
+    fn get_steam_id(&self) -> SteamId;
+    fn request_encrypted_app_ticket(&self, data: &[u8]) -> SteamAPICall;
+    /// Copies the ticket into `ticket`, returning whether it fit and the ticket's length
+    fn get_encrypted_app_ticket(&self, ticket: &mut [u8]) -> (bool, u32);
+    /// Writes a session ticket into `ticket`, returning its handle and length
+    fn get_auth_session_ticket(&self, ticket: &mut [u8]) -> Option<(u32, u32)>;
+    fn cancel_auth_ticket(&self, handle: u32);
+    /// The ticket's data is delivered by a `GetTicketForWebApiResponse`
+    #[cfg(feature = "web-api-ticket")]
+    fn get_auth_ticket_for_web_api(&self, identity: &str) -> Option<u32>;
+    fn begin_auth_session(&self, ticket: &[u8], steam_id: SteamId) -> SteamResult;
+    fn end_auth_session(&self, steam_id: SteamId);
+}
+
+pub trait AppsBackend {
+    fn is_subscribed(&self) -> bool;
+    fn is_subscribed_app(&self, id: AppId) -> bool;
+    fn is_app_installed(&self, id: AppId) -> bool;
+    fn get_dlc_count(&self) -> i32;
+    fn get_dlc_data_by_index(&self, idx: i32) -> Option<DlcInfo>;
+    fn install_dlc(&self, id: AppId);
+    fn uninstall_dlc(&self, id: AppId);
+    fn is_dlc_installed(&self, id: AppId) -> bool;
+    fn get_current_beta_name(&self) -> Option<String>;
+    fn get_current_game_language(&self) -> String;
+    /// Comma separated, as steam hands them out
+    fn get_available_game_languages(&self) -> String;
+    fn get_app_install_dir(&self, id: AppId) -> Option<PathBuf>;
+    fn get_app_build_id(&self) -> i32;
+    fn get_installed_depots(&self, id: AppId) -> Vec<DepotId>;
+    fn get_app_owner(&self) -> SteamId;
+    fn is_subscribed_from_family_sharing(&self) -> bool;
+    fn is_subscribed_from_free_weekend(&self) -> bool;
+    /// The seconds allowed and played, if the app is a timed trial
+    fn is_timed_trial(&self) -> Option<(u32, u32)>;
+    fn is_vac_banned(&self) -> bool;
+    fn get_launch_query_param(&self, key: &str) -> Option<String>;
+    fn get_launch_command_line(&self) -> String;
+    /// The arguments the process itself was started with, which may not be valid UTF-8
+    fn get_process_args(&self) -> Vec<OsString>;
+}
+
+pub trait FriendsBackend {
+    fn get_persona_name(&self) -> String;
+    fn get_friend_count(&self, flags: FriendFlags) -> i32;
+    fn get_friend_by_index(&self, idx: i32, flags: FriendFlags) -> SteamId;
+    fn get_friend_persona_name(&self, id: SteamId) -> String;
+    fn get_small_friend_avatar(&self, id: SteamId) -> i32;
+    fn get_medium_friend_avatar(&self, id: SteamId) -> i32;
+    fn get_large_friend_avatar(&self, id: SteamId) -> i32;
+    fn set_rich_presence(&self, key: &str, value: &str) -> bool;
+    fn clear_rich_presence(&self);
+    fn get_friend_rich_presence(&self, id: SteamId, key: &str) -> Option<String>;
+    fn get_friend_rich_presence_key_count(&self, id: SteamId) -> i32;
+    fn get_friend_rich_presence_key_by_index(&self, id: SteamId, idx: i32) -> Option<String>;
+    fn request_friend_rich_presence(&self, id: SteamId);
+    fn get_friend_persona_state(&self, id: SteamId) -> PersonaState;
+    fn get_friend_game_played(&self, id: SteamId) -> Option<FriendGame>;
+    fn get_friend_steam_level(&self, id: SteamId) -> i32;
+    fn get_player_nickname(&self, id: SteamId) -> Option<String>;
+    fn get_friend_relationship(&self, id: SteamId) -> FriendRelationship;
+    fn request_user_information(&self, id: SteamId, name_only: bool) -> bool;
+}
+
+pub trait UtilsBackend {
+    fn get_app_id(&self) -> AppId;
+    /// Seconds since the unix epoch
+    fn get_server_real_time(&self) -> u32;
+    fn get_ip_country(&self) -> String;
+    fn get_steam_ui_language(&self) -> String;
+    fn get_connected_universe(&self) -> Universe;
+    fn get_seconds_since_app_active(&self) -> u32;
+    fn get_seconds_since_computer_active(&self) -> u32;
+    /// Percent, or 255 when running on AC power
+    fn get_current_battery_power(&self) -> u8;
+    fn is_steam_running_on_steam_deck(&self) -> bool;
+    fn is_steam_in_big_picture_mode(&self) -> bool;
+    fn is_steam_running_in_vr(&self) -> bool;
+    fn is_steam_china_launcher(&self) -> bool;
+    fn get_ipv6_connectivity_state(
+        &self,
+        protocol: Ipv6ConnectivityProtocol,
+    ) -> Ipv6ConnectivityState;
+    fn show_gamepad_text_input(
+        &self,
+        mode: GamepadTextInputMode,
+        line_mode: GamepadTextInputLineMode,
+        description: &str,
+        max_chars: u32,
+        existing: &str,
+    ) -> bool;
+    fn get_entered_gamepad_text_length(&self) -> u32;
+    /// Copies the submitted text and a nul terminator into `text`, failing if it doesn't fit
+    fn get_entered_gamepad_text_input(&self, text: &mut [u8]) -> bool;
+    fn show_floating_gamepad_text_input(
+        &self,
+        mode: FloatingGamepadTextInputMode,
+        field: TextFieldRect,
+    ) -> bool;
+    fn dismiss_floating_gamepad_text_input(&self) -> bool;
+    fn get_image_size(&self, handle: i32) -> Option<(u32, u32)>;
+    /// Copies an image into `dest`, failing if it doesn't fit
+    fn get_image_rgba(&self, handle: i32, dest: &mut [u8]) -> bool;
+}
+
+pub trait UserStatsBackend {
+    fn request_current_stats(&self) -> bool;
+    fn get_stat_i32(&self, name: &str) -> Option<i32>;
+    fn get_stat_f32(&self, name: &str) -> Option<f32>;
+    fn set_stat_i32(&self, name: &str, value: i32) -> bool;
+    fn set_stat_f32(&self, name: &str, value: f32) -> bool;
+    fn get_achievement(&self, name: &str) -> Option<bool>;
+    fn set_achievement(&self, name: &str) -> bool;
+    fn clear_achievement(&self, name: &str) -> bool;
+    fn indicate_achievement_progress(&self, name: &str, current: u32, max: u32) -> bool;
+    fn store_stats(&self) -> bool;
+    fn find_or_create_leaderboard(
+        &self,
+        name: &str,
+        sort_method: LeaderboardSortMethod,
+        display_type: LeaderboardDisplayType,
+    ) -> SteamAPICall;
+    fn upload_leaderboard_score(
+        &self,
+        leaderboard: Leaderboard,
+        method: UploadScoreMethod,
+        score: i32,
+        details: &[i32],
+    ) -> SteamAPICall;
+    fn download_leaderboard_entries(
+        &self,
+        leaderboard: Leaderboard,
+        range: LeaderboardRange,
+    ) -> SteamAPICall;
+    /// Reads an entry out of a [`LeaderboardScoresDownloaded`](crate::callbacks::LeaderboardScoresDownloaded)
+    fn get_downloaded_leaderboard_entry(
+        &self,
+        entries: u64,
+        index: i32,
+    ) -> Option<LeaderboardEntry>;
+}
+
+pub trait RemoteStorageBackend {
+    fn file_write(&self, name: &str, data: &[u8]) -> bool;
+    /// Returns the number of bytes read
+    fn file_read(&self, name: &str, dest: &mut [u8]) -> i32;
+    fn file_delete(&self, name: &str) -> bool;
+    fn file_exists(&self, name: &str) -> bool;
+    fn get_file_size(&self, name: &str) -> i32;
+    /// Seconds since the unix epoch
+    fn get_file_timestamp(&self, name: &str) -> i64;
+    /// Total and available bytes
+    fn get_quota(&self) -> Option<(u64, u64)>;
+    fn get_file_count(&self) -> i32;
+    fn get_file_name_and_size(&self, idx: i32) -> Option<(String, i32)>;
+    /// Returns `k_UGCFileStreamHandleInvalid` on failure
+    fn file_write_stream_open(&self, name: &str) -> u64;
+    fn file_write_stream_write_chunk(&self, handle: u64, data: &[u8]) -> bool;
+    fn file_write_stream_close(&self, handle: u64) -> bool;
+    fn file_write_stream_cancel(&self, handle: u64) -> bool;
+}
+
+pub trait UgcBackend {
+    /// Returns `k_UGCQueryHandleInvalid` on failure
+    fn create_query_user_ugc_request(
+        &self,
+        account_id: u32,
+        list: UserList,
+        ugc_type: UgcType,
+        order: UserListOrder,
+        app_id: AppId,
+        page: u32,
+    ) -> u64;
+    /// Returns `k_UGCQueryHandleInvalid` on failure
+    fn create_query_all_ugc_request(
+        &self,
+        query_type: UgcQueryType,
+        ugc_type: UgcType,
+        app_id: AppId,
+        page: u32,
+    ) -> u64;
+    fn add_required_tag(&self, handle: u64, tag: &str) -> bool;
+    fn add_excluded_tag(&self, handle: u64, tag: &str) -> bool;
+    fn set_match_any_tag(&self, handle: u64, match_any: bool) -> bool;
+    fn set_search_text(&self, handle: u64, text: &str) -> bool;
+    fn set_return_long_description(&self, handle: u64, long_description: bool) -> bool;
+    fn send_query_ugc_request(&self, handle: u64) -> SteamAPICall;
+    fn get_query_ugc_result(&self, handle: u64, index: u32) -> Option<PublishedFileDetails>;
+    fn release_query_ugc_request(&self, handle: u64) -> bool;
+
+    fn create_item(&self, app_id: AppId, file_type: WorkshopFileType) -> SteamAPICall;
+    /// Returns `k_UGCUpdateHandleInvalid` on failure
+    fn start_item_update(&self, app_id: AppId, id: PublishedFileId) -> u64;
+    fn set_item_title(&self, handle: u64, title: &str) -> bool;
+    fn set_item_description(&self, handle: u64, description: &str) -> bool;
+    fn set_item_content(&self, handle: u64, folder: &Path) -> bool;
+    fn set_item_preview(&self, handle: u64, file: &Path) -> bool;
+    fn set_item_tags(&self, handle: u64, tags: &[&str]) -> bool;
+    fn set_item_visibility(&self, handle: u64, visibility: Visibility) -> bool;
+    fn submit_item_update(&self, handle: u64, change_note: Option<&str>) -> SteamAPICall;
+    fn get_item_update_progress(&self, handle: u64) -> UpdateProgress;
+
+    fn subscribe_item(&self, id: PublishedFileId) -> SteamAPICall;
+    fn unsubscribe_item(&self, id: PublishedFileId) -> SteamAPICall;
+    fn download_item(&self, id: PublishedFileId, high_priority: bool) -> bool;
+    fn get_item_state(&self, id: PublishedFileId) -> ItemState;
+    fn get_item_install_info(&self, id: PublishedFileId) -> Option<InstallInfo>;
+    fn get_item_download_info(&self, id: PublishedFileId) -> Option<(u64, u64)>;
+    fn get_subscribed_items(&self) -> Vec<PublishedFileId>;
+}
+
+/// Handles are the sdk's `HSteamListenSocket`, `HSteamNetConnection` and `HSteamNetPollGroup`,
+/// with 0 meaning invalid
+pub trait NetworkingSocketsBackend {
+    fn create_listen_socket_ip(&self, addr: SocketAddr) -> u32;
+    fn create_listen_socket_p2p(&self, virtual_port: i32) -> u32;
+    fn get_listen_socket_address(&self, socket: u32) -> Option<SocketAddr>;
+    fn close_listen_socket(&self, socket: u32) -> bool;
+
+    fn connect_by_ip_address(&self, addr: SocketAddr) -> u32;
+    fn connect_p2p(&self, identity: &NetworkingIdentity, virtual_port: i32) -> u32;
+    fn accept_connection(&self, conn: u32) -> SteamResult;
+    fn close_connection(&self, conn: u32, reason: i32, debug: &str, linger: bool) -> bool;
+    fn get_connection_info(&self, conn: u32) -> Option<NetConnectionInfo>;
+
+    /// Returns the message number on success
+    fn send_message_to_connection(&self, conn: u32, data: &[u8], flags: SendFlags) -> SResult<i64>;
+    fn flush_messages_on_connection(&self, conn: u32) -> SteamResult;
+    fn receive_messages_on_connection(&self, conn: u32, max: usize) -> Vec<NetworkingMessage>;
+
+    fn create_poll_group(&self) -> u32;
+    fn destroy_poll_group(&self, group: u32) -> bool;
+    fn set_connection_poll_group(&self, conn: u32, group: u32) -> bool;
+    fn receive_messages_on_poll_group(&self, group: u32, max: usize) -> Vec<NetworkingMessage>;
+}
+
+pub trait NetworkingMessagesBackend {
+    fn send_message_to_user(
+        &self,
+        identity: &NetworkingIdentity,
+        data: &[u8],
+        flags: SendFlags,
+        channel: i32,
+    ) -> SteamResult;
+    fn receive_messages_on_channel(&self, channel: i32, max: usize) -> Vec<NetworkingMessage>;
+    fn accept_session_with_user(&self, identity: &NetworkingIdentity) -> bool;
+    fn close_session_with_user(&self, identity: &NetworkingIdentity) -> bool;
+    fn close_channel_with_user(&self, identity: &NetworkingIdentity, channel: i32) -> bool;
+    fn get_session_connection_info(
+        &self,
+        identity: &NetworkingIdentity,
+    ) -> Option<NetConnectionInfo>;
+}
+
+pub trait MatchmakingBackend {
+    fn add_request_lobby_list_string_filter(
+        &self,
+        key: &str,
+        value: &str,
+        comparison: LobbyComparison,
+    );
+    fn add_request_lobby_list_numerical_filter(
+        &self,
+        key: &str,
+        value: i32,
+        comparison: LobbyComparison,
+    );
+    fn add_request_lobby_list_near_value_filter(&self, key: &str, value: i32);
+    fn add_request_lobby_list_filter_slots_available(&self, slots: i32);
+    fn add_request_lobby_list_distance_filter(&self, distance: LobbyDistanceFilter);
+    fn add_request_lobby_list_result_count_filter(&self, max_results: i32);
+    fn request_lobby_list(&self) -> SteamAPICall;
+    /// Only valid while handling the `LobbyMatchList` of the last request
+    fn get_lobby_by_index(&self, idx: i32) -> SteamId;
+
+    fn create_lobby(&self, lobby_type: LobbyType, max_members: i32) -> SteamAPICall;
+    fn join_lobby(&self, lobby: SteamId) -> SteamAPICall;
+    fn leave_lobby(&self, lobby: SteamId);
+    fn get_num_lobby_members(&self, lobby: SteamId) -> i32;
+    fn get_lobby_member_by_index(&self, lobby: SteamId, idx: i32) -> SteamId;
+    fn get_lobby_owner(&self, lobby: SteamId) -> SteamId;
+    fn set_lobby_owner(&self, lobby: SteamId, owner: SteamId) -> bool;
+    fn get_lobby_member_limit(&self, lobby: SteamId) -> i32;
+    fn set_lobby_member_limit(&self, lobby: SteamId, max_members: i32) -> bool;
+    fn set_lobby_type(&self, lobby: SteamId, lobby_type: LobbyType) -> bool;
+    fn set_lobby_joinable(&self, lobby: SteamId, joinable: bool) -> bool;
+
+    /// Returns an empty string if the key isn't set
+    fn get_lobby_data(&self, lobby: SteamId, key: &str) -> String;
+    fn set_lobby_data(&self, lobby: SteamId, key: &str, value: &str) -> bool;
+    fn delete_lobby_data(&self, lobby: SteamId, key: &str) -> bool;
+    fn get_lobby_data_count(&self, lobby: SteamId) -> i32;
+    fn get_lobby_data_by_index(&self, lobby: SteamId, idx: i32) -> Option<(String, String)>;
+    fn request_lobby_data(&self, lobby: SteamId) -> bool;
+    /// Returns an empty string if the key isn't set
+    fn get_lobby_member_data(&self, lobby: SteamId, member: SteamId, key: &str) -> String;
+    fn set_lobby_member_data(&self, lobby: SteamId, key: &str, value: &str);
+
+    fn send_lobby_chat_msg(&self, lobby: SteamId, data: &[u8]) -> bool;
+    fn get_lobby_chat_entry(&self, lobby: SteamId, chat_id: i32) -> Option<LobbyChatEntry>;
+}
+
+pub trait GameServerBackend {
+    fn get_steam_id(&self) -> SteamId;
+    fn log_on(&self, token: &str);
+    fn log_on_anonymous(&self);
+    fn log_off(&self);
+    fn logged_on(&self) -> bool;
+    fn secure(&self) -> bool;
+
+    fn set_product(&self, product: &str);
+    fn set_game_description(&self, description: &str);
+    fn set_dedicated_server(&self, dedicated: bool);
+    fn set_server_name(&self, name: &str);
+    fn set_map_name(&self, map: &str);
+    fn set_max_player_count(&self, max_players: i32);
+    fn set_advertise_server_active(&self, active: bool);
+
+    fn begin_auth_session(&self, ticket: &[u8], steam_id: SteamId) -> SteamResult;
+    fn end_auth_session(&self, steam_id: SteamId);
+}
+
+/// The bytes of a received [`NetworkingMessage`], kept by the backend that received
+/// them and released when dropped
+pub trait MessageBuffer: Send + std::fmt::Debug {
+    fn data(&self) -> &[u8];
+}
+
+/// A callback pulled off a backend, waiting to be dispatched
+pub enum CallbackMessage {
+    Callback {
+        id: i32,
+        payload: Payload,
+    },
+    CallResult {
+        api_call: SteamAPICall,
+        id: i32,
+        payload: Payload,
+        failed: bool,
+    },
+}
+
+/// The data carried by a [`CallbackMessage`]
+pub enum Payload {
+    /// The raw struct, as laid out by the steamworks sdk
+    Raw(Vec<u8>),
+    /// An already decoded callback, as queued by a [`FakeBackend`]
+    Value(Box<dyn Any + Send>),
+}
+
+impl Payload {
+    pub(crate) fn decode<C: Callback>(&self) -> Option<C> {
+        match self {
+            Payload::Raw(data) => {
+                if data.len() < C::SIZE as usize {
+                    return None;
+                }
+                Some(unsafe { C::from_raw(data.as_ptr() as *mut c_void) })
+            }
+            Payload::Value(value) => value.downcast_ref::<C>().cloned(),
+        }
+    }
+}
@@ -0,0 +1,2151 @@
+use core::ffi::{c_char, CStr};
+use std::ffi::CString;
+use std::net::{Ipv4Addr, SocketAddr};
+use std::path::{Path, PathBuf};
+
+use super::*;
+use crate::bindings;
+use crate::callbacks::string_from_chars;
+use crate::matchmaking::MAX_LOBBY_CHAT_MESSAGE;
+use crate::networking_types::{ip_addr_from_raw, ip_addr_to_raw};
+use crate::result::SteamResult;
+use crate::{SResult, ServerMode};
+
+/// Backend that forwards every call to the steamworks sdk
+pub struct SteamBackend {
+    user: *mut bindings::ISteamUser,
+    apps: *mut bindings::ISteamApps,
+    friends: *mut bindings::ISteamFriends,
+    utils: *mut bindings::ISteamUtils,
+    user_stats: *mut bindings::ISteamUserStats,
+    remote_storage: *mut bindings::ISteamRemoteStorage,
+    ugc: *mut bindings::ISteamUGC,
+    networking_sockets: *mut bindings::ISteamNetworkingSockets,
+    networking_messages: *mut bindings::ISteamNetworkingMessages,
+    matchmaking: *mut bindings::ISteamMatchmaking,
+}
+
+unsafe impl Send for SteamBackend {}
+unsafe impl Sync for SteamBackend {}
+
+impl SteamBackend {
+    /// Initializes the steamworks API, failing if steam isn't running
+    pub fn new() -> SResult<Self> {
+        unsafe {
+            if !bindings::SteamAPI_Init() {
+                return Err(SteamResult::InitFailed);
+            }
+            bindings::SteamAPI_ManualDispatch_Init();
+
+            Ok(Self {
+                #[cfg(feature = "web-api-ticket")]
+                user: bindings::SteamAPI_SteamUser_v023(),
+                #[cfg(not(feature = "web-api-ticket"))]
+                user: bindings::SteamAPI_SteamUser_v022(),
+                apps: bindings::SteamAPI_SteamApps_v008(),
+                friends: bindings::SteamAPI_SteamFriends_v017(),
+                utils: bindings::SteamAPI_SteamUtils_v010(),
+                user_stats: bindings::SteamAPI_SteamUserStats_v012(),
+                remote_storage: bindings::SteamAPI_SteamRemoteStorage_v016(),
+                ugc: bindings::SteamAPI_SteamUGC_v017(),
+                networking_sockets: bindings::SteamAPI_SteamNetworkingSockets_SteamAPI_v012(),
+                networking_messages: bindings::SteamAPI_SteamNetworkingMessages_SteamAPI_v002(),
+                matchmaking: bindings::SteamAPI_SteamMatchmaking_v009(),
+            })
+        }
+    }
+}
+
+impl Backend for SteamBackend {
+    fn user(&self) -> &dyn UserBackend {
+        self
+    }
+
+    fn apps(&self) -> &dyn AppsBackend {
+        self
+    }
+
+    fn friends(&self) -> &dyn FriendsBackend {
+        self
+    }
+
+    fn utils(&self) -> &dyn UtilsBackend {
+        self
+    }
+
+    fn user_stats(&self) -> &dyn UserStatsBackend {
+        self
+    }
+
+    fn remote_storage(&self) -> &dyn RemoteStorageBackend {
+        self
+    }
+
+    fn ugc(&self) -> &dyn UgcBackend {
+        self
+    }
+
+    fn networking_sockets(&self) -> &dyn NetworkingSocketsBackend {
+        self
+    }
+
+    fn networking_messages(&self) -> &dyn NetworkingMessagesBackend {
+        self
+    }
+
+    fn matchmaking(&self) -> &dyn MatchmakingBackend {
+        self
+    }
+
+    fn run_frame(&self) {
+        unsafe { bindings::SteamAPI_ManualDispatch_RunFrame(bindings::SteamAPI_GetHSteamPipe()) }
+    }
+
+    fn next_callback(&self) -> Option<CallbackMessage> {
+        unsafe { next_callback(bindings::SteamAPI_GetHSteamPipe()) }
+    }
+
+    fn shutdown(&self) {
+        unsafe { bindings::SteamAPI_Shutdown() }
+    }
+}
+
+/// Backend for a dedicated game server, forwarding every call to the steamworks sdk
+pub struct SteamServerBackend {
+    game_server: *mut bindings::ISteamGameServer,
+}
+
+unsafe impl Send for SteamServerBackend {}
+unsafe impl Sync for SteamServerBackend {}
+
+impl SteamServerBackend {
+    /// Initializes the game server api, failing if the ports can't be bound
+    pub fn new(
+        ip: Ipv4Addr,
+        game_port: u16,
+        query_port: u16,
+        mode: ServerMode,
+        version: &str,
+    ) -> SResult<Self> {
+        let version = CString::new(version).map_err(|_| SteamResult::InvalidParameter)?;
+        unsafe {
+            if !bindings::SteamInternal_GameServer_Init(
+                u32::from(ip),
+                0,
+                game_port,
+                query_port,
+                mode.into(),
+                version.as_ptr(),
+            ) {
+                return Err(SteamResult::InitFailed);
+            }
+            bindings::SteamAPI_ManualDispatch_Init();
+
+            Ok(Self {
+                game_server: bindings::SteamAPI_SteamGameServer_v015(),
+            })
+        }
+    }
+}
+
+impl ServerBackend for SteamServerBackend {
+    fn game_server(&self) -> &dyn GameServerBackend {
+        self
+    }
+
+    fn run_frame(&self) {
+        unsafe {
+            bindings::SteamAPI_ManualDispatch_RunFrame(bindings::SteamGameServer_GetHSteamPipe())
+        }
+    }
+
+    fn next_callback(&self) -> Option<CallbackMessage> {
+        unsafe { next_callback(bindings::SteamGameServer_GetHSteamPipe()) }
+    }
+
+    fn shutdown(&self) {
+        unsafe { bindings::SteamGameServer_Shutdown() }
+    }
+}
+
+impl UserBackend for SteamBackend {
+    fn get_steam_id(&self) -> SteamId {
+        unsafe { SteamId(bindings::SteamAPI_ISteamUser_GetSteamID(self.user)) }
+    }
+
+    fn request_encrypted_app_ticket(&self, data: &[u8]) -> SteamAPICall {
+        // Steam copies the data before returning, it is never written to
+        unsafe {
+            bindings::SteamAPI_ISteamUser_RequestEncryptedAppTicket(
+                self.user,
+                data.as_ptr() as *mut _,
+                data.len() as i32,
+            )
+        }
+    }
+
+    fn get_encrypted_app_ticket(&self, ticket: &mut [u8]) -> (bool, u32) {
+        unsafe {
+            let mut ticket_len = 0;
+            let ok = bindings::SteamAPI_ISteamUser_GetEncryptedAppTicket(
+                self.user,
+                ticket.as_mut_ptr() as *mut _,
+                ticket.len() as i32,
+                &mut ticket_len,
+            );
+
+            (ok, ticket_len)
+        }
+    }
+
+    fn get_auth_session_ticket(&self, ticket: &mut [u8]) -> Option<(u32, u32)> {
+        unsafe {
+            let mut ticket_len = 0;
+            let handle = bindings::SteamAPI_ISteamUser_GetAuthSessionTicket(
+                self.user,
+                ticket.as_mut_ptr() as *mut _,
+                ticket.len() as i32,
+                &mut ticket_len,
+                std::ptr::null(),
+            );
+            if handle == bindings::k_HAuthTicketInvalid {
+                return None;
+            }
+
+            Some((handle, ticket_len))
+        }
+    }
+
+    fn cancel_auth_ticket(&self, handle: u32) {
+        unsafe { bindings::SteamAPI_ISteamUser_CancelAuthTicket(self.user, handle) }
+    }
+
+    #[cfg(feature = "web-api-ticket")]
+    fn get_auth_ticket_for_web_api(&self, identity: &str) -> Option<u32> {
+        let identity = CString::new(identity).ok()?;
+        let handle = unsafe {
+            bindings::SteamAPI_ISteamUser_GetAuthTicketForWebApi(self.user, identity.as_ptr())
+        };
+        (handle != bindings::k_HAuthTicketInvalid).then_some(handle)
+    }
+
+    fn begin_auth_session(&self, ticket: &[u8], steam_id: SteamId) -> SteamResult {
+        unsafe {
+            bindings::SteamAPI_ISteamUser_BeginAuthSession(
+                self.user,
+                ticket.as_ptr() as *const _,
+                ticket.len() as i32,
+                steam_id.0,
+            )
+            .into()
+        }
+    }
+
+    fn end_auth_session(&self, steam_id: SteamId) {
+        unsafe { bindings::SteamAPI_ISteamUser_EndAuthSession(self.user, steam_id.0) }
+    }
+}
+
+impl AppsBackend for SteamBackend {
+    fn is_subscribed(&self) -> bool {
+        unsafe { bindings::SteamAPI_ISteamApps_BIsSubscribed(self.apps) }
+    }
+
+    fn is_subscribed_app(&self, id: AppId) -> bool {
+        unsafe { bindings::SteamAPI_ISteamApps_BIsSubscribedApp(self.apps, id.0) }
+    }
+
+    fn is_app_installed(&self, id: AppId) -> bool {
+        unsafe { bindings::SteamAPI_ISteamApps_BIsAppInstalled(self.apps, id.0) }
+    }
+
+    fn get_dlc_count(&self) -> i32 {
+        unsafe { bindings::SteamAPI_ISteamApps_GetDLCCount(self.apps) }
+    }
+
+    fn get_dlc_data_by_index(&self, idx: i32) -> Option<DlcInfo> {
+        let mut app_id = 0;
+        let mut available = false;
+        let mut name = [0 as c_char; 128];
+
+        unsafe {
+            if !bindings::SteamAPI_ISteamApps_BGetDLCDataByIndex(
+                self.apps,
+                idx,
+                &mut app_id,
+                &mut available,
+                name.as_mut_ptr(),
+                name.len() as i32,
+            ) {
+                return None;
+            }
+        }
+
+        Some(DlcInfo {
+            app_id: AppId(app_id),
+            available,
+            name: string_from_chars(&name),
+        })
+    }
+
+    fn install_dlc(&self, id: AppId) {
+        unsafe { bindings::SteamAPI_ISteamApps_InstallDLC(self.apps, id.0) }
+    }
+
+    fn uninstall_dlc(&self, id: AppId) {
+        unsafe { bindings::SteamAPI_ISteamApps_UninstallDLC(self.apps, id.0) }
+    }
+
+    fn is_dlc_installed(&self, id: AppId) -> bool {
+        unsafe { bindings::SteamAPI_ISteamApps_BIsDlcInstalled(self.apps, id.0) }
+    }
+
+    fn get_current_beta_name(&self) -> Option<String> {
+        let mut name = [0 as c_char; 256];
+
+        unsafe {
+            if !bindings::SteamAPI_ISteamApps_GetCurrentBetaName(
+                self.apps,
+                name.as_mut_ptr(),
+                name.len() as i32,
+            ) {
+                return None;
+            }
+        }
+
+        Some(string_from_chars(&name))
+    }
+
+    fn get_current_game_language(&self) -> String {
+        unsafe {
+            let language = bindings::SteamAPI_ISteamApps_GetCurrentGameLanguage(self.apps);
+            CStr::from_ptr(language).to_string_lossy().into_owned()
+        }
+    }
+
+    fn get_available_game_languages(&self) -> String {
+        unsafe {
+            let languages = bindings::SteamAPI_ISteamApps_GetAvailableGameLanguages(self.apps);
+            CStr::from_ptr(languages).to_string_lossy().into_owned()
+        }
+    }
+
+    fn get_app_install_dir(&self, id: AppId) -> Option<PathBuf> {
+        let mut folder = [0 as c_char; 1024];
+
+        // Returns how many bytes were copied, nothing if the app isn't installed
+        let len = unsafe {
+            bindings::SteamAPI_ISteamApps_GetAppInstallDir(
+                self.apps,
+                id.0,
+                folder.as_mut_ptr(),
+                folder.len() as u32,
+            )
+        };
+        if len == 0 {
+            return None;
+        }
+
+        Some(PathBuf::from(string_from_chars(&folder)))
+    }
+
+    fn get_app_build_id(&self) -> i32 {
+        unsafe { bindings::SteamAPI_ISteamApps_GetAppBuildId(self.apps) }
+    }
+
+    fn get_installed_depots(&self, id: AppId) -> Vec<DepotId> {
+        let mut depots = [0; 256];
+        let count = unsafe {
+            bindings::SteamAPI_ISteamApps_GetInstalledDepots(
+                self.apps,
+                id.0,
+                depots.as_mut_ptr(),
+                depots.len() as u32,
+            )
+        };
+
+        depots[..count as usize]
+            .iter()
+            .map(|&id| DepotId(id))
+            .collect()
+    }
+
+    fn get_app_owner(&self) -> SteamId {
+        SteamId(unsafe { bindings::SteamAPI_ISteamApps_GetAppOwner(self.apps) })
+    }
+
+    fn is_subscribed_from_family_sharing(&self) -> bool {
+        unsafe { bindings::SteamAPI_ISteamApps_BIsSubscribedFromFamilySharing(self.apps) }
+    }
+
+    fn is_subscribed_from_free_weekend(&self) -> bool {
+        unsafe { bindings::SteamAPI_ISteamApps_BIsSubscribedFromFreeWeekend(self.apps) }
+    }
+
+    fn is_timed_trial(&self) -> Option<(u32, u32)> {
+        let mut allowed = 0;
+        let mut played = 0;
+
+        unsafe {
+            if !bindings::SteamAPI_ISteamApps_BIsTimedTrial(self.apps, &mut allowed, &mut played) {
+                return None;
+            }
+        }
+
+        Some((allowed, played))
+    }
+
+    fn is_vac_banned(&self) -> bool {
+        unsafe { bindings::SteamAPI_ISteamApps_BIsVACBanned(self.apps) }
+    }
+
+    fn get_launch_query_param(&self, key: &str) -> Option<String> {
+        let key = CString::new(key).ok()?;
+        let value = unsafe {
+            let value = bindings::SteamAPI_ISteamApps_GetLaunchQueryParam(self.apps, key.as_ptr());
+            CStr::from_ptr(value).to_string_lossy().into_owned()
+        };
+
+        // Unset keys come back empty
+        if value.is_empty() {
+            return None;
+        }
+        Some(value)
+    }
+
+    fn get_launch_command_line(&self) -> String {
+        let mut command_line = [0 as c_char; 1024];
+        unsafe {
+            bindings::SteamAPI_ISteamApps_GetLaunchCommandLine(
+                self.apps,
+                command_line.as_mut_ptr(),
+                command_line.len() as i32,
+            );
+        }
+
+        string_from_chars(&command_line)
+    }
+
+    fn get_process_args(&self) -> Vec<OsString> {
+        std::env::args_os().collect()
+    }
+}
+
+impl FriendsBackend for SteamBackend {
+    fn get_persona_name(&self) -> String {
+        unsafe {
+            let name = bindings::SteamAPI_ISteamFriends_GetPersonaName(self.friends);
+            let name = CStr::from_ptr(name);
+            name.to_string_lossy().into_owned()
+        }
+    }
+
+    fn get_friend_count(&self, flags: FriendFlags) -> i32 {
+        unsafe { bindings::SteamAPI_ISteamFriends_GetFriendCount(self.friends, flags.bits() as _) }
+    }
+
+    fn get_friend_by_index(&self, idx: i32, flags: FriendFlags) -> SteamId {
+        SteamId(unsafe {
+            bindings::SteamAPI_ISteamFriends_GetFriendByIndex(self.friends, idx, flags.bits() as _)
+        })
+    }
+
+    fn get_friend_persona_name(&self, id: SteamId) -> String {
+        unsafe {
+            let name = bindings::SteamAPI_ISteamFriends_GetFriendPersonaName(self.friends, id.0);
+            let name = CStr::from_ptr(name);
+            name.to_string_lossy().into_owned()
+        }
+    }
+
+    fn get_small_friend_avatar(&self, id: SteamId) -> i32 {
+        unsafe { bindings::SteamAPI_ISteamFriends_GetSmallFriendAvatar(self.friends, id.0) }
+    }
+
+    fn get_medium_friend_avatar(&self, id: SteamId) -> i32 {
+        unsafe { bindings::SteamAPI_ISteamFriends_GetMediumFriendAvatar(self.friends, id.0) }
+    }
+
+    fn get_large_friend_avatar(&self, id: SteamId) -> i32 {
+        unsafe { bindings::SteamAPI_ISteamFriends_GetLargeFriendAvatar(self.friends, id.0) }
+    }
+
+    fn set_rich_presence(&self, key: &str, value: &str) -> bool {
+        let (Ok(key), Ok(value)) = (CString::new(key), CString::new(value)) else {
+            return false;
+        };
+        unsafe {
+            bindings::SteamAPI_ISteamFriends_SetRichPresence(
+                self.friends,
+                key.as_ptr(),
+                value.as_ptr(),
+            )
+        }
+    }
+
+    fn clear_rich_presence(&self) {
+        unsafe { bindings::SteamAPI_ISteamFriends_ClearRichPresence(self.friends) }
+    }
+
+    fn get_friend_rich_presence(&self, id: SteamId, key: &str) -> Option<String> {
+        let key = CString::new(key).ok()?;
+        let value = unsafe {
+            let value = bindings::SteamAPI_ISteamFriends_GetFriendRichPresence(
+                self.friends,
+                id.0,
+                key.as_ptr(),
+            );
+            CStr::from_ptr(value).to_string_lossy().into_owned()
+        };
+
+        // Unset keys come back empty
+        if value.is_empty() {
+            return None;
+        }
+        Some(value)
+    }
+
+    fn get_friend_rich_presence_key_count(&self, id: SteamId) -> i32 {
+        unsafe {
+            bindings::SteamAPI_ISteamFriends_GetFriendRichPresenceKeyCount(self.friends, id.0)
+        }
+    }
+
+    fn get_friend_rich_presence_key_by_index(&self, id: SteamId, idx: i32) -> Option<String> {
+        let key = unsafe {
+            let key = bindings::SteamAPI_ISteamFriends_GetFriendRichPresenceKeyByIndex(
+                self.friends,
+                id.0,
+                idx,
+            );
+            CStr::from_ptr(key).to_string_lossy().into_owned()
+        };
+
+        if key.is_empty() {
+            return None;
+        }
+        Some(key)
+    }
+
+    fn request_friend_rich_presence(&self, id: SteamId) {
+        unsafe { bindings::SteamAPI_ISteamFriends_RequestFriendRichPresence(self.friends, id.0) }
+    }
+
+    fn get_friend_persona_state(&self, id: SteamId) -> PersonaState {
+        unsafe { bindings::SteamAPI_ISteamFriends_GetFriendPersonaState(self.friends, id.0) }.into()
+    }
+
+    fn get_friend_game_played(&self, id: SteamId) -> Option<FriendGame> {
+        unsafe {
+            let mut info = std::mem::zeroed::<bindings::FriendGameInfo_t>();
+            if !bindings::SteamAPI_ISteamFriends_GetFriendGamePlayed(self.friends, id.0, &mut info)
+            {
+                return None;
+            }
+            Some(info.into())
+        }
+    }
+
+    fn get_friend_steam_level(&self, id: SteamId) -> i32 {
+        unsafe { bindings::SteamAPI_ISteamFriends_GetFriendSteamLevel(self.friends, id.0) }
+    }
+
+    fn get_player_nickname(&self, id: SteamId) -> Option<String> {
+        unsafe {
+            let nickname = bindings::SteamAPI_ISteamFriends_GetPlayerNickname(self.friends, id.0);
+            if nickname.is_null() {
+                return None;
+            }
+            Some(CStr::from_ptr(nickname).to_string_lossy().into_owned())
+        }
+    }
+
+    fn get_friend_relationship(&self, id: SteamId) -> FriendRelationship {
+        unsafe { bindings::SteamAPI_ISteamFriends_GetFriendRelationship(self.friends, id.0) }.into()
+    }
+
+    fn request_user_information(&self, id: SteamId, name_only: bool) -> bool {
+        unsafe {
+            bindings::SteamAPI_ISteamFriends_RequestUserInformation(self.friends, id.0, name_only)
+        }
+    }
+}
+
+impl UtilsBackend for SteamBackend {
+    fn get_app_id(&self) -> AppId {
+        AppId(unsafe { bindings::SteamAPI_ISteamUtils_GetAppID(self.utils) })
+    }
+
+    fn get_server_real_time(&self) -> u32 {
+        unsafe { bindings::SteamAPI_ISteamUtils_GetServerRealTime(self.utils) }
+    }
+
+    fn get_ip_country(&self) -> String {
+        unsafe {
+            let country = bindings::SteamAPI_ISteamUtils_GetIPCountry(self.utils);
+            CStr::from_ptr(country).to_string_lossy().into_owned()
+        }
+    }
+
+    fn get_steam_ui_language(&self) -> String {
+        unsafe {
+            let language = bindings::SteamAPI_ISteamUtils_GetSteamUILanguage(self.utils);
+            CStr::from_ptr(language).to_string_lossy().into_owned()
+        }
+    }
+
+    fn get_connected_universe(&self) -> Universe {
+        unsafe { bindings::SteamAPI_ISteamUtils_GetConnectedUniverse(self.utils) }.into()
+    }
+
+    fn get_seconds_since_app_active(&self) -> u32 {
+        unsafe { bindings::SteamAPI_ISteamUtils_GetSecondsSinceAppActive(self.utils) }
+    }
+
+    fn get_seconds_since_computer_active(&self) -> u32 {
+        unsafe { bindings::SteamAPI_ISteamUtils_GetSecondsSinceComputerActive(self.utils) }
+    }
+
+    fn get_current_battery_power(&self) -> u8 {
+        unsafe { bindings::SteamAPI_ISteamUtils_GetCurrentBatteryPower(self.utils) }
+    }
+
+    fn is_steam_running_on_steam_deck(&self) -> bool {
+        unsafe { bindings::SteamAPI_ISteamUtils_IsSteamRunningOnSteamDeck(self.utils) }
+    }
+
+    fn is_steam_in_big_picture_mode(&self) -> bool {
+        unsafe { bindings::SteamAPI_ISteamUtils_IsSteamInBigPictureMode(self.utils) }
+    }
+
+    fn is_steam_running_in_vr(&self) -> bool {
+        unsafe { bindings::SteamAPI_ISteamUtils_IsSteamRunningInVR(self.utils) }
+    }
+
+    fn is_steam_china_launcher(&self) -> bool {
+        unsafe { bindings::SteamAPI_ISteamUtils_IsSteamChinaLauncher(self.utils) }
+    }
+
+    fn get_ipv6_connectivity_state(
+        &self,
+        protocol: Ipv6ConnectivityProtocol,
+    ) -> Ipv6ConnectivityState {
+        unsafe {
+            bindings::SteamAPI_ISteamUtils_GetIPv6ConnectivityState(self.utils, protocol.into())
+        }
+        .into()
+    }
+
+    fn show_gamepad_text_input(
+        &self,
+        mode: GamepadTextInputMode,
+        line_mode: GamepadTextInputLineMode,
+        description: &str,
+        max_chars: u32,
+        existing: &str,
+    ) -> bool {
+        let (Ok(description), Ok(existing)) = (CString::new(description), CString::new(existing))
+        else {
+            return false;
+        };
+        unsafe {
+            bindings::SteamAPI_ISteamUtils_ShowGamepadTextInput(
+                self.utils,
+                mode.into(),
+                line_mode.into(),
+                description.as_ptr(),
+                max_chars,
+                existing.as_ptr(),
+            )
+        }
+    }
+
+    fn get_entered_gamepad_text_length(&self) -> u32 {
+        unsafe { bindings::SteamAPI_ISteamUtils_GetEnteredGamepadTextLength(self.utils) }
+    }
+
+    fn get_entered_gamepad_text_input(&self, text: &mut [u8]) -> bool {
+        unsafe {
+            bindings::SteamAPI_ISteamUtils_GetEnteredGamepadTextInput(
+                self.utils,
+                text.as_mut_ptr() as *mut c_char,
+                text.len() as u32,
+            )
+        }
+    }
+
+    fn show_floating_gamepad_text_input(
+        &self,
+        mode: FloatingGamepadTextInputMode,
+        field: TextFieldRect,
+    ) -> bool {
+        unsafe {
+            bindings::SteamAPI_ISteamUtils_ShowFloatingGamepadTextInput(
+                self.utils,
+                mode.into(),
+                field.x,
+                field.y,
+                field.width,
+                field.height,
+            )
+        }
+    }
+
+    fn dismiss_floating_gamepad_text_input(&self) -> bool {
+        unsafe { bindings::SteamAPI_ISteamUtils_DismissFloatingGamepadTextInput(self.utils) }
+    }
+
+    fn get_image_size(&self, handle: i32) -> Option<(u32, u32)> {
+        let mut width = 0;
+        let mut height = 0;
+
+        unsafe {
+            if !bindings::SteamAPI_ISteamUtils_GetImageSize(
+                self.utils,
+                handle,
+                &mut width,
+                &mut height,
+            ) {
+                return None;
+            }
+        }
+
+        Some((width, height))
+    }
+
+    fn get_image_rgba(&self, handle: i32, dest: &mut [u8]) -> bool {
+        unsafe {
+            bindings::SteamAPI_ISteamUtils_GetImageRGBA(
+                self.utils,
+                handle,
+                dest.as_mut_ptr(),
+                dest.len() as i32,
+            )
+        }
+    }
+}
+
+impl UserStatsBackend for SteamBackend {
+    fn request_current_stats(&self) -> bool {
+        unsafe { bindings::SteamAPI_ISteamUserStats_RequestCurrentStats(self.user_stats) }
+    }
+
+    fn get_stat_i32(&self, name: &str) -> Option<i32> {
+        let name = CString::new(name).ok()?;
+        let mut value = 0;
+
+        unsafe {
+            if !bindings::SteamAPI_ISteamUserStats_GetStatInt32(
+                self.user_stats,
+                name.as_ptr(),
+                &mut value,
+            ) {
+                return None;
+            }
+        }
+
+        Some(value)
+    }
+
+    fn get_stat_f32(&self, name: &str) -> Option<f32> {
+        let name = CString::new(name).ok()?;
+        let mut value = 0.0;
+
+        unsafe {
+            if !bindings::SteamAPI_ISteamUserStats_GetStatFloat(
+                self.user_stats,
+                name.as_ptr(),
+                &mut value,
+            ) {
+                return None;
+            }
+        }
+
+        Some(value)
+    }
+
+    fn set_stat_i32(&self, name: &str, value: i32) -> bool {
+        let Ok(name) = CString::new(name) else {
+            return false;
+        };
+        unsafe {
+            bindings::SteamAPI_ISteamUserStats_SetStatInt32(self.user_stats, name.as_ptr(), value)
+        }
+    }
+
+    fn set_stat_f32(&self, name: &str, value: f32) -> bool {
+        let Ok(name) = CString::new(name) else {
+            return false;
+        };
+        unsafe {
+            bindings::SteamAPI_ISteamUserStats_SetStatFloat(self.user_stats, name.as_ptr(), value)
+        }
+    }
+
+    fn get_achievement(&self, name: &str) -> Option<bool> {
+        let name = CString::new(name).ok()?;
+        let mut achieved = false;
+
+        unsafe {
+            if !bindings::SteamAPI_ISteamUserStats_GetAchievement(
+                self.user_stats,
+                name.as_ptr(),
+                &mut achieved,
+            ) {
+                return None;
+            }
+        }
+
+        Some(achieved)
+    }
+
+    fn set_achievement(&self, name: &str) -> bool {
+        let Ok(name) = CString::new(name) else {
+            return false;
+        };
+        unsafe { bindings::SteamAPI_ISteamUserStats_SetAchievement(self.user_stats, name.as_ptr()) }
+    }
+
+    fn clear_achievement(&self, name: &str) -> bool {
+        let Ok(name) = CString::new(name) else {
+            return false;
+        };
+        unsafe {
+            bindings::SteamAPI_ISteamUserStats_ClearAchievement(self.user_stats, name.as_ptr())
+        }
+    }
+
+    fn indicate_achievement_progress(&self, name: &str, current: u32, max: u32) -> bool {
+        let Ok(name) = CString::new(name) else {
+            return false;
+        };
+        unsafe {
+            bindings::SteamAPI_ISteamUserStats_IndicateAchievementProgress(
+                self.user_stats,
+                name.as_ptr(),
+                current,
+                max,
+            )
+        }
+    }
+
+    fn store_stats(&self) -> bool {
+        unsafe { bindings::SteamAPI_ISteamUserStats_StoreStats(self.user_stats) }
+    }
+
+    fn find_or_create_leaderboard(
+        &self,
+        name: &str,
+        sort_method: LeaderboardSortMethod,
+        display_type: LeaderboardDisplayType,
+    ) -> SteamAPICall {
+        let Ok(name) = CString::new(name) else {
+            return bindings::k_uAPICallInvalid;
+        };
+        unsafe {
+            bindings::SteamAPI_ISteamUserStats_FindOrCreateLeaderboard(
+                self.user_stats,
+                name.as_ptr(),
+                sort_method.into(),
+                display_type.into(),
+            )
+        }
+    }
+
+    fn upload_leaderboard_score(
+        &self,
+        leaderboard: Leaderboard,
+        method: UploadScoreMethod,
+        score: i32,
+        details: &[i32],
+    ) -> SteamAPICall {
+        unsafe {
+            bindings::SteamAPI_ISteamUserStats_UploadLeaderboardScore(
+                self.user_stats,
+                leaderboard.0,
+                method.into(),
+                score,
+                details.as_ptr(),
+                details.len() as i32,
+            )
+        }
+    }
+
+    fn download_leaderboard_entries(
+        &self,
+        leaderboard: Leaderboard,
+        range: LeaderboardRange,
+    ) -> SteamAPICall {
+        use bindings::ELeaderboardDataRequest::*;
+
+        let (request, start, end) = match range {
+            LeaderboardRange::Global { start, end } => {
+                (k_ELeaderboardDataRequestGlobal, start, end)
+            }
+            LeaderboardRange::AroundUser { before, after } => (
+                k_ELeaderboardDataRequestGlobalAroundUser,
+                -(before as i32),
+                after as i32,
+            ),
+            LeaderboardRange::Friends => (k_ELeaderboardDataRequestFriends, 0, 0),
+        };
+
+        unsafe {
+            bindings::SteamAPI_ISteamUserStats_DownloadLeaderboardEntries(
+                self.user_stats,
+                leaderboard.0,
+                request,
+                start,
+                end,
+            )
+        }
+    }
+
+    fn get_downloaded_leaderboard_entry(
+        &self,
+        entries: u64,
+        index: i32,
+    ) -> Option<LeaderboardEntry> {
+        let mut details = [0; bindings::k_cLeaderboardDetailsMax as usize];
+
+        unsafe {
+            let mut entry: bindings::LeaderboardEntry_t = std::mem::zeroed();
+            if !bindings::SteamAPI_ISteamUserStats_GetDownloadedLeaderboardEntry(
+                self.user_stats,
+                entries,
+                index,
+                &mut entry,
+                details.as_mut_ptr(),
+                details.len() as i32,
+            ) {
+                return None;
+            }
+
+            let detail_count = (entry.m_cDetails.max(0) as usize).min(details.len());
+            Some(LeaderboardEntry {
+                steam_id: SteamId(entry.m_steamIDUser.m_steamid.m_unAll64Bits),
+                global_rank: entry.m_nGlobalRank,
+                score: entry.m_nScore,
+                details: details[..detail_count].to_vec(),
+            })
+        }
+    }
+}
+
+impl RemoteStorageBackend for SteamBackend {
+    fn file_write(&self, name: &str, data: &[u8]) -> bool {
+        let Ok(name) = CString::new(name) else {
+            return false;
+        };
+        unsafe {
+            bindings::SteamAPI_ISteamRemoteStorage_FileWrite(
+                self.remote_storage,
+                name.as_ptr(),
+                data.as_ptr() as *const _,
+                data.len() as i32,
+            )
+        }
+    }
+
+    fn file_read(&self, name: &str, dest: &mut [u8]) -> i32 {
+        let Ok(name) = CString::new(name) else {
+            return 0;
+        };
+        unsafe {
+            bindings::SteamAPI_ISteamRemoteStorage_FileRead(
+                self.remote_storage,
+                name.as_ptr(),
+                dest.as_mut_ptr() as *mut _,
+                dest.len() as i32,
+            )
+        }
+    }
+
+    fn file_delete(&self, name: &str) -> bool {
+        let Ok(name) = CString::new(name) else {
+            return false;
+        };
+        unsafe {
+            bindings::SteamAPI_ISteamRemoteStorage_FileDelete(self.remote_storage, name.as_ptr())
+        }
+    }
+
+    fn file_exists(&self, name: &str) -> bool {
+        let Ok(name) = CString::new(name) else {
+            return false;
+        };
+        unsafe {
+            bindings::SteamAPI_ISteamRemoteStorage_FileExists(self.remote_storage, name.as_ptr())
+        }
+    }
+
+    fn get_file_size(&self, name: &str) -> i32 {
+        let Ok(name) = CString::new(name) else {
+            return 0;
+        };
+        unsafe {
+            bindings::SteamAPI_ISteamRemoteStorage_GetFileSize(self.remote_storage, name.as_ptr())
+        }
+    }
+
+    fn get_file_timestamp(&self, name: &str) -> i64 {
+        let Ok(name) = CString::new(name) else {
+            return 0;
+        };
+        unsafe {
+            bindings::SteamAPI_ISteamRemoteStorage_GetFileTimestamp(
+                self.remote_storage,
+                name.as_ptr(),
+            )
+        }
+    }
+
+    fn get_quota(&self) -> Option<(u64, u64)> {
+        let mut total = 0;
+        let mut available = 0;
+
+        unsafe {
+            if !bindings::SteamAPI_ISteamRemoteStorage_GetQuota(
+                self.remote_storage,
+                &mut total,
+                &mut available,
+            ) {
+                return None;
+            }
+        }
+
+        Some((total, available))
+    }
+
+    fn get_file_count(&self) -> i32 {
+        unsafe { bindings::SteamAPI_ISteamRemoteStorage_GetFileCount(self.remote_storage) }
+    }
+
+    fn get_file_name_and_size(&self, idx: i32) -> Option<(String, i32)> {
+        let mut size = 0;
+
+        unsafe {
+            let name = bindings::SteamAPI_ISteamRemoteStorage_GetFileNameAndSize(
+                self.remote_storage,
+                idx,
+                &mut size,
+            );
+            if name.is_null() {
+                return None;
+            }
+            let name = CStr::from_ptr(name);
+            Some((name.to_string_lossy().into_owned(), size))
+        }
+    }
+
+    fn file_write_stream_open(&self, name: &str) -> u64 {
+        let Ok(name) = CString::new(name) else {
+            return bindings::k_UGCFileStreamHandleInvalid;
+        };
+        unsafe {
+            bindings::SteamAPI_ISteamRemoteStorage_FileWriteStreamOpen(
+                self.remote_storage,
+                name.as_ptr(),
+            )
+        }
+    }
+
+    fn file_write_stream_write_chunk(&self, handle: u64, data: &[u8]) -> bool {
+        unsafe {
+            bindings::SteamAPI_ISteamRemoteStorage_FileWriteStreamWriteChunk(
+                self.remote_storage,
+                handle,
+                data.as_ptr() as *const _,
+                data.len() as i32,
+            )
+        }
+    }
+
+    fn file_write_stream_close(&self, handle: u64) -> bool {
+        unsafe {
+            bindings::SteamAPI_ISteamRemoteStorage_FileWriteStreamClose(self.remote_storage, handle)
+        }
+    }
+
+    fn file_write_stream_cancel(&self, handle: u64) -> bool {
+        unsafe {
+            bindings::SteamAPI_ISteamRemoteStorage_FileWriteStreamCancel(
+                self.remote_storage,
+                handle,
+            )
+        }
+    }
+}
+
+impl UgcBackend for SteamBackend {
+    fn create_query_user_ugc_request(
+        &self,
+        account_id: u32,
+        list: UserList,
+        ugc_type: UgcType,
+        order: UserListOrder,
+        app_id: AppId,
+        page: u32,
+    ) -> u64 {
+        unsafe {
+            bindings::SteamAPI_ISteamUGC_CreateQueryUserUGCRequest(
+                self.ugc,
+                account_id,
+                list.into(),
+                ugc_type.into(),
+                order.into(),
+                app_id.0,
+                app_id.0,
+                page,
+            )
+        }
+    }
+
+    fn create_query_all_ugc_request(
+        &self,
+        query_type: UgcQueryType,
+        ugc_type: UgcType,
+        app_id: AppId,
+        page: u32,
+    ) -> u64 {
+        unsafe {
+            bindings::SteamAPI_ISteamUGC_CreateQueryAllUGCRequestPage(
+                self.ugc,
+                query_type.into(),
+                ugc_type.into(),
+                app_id.0,
+                app_id.0,
+                page,
+            )
+        }
+    }
+
+    fn add_required_tag(&self, handle: u64, tag: &str) -> bool {
+        let Ok(tag) = CString::new(tag) else {
+            return false;
+        };
+        unsafe { bindings::SteamAPI_ISteamUGC_AddRequiredTag(self.ugc, handle, tag.as_ptr()) }
+    }
+
+    fn add_excluded_tag(&self, handle: u64, tag: &str) -> bool {
+        let Ok(tag) = CString::new(tag) else {
+            return false;
+        };
+        unsafe { bindings::SteamAPI_ISteamUGC_AddExcludedTag(self.ugc, handle, tag.as_ptr()) }
+    }
+
+    fn set_match_any_tag(&self, handle: u64, match_any: bool) -> bool {
+        unsafe { bindings::SteamAPI_ISteamUGC_SetMatchAnyTag(self.ugc, handle, match_any) }
+    }
+
+    fn set_search_text(&self, handle: u64, text: &str) -> bool {
+        let Ok(text) = CString::new(text) else {
+            return false;
+        };
+        unsafe { bindings::SteamAPI_ISteamUGC_SetSearchText(self.ugc, handle, text.as_ptr()) }
+    }
+
+    fn set_return_long_description(&self, handle: u64, long_description: bool) -> bool {
+        unsafe {
+            bindings::SteamAPI_ISteamUGC_SetReturnLongDescription(
+                self.ugc,
+                handle,
+                long_description,
+            )
+        }
+    }
+
+    fn send_query_ugc_request(&self, handle: u64) -> SteamAPICall {
+        unsafe { bindings::SteamAPI_ISteamUGC_SendQueryUGCRequest(self.ugc, handle) }
+    }
+
+    fn get_query_ugc_result(&self, handle: u64, index: u32) -> Option<PublishedFileDetails> {
+        unsafe {
+            // The details struct is around 10kb, keep it off the stack
+            let mut details = Box::new(std::mem::zeroed::<bindings::SteamUGCDetails_t>());
+            if !bindings::SteamAPI_ISteamUGC_GetQueryUGCResult(
+                self.ugc,
+                handle,
+                index,
+                &mut *details,
+            ) {
+                return None;
+            }
+            Some(PublishedFileDetails::from(&*details))
+        }
+    }
+
+    fn release_query_ugc_request(&self, handle: u64) -> bool {
+        unsafe { bindings::SteamAPI_ISteamUGC_ReleaseQueryUGCRequest(self.ugc, handle) }
+    }
+
+    fn create_item(&self, app_id: AppId, file_type: WorkshopFileType) -> SteamAPICall {
+        unsafe { bindings::SteamAPI_ISteamUGC_CreateItem(self.ugc, app_id.0, file_type.into()) }
+    }
+
+    fn start_item_update(&self, app_id: AppId, id: PublishedFileId) -> u64 {
+        unsafe { bindings::SteamAPI_ISteamUGC_StartItemUpdate(self.ugc, app_id.0, id.0) }
+    }
+
+    fn set_item_title(&self, handle: u64, title: &str) -> bool {
+        let Ok(title) = CString::new(title) else {
+            return false;
+        };
+        unsafe { bindings::SteamAPI_ISteamUGC_SetItemTitle(self.ugc, handle, title.as_ptr()) }
+    }
+
+    fn set_item_description(&self, handle: u64, description: &str) -> bool {
+        let Ok(description) = CString::new(description) else {
+            return false;
+        };
+        unsafe {
+            bindings::SteamAPI_ISteamUGC_SetItemDescription(self.ugc, handle, description.as_ptr())
+        }
+    }
+
+    fn set_item_content(&self, handle: u64, folder: &Path) -> bool {
+        let Some(folder) = path_to_cstring(folder) else {
+            return false;
+        };
+        unsafe { bindings::SteamAPI_ISteamUGC_SetItemContent(self.ugc, handle, folder.as_ptr()) }
+    }
+
+    fn set_item_preview(&self, handle: u64, file: &Path) -> bool {
+        let Some(file) = path_to_cstring(file) else {
+            return false;
+        };
+        unsafe { bindings::SteamAPI_ISteamUGC_SetItemPreview(self.ugc, handle, file.as_ptr()) }
+    }
+
+    fn set_item_tags(&self, handle: u64, tags: &[&str]) -> bool {
+        let Ok(tags) = tags
+            .iter()
+            .map(|tag| CString::new(*tag))
+            .collect::<Result<Vec<_>, _>>()
+        else {
+            return false;
+        };
+        let mut pointers: Vec<_> = tags.iter().map(|tag| tag.as_ptr()).collect();
+        let array = bindings::SteamParamStringArray_t {
+            m_ppStrings: pointers.as_mut_ptr(),
+            m_nNumStrings: pointers.len() as i32,
+        };
+
+        unsafe { bindings::SteamAPI_ISteamUGC_SetItemTags(self.ugc, handle, &array) }
+    }
+
+    fn set_item_visibility(&self, handle: u64, visibility: Visibility) -> bool {
+        unsafe {
+            bindings::SteamAPI_ISteamUGC_SetItemVisibility(self.ugc, handle, visibility.into())
+        }
+    }
+
+    fn submit_item_update(&self, handle: u64, change_note: Option<&str>) -> SteamAPICall {
+        let change_note = match change_note.map(CString::new) {
+            Some(Ok(note)) => Some(note),
+            Some(Err(_)) => return bindings::k_uAPICallInvalid,
+            None => None,
+        };
+
+        unsafe {
+            bindings::SteamAPI_ISteamUGC_SubmitItemUpdate(
+                self.ugc,
+                handle,
+                change_note
+                    .as_ref()
+                    .map_or(std::ptr::null(), |note| note.as_ptr()),
+            )
+        }
+    }
+
+    fn get_item_update_progress(&self, handle: u64) -> UpdateProgress {
+        let mut bytes_processed = 0;
+        let mut bytes_total = 0;
+
+        let status = unsafe {
+            bindings::SteamAPI_ISteamUGC_GetItemUpdateProgress(
+                self.ugc,
+                handle,
+                &mut bytes_processed,
+                &mut bytes_total,
+            )
+        };
+
+        UpdateProgress {
+            status: status.into(),
+            bytes_processed,
+            bytes_total,
+        }
+    }
+
+    fn subscribe_item(&self, id: PublishedFileId) -> SteamAPICall {
+        unsafe { bindings::SteamAPI_ISteamUGC_SubscribeItem(self.ugc, id.0) }
+    }
+
+    fn unsubscribe_item(&self, id: PublishedFileId) -> SteamAPICall {
+        unsafe { bindings::SteamAPI_ISteamUGC_UnsubscribeItem(self.ugc, id.0) }
+    }
+
+    fn download_item(&self, id: PublishedFileId, high_priority: bool) -> bool {
+        unsafe { bindings::SteamAPI_ISteamUGC_DownloadItem(self.ugc, id.0, high_priority) }
+    }
+
+    fn get_item_state(&self, id: PublishedFileId) -> ItemState {
+        ItemState::from_bits_truncate(unsafe {
+            bindings::SteamAPI_ISteamUGC_GetItemState(self.ugc, id.0)
+        })
+    }
+
+    fn get_item_install_info(&self, id: PublishedFileId) -> Option<InstallInfo> {
+        let mut size_on_disk = 0;
+        let mut folder = [0 as c_char; 1024];
+        let mut timestamp = 0;
+
+        unsafe {
+            if !bindings::SteamAPI_ISteamUGC_GetItemInstallInfo(
+                self.ugc,
+                id.0,
+                &mut size_on_disk,
+                folder.as_mut_ptr(),
+                folder.len() as u32,
+                &mut timestamp,
+            ) {
+                return None;
+            }
+        }
+
+        Some(InstallInfo {
+            folder: PathBuf::from(string_from_chars(&folder)),
+            size_on_disk,
+            timestamp,
+        })
+    }
+
+    fn get_item_download_info(&self, id: PublishedFileId) -> Option<(u64, u64)> {
+        let mut downloaded = 0;
+        let mut total = 0;
+
+        unsafe {
+            if !bindings::SteamAPI_ISteamUGC_GetItemDownloadInfo(
+                self.ugc,
+                id.0,
+                &mut downloaded,
+                &mut total,
+            ) {
+                return None;
+            }
+        }
+
+        Some((downloaded, total))
+    }
+
+    fn get_subscribed_items(&self) -> Vec<PublishedFileId> {
+        unsafe {
+            let count = bindings::SteamAPI_ISteamUGC_GetNumSubscribedItems(self.ugc);
+            let mut items = vec![0; count as usize];
+            let filled = bindings::SteamAPI_ISteamUGC_GetSubscribedItems(
+                self.ugc,
+                items.as_mut_ptr(),
+                count,
+            );
+            items.truncate(filled as usize);
+            items.into_iter().map(PublishedFileId).collect()
+        }
+    }
+}
+
+impl NetworkingSocketsBackend for SteamBackend {
+    fn create_listen_socket_ip(&self, addr: SocketAddr) -> u32 {
+        let addr = ip_addr_to_raw(addr);
+        unsafe {
+            bindings::SteamAPI_ISteamNetworkingSockets_CreateListenSocketIP(
+                self.networking_sockets,
+                &addr,
+                0,
+                std::ptr::null(),
+            )
+        }
+    }
+
+    fn create_listen_socket_p2p(&self, virtual_port: i32) -> u32 {
+        unsafe {
+            bindings::SteamAPI_ISteamNetworkingSockets_CreateListenSocketP2P(
+                self.networking_sockets,
+                virtual_port,
+                0,
+                std::ptr::null(),
+            )
+        }
+    }
+
+    fn get_listen_socket_address(&self, socket: u32) -> Option<SocketAddr> {
+        unsafe {
+            let mut addr = std::mem::zeroed();
+            if !bindings::SteamAPI_ISteamNetworkingSockets_GetListenSocketAddress(
+                self.networking_sockets,
+                socket,
+                &mut addr,
+            ) {
+                return None;
+            }
+            ip_addr_from_raw(&addr)
+        }
+    }
+
+    fn close_listen_socket(&self, socket: u32) -> bool {
+        unsafe {
+            bindings::SteamAPI_ISteamNetworkingSockets_CloseListenSocket(
+                self.networking_sockets,
+                socket,
+            )
+        }
+    }
+
+    fn connect_by_ip_address(&self, addr: SocketAddr) -> u32 {
+        let addr = ip_addr_to_raw(addr);
+        unsafe {
+            bindings::SteamAPI_ISteamNetworkingSockets_ConnectByIPAddress(
+                self.networking_sockets,
+                &addr,
+                0,
+                std::ptr::null(),
+            )
+        }
+    }
+
+    fn connect_p2p(&self, identity: &NetworkingIdentity, virtual_port: i32) -> u32 {
+        let identity = bindings::SteamNetworkingIdentity::from(identity);
+        unsafe {
+            bindings::SteamAPI_ISteamNetworkingSockets_ConnectP2P(
+                self.networking_sockets,
+                &identity,
+                virtual_port,
+                0,
+                std::ptr::null(),
+            )
+        }
+    }
+
+    fn accept_connection(&self, conn: u32) -> SteamResult {
+        unsafe {
+            bindings::SteamAPI_ISteamNetworkingSockets_AcceptConnection(
+                self.networking_sockets,
+                conn,
+            )
+            .into()
+        }
+    }
+
+    fn close_connection(&self, conn: u32, reason: i32, debug: &str, linger: bool) -> bool {
+        // A debug string we can't pass along isn't worth leaking the connection over
+        let debug = CString::new(debug).unwrap_or_default();
+        unsafe {
+            bindings::SteamAPI_ISteamNetworkingSockets_CloseConnection(
+                self.networking_sockets,
+                conn,
+                reason,
+                debug.as_ptr(),
+                linger,
+            )
+        }
+    }
+
+    fn get_connection_info(&self, conn: u32) -> Option<NetConnectionInfo> {
+        unsafe {
+            let mut info = std::mem::zeroed();
+            if !bindings::SteamAPI_ISteamNetworkingSockets_GetConnectionInfo(
+                self.networking_sockets,
+                conn,
+                &mut info,
+            ) {
+                return None;
+            }
+            Some(NetConnectionInfo::from(&info))
+        }
+    }
+
+    fn send_message_to_connection(&self, conn: u32, data: &[u8], flags: SendFlags) -> SResult<i64> {
+        let mut message_number = 0;
+        let result: SteamResult = unsafe {
+            bindings::SteamAPI_ISteamNetworkingSockets_SendMessageToConnection(
+                self.networking_sockets,
+                conn,
+                data.as_ptr() as *const _,
+                data.len() as u32,
+                flags.bits(),
+                &mut message_number,
+            )
+            .into()
+        };
+        result.into_result().map(|()| message_number)
+    }
+
+    fn flush_messages_on_connection(&self, conn: u32) -> SteamResult {
+        unsafe {
+            bindings::SteamAPI_ISteamNetworkingSockets_FlushMessagesOnConnection(
+                self.networking_sockets,
+                conn,
+            )
+            .into()
+        }
+    }
+
+    fn receive_messages_on_connection(&self, conn: u32, max: usize) -> Vec<NetworkingMessage> {
+        let mut messages = vec![std::ptr::null_mut(); max];
+        let count = unsafe {
+            bindings::SteamAPI_ISteamNetworkingSockets_ReceiveMessagesOnConnection(
+                self.networking_sockets,
+                conn,
+                messages.as_mut_ptr(),
+                max as i32,
+            )
+        };
+        take_messages(messages, count)
+    }
+
+    fn create_poll_group(&self) -> u32 {
+        unsafe {
+            bindings::SteamAPI_ISteamNetworkingSockets_CreatePollGroup(self.networking_sockets)
+        }
+    }
+
+    fn destroy_poll_group(&self, group: u32) -> bool {
+        unsafe {
+            bindings::SteamAPI_ISteamNetworkingSockets_DestroyPollGroup(
+                self.networking_sockets,
+                group,
+            )
+        }
+    }
+
+    fn set_connection_poll_group(&self, conn: u32, group: u32) -> bool {
+        unsafe {
+            bindings::SteamAPI_ISteamNetworkingSockets_SetConnectionPollGroup(
+                self.networking_sockets,
+                conn,
+                group,
+            )
+        }
+    }
+
+    fn receive_messages_on_poll_group(&self, group: u32, max: usize) -> Vec<NetworkingMessage> {
+        let mut messages = vec![std::ptr::null_mut(); max];
+        let count = unsafe {
+            bindings::SteamAPI_ISteamNetworkingSockets_ReceiveMessagesOnPollGroup(
+                self.networking_sockets,
+                group,
+                messages.as_mut_ptr(),
+                max as i32,
+            )
+        };
+        take_messages(messages, count)
+    }
+}
+
+impl NetworkingMessagesBackend for SteamBackend {
+    fn send_message_to_user(
+        &self,
+        identity: &NetworkingIdentity,
+        data: &[u8],
+        flags: SendFlags,
+        channel: i32,
+    ) -> SteamResult {
+        let identity = bindings::SteamNetworkingIdentity::from(identity);
+        unsafe {
+            bindings::SteamAPI_ISteamNetworkingMessages_SendMessageToUser(
+                self.networking_messages,
+                &identity,
+                data.as_ptr() as *const _,
+                data.len() as u32,
+                flags.bits(),
+                channel,
+            )
+            .into()
+        }
+    }
+
+    fn receive_messages_on_channel(&self, channel: i32, max: usize) -> Vec<NetworkingMessage> {
+        let mut messages = vec![std::ptr::null_mut(); max];
+        let count = unsafe {
+            bindings::SteamAPI_ISteamNetworkingMessages_ReceiveMessagesOnChannel(
+                self.networking_messages,
+                channel,
+                messages.as_mut_ptr(),
+                max as i32,
+            )
+        };
+        take_messages(messages, count)
+    }
+
+    fn accept_session_with_user(&self, identity: &NetworkingIdentity) -> bool {
+        let identity = bindings::SteamNetworkingIdentity::from(identity);
+        unsafe {
+            bindings::SteamAPI_ISteamNetworkingMessages_AcceptSessionWithUser(
+                self.networking_messages,
+                &identity,
+            )
+        }
+    }
+
+    fn close_session_with_user(&self, identity: &NetworkingIdentity) -> bool {
+        let identity = bindings::SteamNetworkingIdentity::from(identity);
+        unsafe {
+            bindings::SteamAPI_ISteamNetworkingMessages_CloseSessionWithUser(
+                self.networking_messages,
+                &identity,
+            )
+        }
+    }
+
+    fn close_channel_with_user(&self, identity: &NetworkingIdentity, channel: i32) -> bool {
+        let identity = bindings::SteamNetworkingIdentity::from(identity);
+        unsafe {
+            bindings::SteamAPI_ISteamNetworkingMessages_CloseChannelWithUser(
+                self.networking_messages,
+                &identity,
+                channel,
+            )
+        }
+    }
+
+    fn get_session_connection_info(
+        &self,
+        identity: &NetworkingIdentity,
+    ) -> Option<NetConnectionInfo> {
+        let identity = bindings::SteamNetworkingIdentity::from(identity);
+        unsafe {
+            let mut info = std::mem::zeroed();
+            let state = bindings::SteamAPI_ISteamNetworkingMessages_GetSessionConnectionInfo(
+                self.networking_messages,
+                &identity,
+                &mut info,
+                std::ptr::null_mut(),
+            );
+            if state
+                == bindings::ESteamNetworkingConnectionState::k_ESteamNetworkingConnectionState_None
+            {
+                return None;
+            }
+            Some(NetConnectionInfo::from(&info))
+        }
+    }
+}
+
+impl MatchmakingBackend for SteamBackend {
+    fn add_request_lobby_list_string_filter(
+        &self,
+        key: &str,
+        value: &str,
+        comparison: LobbyComparison,
+    ) {
+        let (Ok(key), Ok(value)) = (CString::new(key), CString::new(value)) else {
+            return;
+        };
+        unsafe {
+            bindings::SteamAPI_ISteamMatchmaking_AddRequestLobbyListStringFilter(
+                self.matchmaking,
+                key.as_ptr(),
+                value.as_ptr(),
+                comparison.into(),
+            )
+        }
+    }
+
+    fn add_request_lobby_list_numerical_filter(
+        &self,
+        key: &str,
+        value: i32,
+        comparison: LobbyComparison,
+    ) {
+        let Ok(key) = CString::new(key) else {
+            return;
+        };
+        unsafe {
+            bindings::SteamAPI_ISteamMatchmaking_AddRequestLobbyListNumericalFilter(
+                self.matchmaking,
+                key.as_ptr(),
+                value,
+                comparison.into(),
+            )
+        }
+    }
+
+    fn add_request_lobby_list_near_value_filter(&self, key: &str, value: i32) {
+        let Ok(key) = CString::new(key) else {
+            return;
+        };
+        unsafe {
+            bindings::SteamAPI_ISteamMatchmaking_AddRequestLobbyListNearValueFilter(
+                self.matchmaking,
+                key.as_ptr(),
+                value,
+            )
+        }
+    }
+
+    fn add_request_lobby_list_filter_slots_available(&self, slots: i32) {
+        unsafe {
+            bindings::SteamAPI_ISteamMatchmaking_AddRequestLobbyListFilterSlotsAvailable(
+                self.matchmaking,
+                slots,
+            )
+        }
+    }
+
+    fn add_request_lobby_list_distance_filter(&self, distance: LobbyDistanceFilter) {
+        unsafe {
+            bindings::SteamAPI_ISteamMatchmaking_AddRequestLobbyListDistanceFilter(
+                self.matchmaking,
+                distance.into(),
+            )
+        }
+    }
+
+    fn add_request_lobby_list_result_count_filter(&self, max_results: i32) {
+        unsafe {
+            bindings::SteamAPI_ISteamMatchmaking_AddRequestLobbyListResultCountFilter(
+                self.matchmaking,
+                max_results,
+            )
+        }
+    }
+
+    fn request_lobby_list(&self) -> SteamAPICall {
+        unsafe { bindings::SteamAPI_ISteamMatchmaking_RequestLobbyList(self.matchmaking) }
+    }
+
+    fn get_lobby_by_index(&self, idx: i32) -> SteamId {
+        SteamId(unsafe {
+            bindings::SteamAPI_ISteamMatchmaking_GetLobbyByIndex(self.matchmaking, idx)
+        })
+    }
+
+    fn create_lobby(&self, lobby_type: LobbyType, max_members: i32) -> SteamAPICall {
+        unsafe {
+            bindings::SteamAPI_ISteamMatchmaking_CreateLobby(
+                self.matchmaking,
+                lobby_type.into(),
+                max_members,
+            )
+        }
+    }
+
+    fn join_lobby(&self, lobby: SteamId) -> SteamAPICall {
+        unsafe { bindings::SteamAPI_ISteamMatchmaking_JoinLobby(self.matchmaking, lobby.0) }
+    }
+
+    fn leave_lobby(&self, lobby: SteamId) {
+        unsafe { bindings::SteamAPI_ISteamMatchmaking_LeaveLobby(self.matchmaking, lobby.0) }
+    }
+
+    fn get_num_lobby_members(&self, lobby: SteamId) -> i32 {
+        unsafe {
+            bindings::SteamAPI_ISteamMatchmaking_GetNumLobbyMembers(self.matchmaking, lobby.0)
+        }
+    }
+
+    fn get_lobby_member_by_index(&self, lobby: SteamId, idx: i32) -> SteamId {
+        SteamId(unsafe {
+            bindings::SteamAPI_ISteamMatchmaking_GetLobbyMemberByIndex(
+                self.matchmaking,
+                lobby.0,
+                idx,
+            )
+        })
+    }
+
+    fn get_lobby_owner(&self, lobby: SteamId) -> SteamId {
+        SteamId(unsafe {
+            bindings::SteamAPI_ISteamMatchmaking_GetLobbyOwner(self.matchmaking, lobby.0)
+        })
+    }
+
+    fn set_lobby_owner(&self, lobby: SteamId, owner: SteamId) -> bool {
+        unsafe {
+            bindings::SteamAPI_ISteamMatchmaking_SetLobbyOwner(self.matchmaking, lobby.0, owner.0)
+        }
+    }
+
+    fn get_lobby_member_limit(&self, lobby: SteamId) -> i32 {
+        unsafe {
+            bindings::SteamAPI_ISteamMatchmaking_GetLobbyMemberLimit(self.matchmaking, lobby.0)
+        }
+    }
+
+    fn set_lobby_member_limit(&self, lobby: SteamId, max_members: i32) -> bool {
+        unsafe {
+            bindings::SteamAPI_ISteamMatchmaking_SetLobbyMemberLimit(
+                self.matchmaking,
+                lobby.0,
+                max_members,
+            )
+        }
+    }
+
+    fn set_lobby_type(&self, lobby: SteamId, lobby_type: LobbyType) -> bool {
+        unsafe {
+            bindings::SteamAPI_ISteamMatchmaking_SetLobbyType(
+                self.matchmaking,
+                lobby.0,
+                lobby_type.into(),
+            )
+        }
+    }
+
+    fn set_lobby_joinable(&self, lobby: SteamId, joinable: bool) -> bool {
+        unsafe {
+            bindings::SteamAPI_ISteamMatchmaking_SetLobbyJoinable(
+                self.matchmaking,
+                lobby.0,
+                joinable,
+            )
+        }
+    }
+
+    fn get_lobby_data(&self, lobby: SteamId, key: &str) -> String {
+        let Ok(key) = CString::new(key) else {
+            return String::new();
+        };
+        unsafe {
+            let value = bindings::SteamAPI_ISteamMatchmaking_GetLobbyData(
+                self.matchmaking,
+                lobby.0,
+                key.as_ptr(),
+            );
+            CStr::from_ptr(value).to_string_lossy().into_owned()
+        }
+    }
+
+    fn set_lobby_data(&self, lobby: SteamId, key: &str, value: &str) -> bool {
+        let (Ok(key), Ok(value)) = (CString::new(key), CString::new(value)) else {
+            return false;
+        };
+        unsafe {
+            bindings::SteamAPI_ISteamMatchmaking_SetLobbyData(
+                self.matchmaking,
+                lobby.0,
+                key.as_ptr(),
+                value.as_ptr(),
+            )
+        }
+    }
+
+    fn delete_lobby_data(&self, lobby: SteamId, key: &str) -> bool {
+        let Ok(key) = CString::new(key) else {
+            return false;
+        };
+        unsafe {
+            bindings::SteamAPI_ISteamMatchmaking_DeleteLobbyData(
+                self.matchmaking,
+                lobby.0,
+                key.as_ptr(),
+            )
+        }
+    }
+
+    fn get_lobby_data_count(&self, lobby: SteamId) -> i32 {
+        unsafe { bindings::SteamAPI_ISteamMatchmaking_GetLobbyDataCount(self.matchmaking, lobby.0) }
+    }
+
+    fn get_lobby_data_by_index(&self, lobby: SteamId, idx: i32) -> Option<(String, String)> {
+        let mut key = [0 as c_char; bindings::k_nMaxLobbyKeyLength as usize];
+        let mut value = vec![0 as c_char; bindings::k_cubChatMetadataMax as usize];
+
+        unsafe {
+            if !bindings::SteamAPI_ISteamMatchmaking_GetLobbyDataByIndex(
+                self.matchmaking,
+                lobby.0,
+                idx,
+                key.as_mut_ptr(),
+                key.len() as i32,
+                value.as_mut_ptr(),
+                value.len() as i32,
+            ) {
+                return None;
+            }
+        }
+
+        Some((string_from_chars(&key), string_from_chars(&value)))
+    }
+
+    fn request_lobby_data(&self, lobby: SteamId) -> bool {
+        unsafe { bindings::SteamAPI_ISteamMatchmaking_RequestLobbyData(self.matchmaking, lobby.0) }
+    }
+
+    fn get_lobby_member_data(&self, lobby: SteamId, member: SteamId, key: &str) -> String {
+        let Ok(key) = CString::new(key) else {
+            return String::new();
+        };
+        unsafe {
+            let value = bindings::SteamAPI_ISteamMatchmaking_GetLobbyMemberData(
+                self.matchmaking,
+                lobby.0,
+                member.0,
+                key.as_ptr(),
+            );
+            if value.is_null() {
+                return String::new();
+            }
+            CStr::from_ptr(value).to_string_lossy().into_owned()
+        }
+    }
+
+    fn set_lobby_member_data(&self, lobby: SteamId, key: &str, value: &str) {
+        let (Ok(key), Ok(value)) = (CString::new(key), CString::new(value)) else {
+            return;
+        };
+        unsafe {
+            bindings::SteamAPI_ISteamMatchmaking_SetLobbyMemberData(
+                self.matchmaking,
+                lobby.0,
+                key.as_ptr(),
+                value.as_ptr(),
+            )
+        }
+    }
+
+    fn send_lobby_chat_msg(&self, lobby: SteamId, data: &[u8]) -> bool {
+        unsafe {
+            bindings::SteamAPI_ISteamMatchmaking_SendLobbyChatMsg(
+                self.matchmaking,
+                lobby.0,
+                data.as_ptr() as *const _,
+                data.len() as i32,
+            )
+        }
+    }
+
+    fn get_lobby_chat_entry(&self, lobby: SteamId, chat_id: i32) -> Option<LobbyChatEntry> {
+        let mut data = vec![0u8; MAX_LOBBY_CHAT_MESSAGE];
+
+        unsafe {
+            let mut sender: bindings::CSteamID = std::mem::zeroed();
+            let mut entry_type = bindings::EChatEntryType::k_EChatEntryTypeInvalid;
+            let len = bindings::SteamAPI_ISteamMatchmaking_GetLobbyChatEntry(
+                self.matchmaking,
+                lobby.0,
+                chat_id,
+                &mut sender,
+                data.as_mut_ptr() as *mut _,
+                data.len() as i32,
+                &mut entry_type,
+            );
+            if entry_type == bindings::EChatEntryType::k_EChatEntryTypeInvalid {
+                return None;
+            }
+
+            data.truncate(len.max(0) as usize);
+            Some(LobbyChatEntry {
+                sender: SteamId(sender.m_steamid.m_unAll64Bits),
+                entry_type: (entry_type as u8).into(),
+                data,
+            })
+        }
+    }
+}
+
+impl GameServerBackend for SteamServerBackend {
+    fn get_steam_id(&self) -> SteamId {
+        unsafe {
+            SteamId(bindings::SteamAPI_ISteamGameServer_GetSteamID(
+                self.game_server,
+            ))
+        }
+    }
+
+    fn log_on(&self, token: &str) {
+        let Ok(token) = CString::new(token) else {
+            return;
+        };
+        unsafe { bindings::SteamAPI_ISteamGameServer_LogOn(self.game_server, token.as_ptr()) }
+    }
+
+    fn log_on_anonymous(&self) {
+        unsafe { bindings::SteamAPI_ISteamGameServer_LogOnAnonymous(self.game_server) }
+    }
+
+    fn log_off(&self) {
+        unsafe { bindings::SteamAPI_ISteamGameServer_LogOff(self.game_server) }
+    }
+
+    fn logged_on(&self) -> bool {
+        unsafe { bindings::SteamAPI_ISteamGameServer_BLoggedOn(self.game_server) }
+    }
+
+    fn secure(&self) -> bool {
+        unsafe { bindings::SteamAPI_ISteamGameServer_BSecure(self.game_server) }
+    }
+
+    fn set_product(&self, product: &str) {
+        let Ok(product) = CString::new(product) else {
+            return;
+        };
+        unsafe {
+            bindings::SteamAPI_ISteamGameServer_SetProduct(self.game_server, product.as_ptr())
+        }
+    }
+
+    fn set_game_description(&self, description: &str) {
+        let Ok(description) = CString::new(description) else {
+            return;
+        };
+        unsafe {
+            bindings::SteamAPI_ISteamGameServer_SetGameDescription(
+                self.game_server,
+                description.as_ptr(),
+            )
+        }
+    }
+
+    fn set_dedicated_server(&self, dedicated: bool) {
+        unsafe {
+            bindings::SteamAPI_ISteamGameServer_SetDedicatedServer(self.game_server, dedicated)
+        }
+    }
+
+    fn set_server_name(&self, name: &str) {
+        let Ok(name) = CString::new(name) else {
+            return;
+        };
+        unsafe {
+            bindings::SteamAPI_ISteamGameServer_SetServerName(self.game_server, name.as_ptr())
+        }
+    }
+
+    fn set_map_name(&self, map: &str) {
+        let Ok(map) = CString::new(map) else {
+            return;
+        };
+        unsafe { bindings::SteamAPI_ISteamGameServer_SetMapName(self.game_server, map.as_ptr()) }
+    }
+
+    fn set_max_player_count(&self, max_players: i32) {
+        unsafe {
+            bindings::SteamAPI_ISteamGameServer_SetMaxPlayerCount(self.game_server, max_players)
+        }
+    }
+
+    fn set_advertise_server_active(&self, active: bool) {
+        unsafe {
+            bindings::SteamAPI_ISteamGameServer_SetAdvertiseServerActive(self.game_server, active)
+        }
+    }
+
+    fn begin_auth_session(&self, ticket: &[u8], steam_id: SteamId) -> SteamResult {
+        unsafe {
+            bindings::SteamAPI_ISteamGameServer_BeginAuthSession(
+                self.game_server,
+                ticket.as_ptr() as *const _,
+                ticket.len() as i32,
+                steam_id.0,
+            )
+            .into()
+        }
+    }
+
+    fn end_auth_session(&self, steam_id: SteamId) {
+        unsafe { bindings::SteamAPI_ISteamGameServer_EndAuthSession(self.game_server, steam_id.0) }
+    }
+}
+
+/// Pops the next callback or call result from a pipe
+unsafe fn next_callback(pipe: bindings::HSteamPipe) -> Option<CallbackMessage> {
+    let mut callback = std::mem::zeroed();
+    if !bindings::SteamAPI_ManualDispatch_GetNextCallback(pipe, &mut callback) {
+        return None;
+    }
+
+    let message = if callback.m_iCallback == bindings::SteamAPICallCompleted_t_k_iCallback as i32 {
+        let apicall = std::ptr::read_unaligned(
+            callback.m_pubParam as *const bindings::SteamAPICallCompleted_t,
+        );
+        let mut apicall_result = vec![0; apicall.m_cubParam as usize];
+        let mut failed = false;
+
+        // A result we couldn't fetch still has to reach its future, as a failure
+        if !bindings::SteamAPI_ManualDispatch_GetAPICallResult(
+            pipe,
+            apicall.m_hAsyncCall,
+            apicall_result.as_mut_ptr() as *mut _,
+            apicall.m_cubParam as _,
+            apicall.m_iCallback,
+            &mut failed,
+        ) {
+            apicall_result.clear();
+            failed = true;
+        }
+
+        CallbackMessage::CallResult {
+            api_call: apicall.m_hAsyncCall,
+            id: apicall.m_iCallback,
+            payload: Payload::Raw(apicall_result),
+            failed,
+        }
+    } else {
+        let data = std::slice::from_raw_parts(
+            callback.m_pubParam as *const u8,
+            callback.m_cubParam as usize,
+        );
+        CallbackMessage::Callback {
+            id: callback.m_iCallback,
+            payload: Payload::Raw(data.to_vec()),
+        }
+    };
+    bindings::SteamAPI_ManualDispatch_FreeLastCallback(pipe);
+
+    Some(message)
+}
+
+/// A message handed out by steam, released back to it when dropped
+#[derive(Debug)]
+struct SteamMessage(*mut bindings::SteamNetworkingMessage_t);
+
+// Steam allows messages to be read and released from any thread
+unsafe impl Send for SteamMessage {}
+
+impl MessageBuffer for SteamMessage {
+    fn data(&self) -> &[u8] {
+        unsafe {
+            let message = &*self.0;
+            if message.m_pData.is_null() {
+                return &[];
+            }
+            std::slice::from_raw_parts(
+                message.m_pData as *const u8,
+                message.m_cbSize.max(0) as usize,
+            )
+        }
+    }
+}
+
+impl Drop for SteamMessage {
+    fn drop(&mut self) {
+        unsafe { bindings::SteamAPI_SteamNetworkingMessage_t_Release(self.0) }
+    }
+}
+
+/// Wraps the first `count` messages filled in by a receive call
+fn take_messages(
+    messages: Vec<*mut bindings::SteamNetworkingMessage_t>,
+    count: i32,
+) -> Vec<NetworkingMessage> {
+    messages
+        .into_iter()
+        .take(count.max(0) as usize)
+        .map(|raw| {
+            let message = unsafe { &*raw };
+            NetworkingMessage::new(
+                Box::new(SteamMessage(raw)),
+                message.m_conn,
+                NetworkingIdentity::from(&message.m_identityPeer),
+                message.m_nChannel,
+                message.m_nFlags & bindings::k_nSteamNetworkingSend_Reliable != 0,
+                message.m_nMessageNumber,
+            )
+        })
+        .collect()
+}
+
+fn path_to_cstring(path: &Path) -> Option<CString> {
+    CString::new(path.to_str()?).ok()
+}
@@ -0,0 +1,102 @@
+//! Decrypting the tickets from `User::get_encrypted_app_ticket` on a backend service.
+//!
+//! This doesn't need Steam to be running, only the app's secret key from the partner site.
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use steamstacks_bindings as bindings;
+
+use crate::result::SteamResult;
+use crate::{AppId, SResult, SteamId};
+
+/// Length of the symmetric key used to decrypt app tickets
+pub const ENCRYPTED_APP_TICKET_KEY_LEN: usize = bindings::k_nSteamEncryptedAppTicketSymmetricKeyLen;
+
+// The size Valve's own samples decrypt into
+const DECRYPTED_TICKET_BUFFER: usize = 1024;
+
+/// A decrypted app ticket
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DecryptedAppTicket {
+    data: Vec<u8>,
+}
+
+impl DecryptedAppTicket {
+    /// Decrypts a ticket sent by a client, failing with `EncryptionFailure` if
+    /// the key is wrong or the ticket was tampered with
+    pub fn decrypt(ticket: &[u8], key: &[u8; ENCRYPTED_APP_TICKET_KEY_LEN]) -> SResult<Self> {
+        let mut data = vec![0; DECRYPTED_TICKET_BUFFER];
+        let mut len = data.len() as u32;
+
+        let ok = unsafe {
+            bindings::SteamEncryptedAppTicket_BDecryptTicket(
+                ticket.as_ptr(),
+                ticket.len() as u32,
+                data.as_mut_ptr(),
+                &mut len,
+                key.as_ptr(),
+                key.len() as i32,
+            )
+        };
+        if !ok {
+            return Err(SteamResult::EncryptionFailure);
+        }
+
+        data.truncate(len as usize);
+        Ok(Self { data })
+    }
+
+    // The library takes mutable pointers but only ever reads the ticket
+    fn ptr(&self) -> *mut u8 {
+        self.data.as_ptr() as *mut _
+    }
+
+    fn len(&self) -> u32 {
+        self.data.len() as u32
+    }
+
+    /// Returns true if the ticket was issued for `app_id`
+    pub fn is_for_app(&self, app_id: AppId) -> bool {
+        unsafe {
+            bindings::SteamEncryptedAppTicket_BIsTicketForApp(self.ptr(), self.len(), app_id.0)
+        }
+    }
+
+    /// When Steam issued the ticket
+    pub fn issue_time(&self) -> SystemTime {
+        let secs =
+            unsafe { bindings::SteamEncryptedAppTicket_GetTicketIssueTime(self.ptr(), self.len()) };
+        UNIX_EPOCH + Duration::from_secs(secs as u64)
+    }
+
+    /// The user the ticket was issued to
+    pub fn steam_id(&self) -> SteamId {
+        unsafe {
+            let mut id = std::mem::zeroed::<bindings::CSteamID>();
+            bindings::SteamEncryptedAppTicket_GetTicketSteamID(self.ptr(), self.len(), &mut id);
+            SteamId(id.m_steamid.m_unAll64Bits)
+        }
+    }
+
+    /// The app the ticket was issued for
+    pub fn app_id(&self) -> AppId {
+        AppId(unsafe { bindings::SteamEncryptedAppTicket_GetTicketAppID(self.ptr(), self.len()) })
+    }
+
+    /// The data the client passed to `User::request_encrypted_app_ticket`
+    pub fn user_data(&self) -> &[u8] {
+        unsafe {
+            let mut len = 0;
+            let data = bindings::SteamEncryptedAppTicket_GetUserVariableData(
+                self.ptr(),
+                self.len(),
+                &mut len,
+            );
+            if data.is_null() {
+                return &[];
+            }
+            // Points into our own buffer, so it lives as long as `self`
+            std::slice::from_raw_parts(data, len as usize)
+        }
+    }
+}
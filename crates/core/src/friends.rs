@@ -1,7 +1,15 @@
+use std::future::{poll_fn, Future};
+use std::net::{Ipv4Addr, SocketAddr};
+use std::sync::Mutex;
+use std::task::{Poll, Waker};
+
 use super::*;
-use core::ffi::CStr;
+use crate::callbacks::AvatarImageLoaded;
+use crate::result::SteamResult;
+use crate::utils::load_image;
 
 bitflags! {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
     #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
     #[repr(C)]
     pub struct FriendFlags: u16 {
@@ -23,6 +31,80 @@ bitflags! {
     }
 }
 
+bitflags! {
+    /// What changed about a user, see `PersonaStateChange`
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    pub struct PersonaChange: u32 {
+        const NAME                 = 0x0001;
+        const STATUS               = 0x0002;
+        const COME_ONLINE          = 0x0004;
+        const GONE_OFFLINE         = 0x0008;
+        const GAME_PLAYED          = 0x0010;
+        const GAME_SERVER          = 0x0020;
+        const AVATAR               = 0x0040;
+        const JOINED_SOURCE        = 0x0080;
+        const LEFT_SOURCE          = 0x0100;
+        const RELATIONSHIP_CHANGED = 0x0200;
+        const NAME_FIRST_SET       = 0x0400;
+        const BROADCAST            = 0x0800;
+        const NICKNAME             = 0x1000;
+        const STEAM_LEVEL          = 0x2000;
+        const RICH_PRESENCE        = 0x4000;
+    }
+}
+
+/// Whether a user is online and how they want to be seen
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PersonaState {
+    Offline,
+    Online,
+    Busy,
+    Away,
+    Snooze,
+    LookingToTrade,
+    LookingToPlay,
+    /// Only ever reported for the current user
+    Invisible,
+}
+
+/// How the current user is related to another user
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum FriendRelationship {
+    None,
+    Blocked,
+    /// They sent us a friend request
+    RequestRecipient,
+    Friend,
+    /// We sent them a friend request
+    RequestInitiator,
+    Ignored,
+    IgnoredFriend,
+}
+
+/// The game a friend is playing
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FriendGame {
+    pub app_id: AppId,
+    /// The game server they are on, if any
+    pub server: Option<SocketAddr>,
+    pub query_port: u16,
+    /// The lobby they are in, if any
+    pub lobby: Option<SteamId>,
+}
+
+// Rich presence limits, the lengths include the nul terminator
+pub const MAX_RICH_PRESENCE_KEYS: usize = bindings::k_cchMaxRichPresenceKeys as usize;
+pub const MAX_RICH_PRESENCE_KEY_LENGTH: usize = bindings::k_cchMaxRichPresenceKeyLength as usize;
+pub const MAX_RICH_PRESENCE_VALUE_LENGTH: usize =
+    bindings::k_cchMaxRichPresenceValueLength as usize;
+
+// Avatar handle steam hands out while the image is still being downloaded
+const AVATAR_LOADING: i32 = -1;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ImageSize {
     LARGE = 184,
     MEDIUM = 64,
@@ -31,31 +113,70 @@ pub enum ImageSize {
 
 #[derive(Clone)]
 pub struct Friends {
-    pub(crate) friends: *mut bindings::ISteamFriends,
+    pub(crate) backend: Arc<dyn Backend>,
 }
 
-unsafe impl Send for Friends {}
-unsafe impl Sync for Friends {}
-
 impl Friends {
-    pub(crate) fn new() -> Self {
-        Self {
-            friends: unsafe { bindings::SteamAPI_SteamFriends_v017() },
-        }
+    pub(crate) fn new(backend: Arc<dyn Backend>) -> Self {
+        Self { backend }
     }
 
     pub fn get_persona_name(&self) -> String {
-        unsafe {
-            let name = bindings::SteamAPI_ISteamFriends_GetPersonaName(self.friends);
-            let name = CStr::from_ptr(name);
-            name.to_string_lossy().into_owned()
+        self.backend.friends().get_persona_name()
+    }
+
+    /// Sets a rich presence key for the current user, an empty value removes the key
+    ///
+    /// Fails with `InvalidParameter` if the key or value is too long or contains a nul
+    /// byte, with `LimitExceeded` if the user already has `MAX_RICH_PRESENCE_KEYS` keys, and
+    /// with `Generic` if steam refuses it for any other reason.
+    pub fn set_rich_presence(&self, key: &str, value: &str) -> SResult<()> {
+        if key.len() >= MAX_RICH_PRESENCE_KEY_LENGTH
+            || value.len() >= MAX_RICH_PRESENCE_VALUE_LENGTH
+            || key.contains('\0')
+            || value.contains('\0')
+        {
+            return Err(SteamResult::InvalidParameter);
+        }
+
+        let friends = self.backend.friends();
+        if friends.set_rich_presence(key, value) {
+            return Ok(());
+        }
+
+        let me = self.backend.user().get_steam_id();
+        if friends.get_friend_rich_presence_key_count(me) as usize >= MAX_RICH_PRESENCE_KEYS {
+            Err(SteamResult::LimitExceeded)
+        } else {
+            Err(SteamResult::Generic)
+        }
+    }
+
+    /// Removes every rich presence key of the current user
+    pub fn clear_rich_presence(&self) {
+        self.backend.friends().clear_rich_presence()
+    }
+
+    /// Asks steam for a user's name and avatar, or only their name if `name_only` is set
+    ///
+    /// Returns false if the information is already available, otherwise a
+    /// `PersonaStateChange` is sent once it has been downloaded.
+    pub fn request_user_information(&self, id: SteamId, name_only: bool) -> bool {
+        self.backend
+            .friends()
+            .request_user_information(id, name_only)
+    }
+
+    /// Any user, not necessarily a friend, whose state can be queried
+    pub fn get_friend(&self, id: SteamId) -> Friend {
+        Friend {
+            id,
+            backend: self.backend.clone(),
         }
     }
 
     pub fn get_friends(&self, flags: FriendFlags) -> Vec<Friend> {
-        let count = unsafe {
-            bindings::SteamAPI_ISteamFriends_GetFriendCount(self.friends, flags.bits() as _)
-        };
+        let count = self.backend.friends().get_friend_count(flags);
 
         if count <= 0 {
             return Vec::new();
@@ -64,78 +185,186 @@ impl Friends {
         let mut friends = Vec::with_capacity(count as usize);
 
         for idx in 0..count {
-            let friend = SteamId(unsafe {
-                bindings::SteamAPI_ISteamFriends_GetFriendByIndex(
-                    self.friends,
-                    idx,
-                    flags.bits() as _,
-                )
-            });
+            let friend = self.backend.friends().get_friend_by_index(idx, flags);
 
             friends.push(Friend {
                 id: friend,
-                friends: self.friends,
+                backend: self.backend.clone(),
             });
         }
 
         friends
     }
 
-    pub fn get_avatar(&self, id: SteamId, size: ImageSize) -> Option<Vec<u8>> {
-        let utils = steam_api::utils();
-
-        let img = match size {
-            ImageSize::LARGE => unsafe {
-                bindings::SteamAPI_ISteamFriends_GetLargeFriendAvatar(self.friends, id.0)
-            },
-            ImageSize::MEDIUM => unsafe {
-                bindings::SteamAPI_ISteamFriends_GetMediumFriendAvatar(self.friends, id.0)
-            },
-            ImageSize::SMALL => unsafe {
-                bindings::SteamAPI_ISteamFriends_GetSmallFriendAvatar(self.friends, id.0)
-            },
-        };
-
-        if img == 0 {
-            return None;
+    /// A user's avatar, `None` if they don't have one or it is still loading
+    ///
+    /// The image comes at whatever size steam has it in, which isn't always `size`.
+    pub fn get_avatar(&self, id: SteamId, size: ImageSize) -> Option<Image> {
+        match avatar_handle(&*self.backend, id, size) {
+            0 | AVATAR_LOADING => None,
+            handle => load_image(&*self.backend, handle).ok(),
         }
+    }
 
-        let mut width = 0;
-        let mut height = 0;
+    /// Like `get_avatar`, but waits for the avatar to be downloaded if it is still loading
+    pub fn request_avatar(
+        &self,
+        id: SteamId,
+        size: ImageSize,
+    ) -> impl Future<Output = Option<Image>> {
+        let backend = self.backend.clone();
 
-        if !utils.get_image_size(img, &mut width, &mut height) {
-            return None;
-        }
+        // Listen before checking, so a download finishing in between isn't missed
+        let waker = Arc::new(Mutex::new(None::<Waker>));
+        let handle = {
+            let waker = waker.clone();
+            steam_api::register_callback(move |avatar: AvatarImageLoaded| {
+                if avatar.steam_id != id {
+                    return;
+                }
+                let waker = waker.lock().unwrap().take();
+                if let Some(waker) = waker {
+                    waker.wake();
+                }
+            })
+        };
 
-        let size_number = size as usize;
-        assert_eq!(width, size_number as u32);
-        assert_eq!(height, size_number as u32);
-        let mut dest = vec![0; size_number * size_number * 4];
+        async move {
+            let _handle = handle;
+            // Steam hands out the real handle once the download is done
+            let image = poll_fn(|cx| {
+                // Held across the check so the callback can't fire in between
+                let mut waker = waker.lock().unwrap();
+                match avatar_handle(&*backend, id, size) {
+                    AVATAR_LOADING => {
+                        *waker = Some(cx.waker().clone());
+                        Poll::Pending
+                    }
+                    image => Poll::Ready(image),
+                }
+            })
+            .await;
 
-        if !utils.get_image_rgba(img, &mut dest, size_number * size_number * 4) {
-            return None;
+            match image {
+                0 => None,
+                image => load_image(&*backend, image).ok(),
+            }
         }
-
-        Some(dest)
     }
 }
 
 pub struct Friend {
     id: SteamId,
-    friends: *mut bindings::ISteamFriends,
+    backend: Arc<dyn Backend>,
 }
 
 impl Friend {
     pub fn get_persona_name(&self) -> String {
-        unsafe {
-            let name =
-                bindings::SteamAPI_ISteamFriends_GetFriendPersonaName(self.friends, self.id.0);
-            let name = CStr::from_ptr(name);
-            name.to_string_lossy().into_owned()
-        }
+        self.backend.friends().get_friend_persona_name(self.id)
     }
 
     pub fn get_steam_id(&self) -> SteamId {
         self.id
     }
+
+    /// A rich presence value set by the friend, once it has been downloaded
+    pub fn get_rich_presence(&self, key: &str) -> Option<String> {
+        self.backend
+            .friends()
+            .get_friend_rich_presence(self.id, key)
+    }
+
+    /// Every rich presence key set by the friend
+    pub fn get_rich_presence_keys(&self) -> Vec<String> {
+        let friends = self.backend.friends();
+        (0..friends.get_friend_rich_presence_key_count(self.id))
+            .filter_map(|idx| friends.get_friend_rich_presence_key_by_index(self.id, idx))
+            .collect()
+    }
+
+    /// Downloads the friend's rich presence, a `FriendRichPresenceUpdate` is sent once
+    /// it's available
+    pub fn request_rich_presence(&self) {
+        self.backend.friends().request_friend_rich_presence(self.id)
+    }
+
+    pub fn persona_state(&self) -> PersonaState {
+        self.backend.friends().get_friend_persona_state(self.id)
+    }
+
+    /// The game the friend is playing, if any
+    pub fn game_played(&self) -> Option<FriendGame> {
+        self.backend.friends().get_friend_game_played(self.id)
+    }
+
+    /// The friend's steam level, `None` until it has been downloaded
+    pub fn steam_level(&self) -> Option<u32> {
+        match self.backend.friends().get_friend_steam_level(self.id) {
+            level if level > 0 => Some(level as u32),
+            _ => None,
+        }
+    }
+
+    /// The nickname the current user gave the friend, if any
+    pub fn nickname(&self) -> Option<String> {
+        self.backend.friends().get_player_nickname(self.id)
+    }
+
+    pub fn relationship(&self) -> FriendRelationship {
+        self.backend.friends().get_friend_relationship(self.id)
+    }
+}
+
+fn avatar_handle(backend: &dyn Backend, id: SteamId, size: ImageSize) -> i32 {
+    match size {
+        ImageSize::LARGE => backend.friends().get_large_friend_avatar(id),
+        ImageSize::MEDIUM => backend.friends().get_medium_friend_avatar(id),
+        ImageSize::SMALL => backend.friends().get_small_friend_avatar(id),
+    }
+}
+
+impl From<bindings::EPersonaState> for PersonaState {
+    fn from(state: bindings::EPersonaState) -> Self {
+        match state {
+            bindings::EPersonaState::k_EPersonaStateOnline => PersonaState::Online,
+            bindings::EPersonaState::k_EPersonaStateBusy => PersonaState::Busy,
+            bindings::EPersonaState::k_EPersonaStateAway => PersonaState::Away,
+            bindings::EPersonaState::k_EPersonaStateSnooze => PersonaState::Snooze,
+            bindings::EPersonaState::k_EPersonaStateLookingToTrade => PersonaState::LookingToTrade,
+            bindings::EPersonaState::k_EPersonaStateLookingToPlay => PersonaState::LookingToPlay,
+            bindings::EPersonaState::k_EPersonaStateInvisible => PersonaState::Invisible,
+            _ => PersonaState::Offline,
+        }
+    }
+}
+
+impl From<bindings::EFriendRelationship> for FriendRelationship {
+    fn from(relationship: bindings::EFriendRelationship) -> Self {
+        use bindings::EFriendRelationship as R;
+        match relationship {
+            R::k_EFriendRelationshipBlocked => FriendRelationship::Blocked,
+            R::k_EFriendRelationshipRequestRecipient => FriendRelationship::RequestRecipient,
+            R::k_EFriendRelationshipFriend => FriendRelationship::Friend,
+            R::k_EFriendRelationshipRequestInitiator => FriendRelationship::RequestInitiator,
+            R::k_EFriendRelationshipIgnored => FriendRelationship::Ignored,
+            R::k_EFriendRelationshipIgnoredFriend => FriendRelationship::IgnoredFriend,
+            _ => FriendRelationship::None,
+        }
+    }
+}
+
+impl From<bindings::FriendGameInfo_t> for FriendGame {
+    fn from(info: bindings::FriendGameInfo_t) -> Self {
+        let game_id = unsafe { info.m_gameID.__bindgen_anon_1.m_ulGameID };
+        let lobby = unsafe { info.m_steamIDLobby.m_steamid.m_unAll64Bits };
+        let ip = info.m_unGameIP;
+
+        Self {
+            // The low 24 bits of a game id are its app id
+            app_id: AppId((game_id & 0xFF_FFFF) as u32),
+            server: (ip != 0).then(|| SocketAddr::from((Ipv4Addr::from(ip), info.m_usGamePort))),
+            query_port: info.m_usQueryPort,
+            lobby: (lobby != 0).then_some(SteamId(lobby)),
+        }
+    }
 }
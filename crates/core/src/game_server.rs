@@ -0,0 +1,120 @@
+use super::*;
+use crate::backend::ServerBackend;
+
+/// How a game server authenticates players, see `steam_api::init_server`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ServerMode {
+    /// Don't authenticate users or list the server in the server browser
+    NoAuthentication,
+    /// Authenticate users and list the server in the server browser
+    Authentication,
+    /// Like `Authentication`, but also VAC secured
+    AuthenticationAndSecure,
+}
+
+/// A dedicated game server, created by `steam_api::init_server`
+///
+/// Server callbacks are pumped by `steam_api::run_callbacks` alongside the client's,
+/// and listened for with `steam_api::register_server_callback`.
+#[derive(Clone)]
+pub struct Server {
+    pub(crate) backend: Arc<dyn ServerBackend>,
+}
+
+impl Server {
+    pub(crate) fn new(backend: Arc<dyn ServerBackend>) -> Self {
+        Self { backend }
+    }
+
+    /// The server's steam id, only valid once logged on
+    pub fn steam_id(&self) -> SteamId {
+        self.backend.game_server().get_steam_id()
+    }
+
+    /// Logs on with a game server login token from the steam web api
+    ///
+    /// The outcome is reported by a `SteamServersConnected` or
+    /// `SteamServerConnectFailure` server callback.
+    pub fn log_on(&self, token: &str) {
+        self.backend.game_server().log_on(token)
+    }
+
+    /// Logs on with an anonymous account that changes every time
+    pub fn log_on_anonymous(&self) {
+        self.backend.game_server().log_on_anonymous()
+    }
+
+    pub fn log_off(&self) {
+        self.backend.game_server().log_off()
+    }
+
+    pub fn is_logged_on(&self) -> bool {
+        self.backend.game_server().logged_on()
+    }
+
+    /// Whether the server is VAC secured, known once `GSPolicyResponse` arrives
+    pub fn is_secure(&self) -> bool {
+        self.backend.game_server().secure()
+    }
+
+    /// Sets the game's product name, usually the app id, must be set before logging on
+    pub fn set_product(&self, product: &str) {
+        self.backend.game_server().set_product(product)
+    }
+
+    /// Sets the game description shown in the server browser, must be set before
+    /// logging on
+    pub fn set_game_description(&self, description: &str) {
+        self.backend.game_server().set_game_description(description)
+    }
+
+    /// Marks the server as dedicated rather than listen, must be set before logging on
+    pub fn set_dedicated_server(&self, dedicated: bool) {
+        self.backend.game_server().set_dedicated_server(dedicated)
+    }
+
+    pub fn set_server_name(&self, name: &str) {
+        self.backend.game_server().set_server_name(name)
+    }
+
+    pub fn set_map_name(&self, map: &str) {
+        self.backend.game_server().set_map_name(map)
+    }
+
+    pub fn set_max_players(&self, max_players: u32) {
+        self.backend
+            .game_server()
+            .set_max_player_count(max_players as i32)
+    }
+
+    /// Lists or hides the server in the server browser
+    pub fn set_advertise_server_active(&self, active: bool) {
+        self.backend
+            .game_server()
+            .set_advertise_server_active(active)
+    }
+
+    /// Starts validating a ticket a player sent us, the session stays open until
+    /// `end_auth_session`
+    pub fn begin_auth_session(&self, ticket: &[u8], steam_id: SteamId) -> SResult<()> {
+        self.backend
+            .game_server()
+            .begin_auth_session(ticket, steam_id)
+            .into_result()
+    }
+
+    /// Ends a session started by `begin_auth_session`, e.g. when the player leaves
+    pub fn end_auth_session(&self, steam_id: SteamId) {
+        self.backend.game_server().end_auth_session(steam_id)
+    }
+}
+
+impl From<ServerMode> for bindings::EServerMode {
+    fn from(mode: ServerMode) -> Self {
+        match mode {
+            ServerMode::NoAuthentication => Self::eServerModeNoAuthentication,
+            ServerMode::Authentication => Self::eServerModeAuthentication,
+            ServerMode::AuthenticationAndSecure => Self::eServerModeAuthenticationAndSecure,
+        }
+    }
+}
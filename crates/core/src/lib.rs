@@ -1,15 +1,44 @@
 use core::fmt;
+use std::sync::Arc;
 use steamstacks_bindings as bindings;
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::backend::Backend;
+
 pub use crate::apps::*;
+#[cfg(feature = "encrypted-app-ticket")]
+pub use crate::encrypted_app_ticket::*;
 pub use crate::friends::*;
+pub use crate::game_server::*;
+pub use crate::matchmaking::*;
+pub use crate::networking_messages::*;
+pub use crate::networking_sockets::*;
+pub use crate::networking_types::*;
+pub use crate::remote_storage::*;
+pub use crate::steam_id::*;
+pub use crate::ugc::*;
 pub use crate::user::*;
+pub use crate::user_stats::*;
 pub use crate::utils::*;
 
 pub mod apps;
+pub mod backend;
+#[cfg(feature = "encrypted-app-ticket")]
+pub mod encrypted_app_ticket;
 pub mod friends;
+pub mod game_server;
+pub mod matchmaking;
+pub mod networking_messages;
+pub mod networking_sockets;
+pub mod networking_types;
+pub mod remote_storage;
 pub mod steam_api;
+pub mod steam_id;
+pub mod ugc;
 pub mod user;
+pub mod user_stats;
 pub mod utils;
 
 #[macro_use]
@@ -22,7 +51,7 @@ extern crate bitflags;
 extern crate lazy_static;
 
 /// An id for a steam app/game
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct AppId(pub u32);
 
@@ -32,14 +61,4 @@ impl From<u32> for AppId {
     }
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub struct SteamId(pub(crate) u64);
-
-impl fmt::Display for SteamId {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.0)
-    }
-}
-
 pub type SResult<T> = Result<T, SteamResult>;
@@ -0,0 +1,434 @@
+use super::*;
+use std::future::Future;
+
+use crate::callbacks::{LobbyCreated, LobbyEnter, LobbyMatchList};
+use crate::result::check;
+
+/// Who can find and join a lobby
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LobbyType {
+    /// Only joinable by invite
+    Private,
+    /// Joinable by friends and by invite
+    FriendsOnly,
+    /// Returned by lobby searches and joinable by anyone
+    Public,
+    /// Joinable by anyone who knows its id, but not returned by searches
+    Invisible,
+    /// Private, and the only lobby of this type the user can be in
+    PrivateUnique,
+}
+
+/// How a lobby's value is compared to a filter's value
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LobbyComparison {
+    EqualToOrLessThan,
+    LessThan,
+    Equal,
+    GreaterThan,
+    EqualToOrGreaterThan,
+    NotEqual,
+}
+
+/// How far away lobbies may be, by the region they were created in
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LobbyDistanceFilter {
+    /// Only the same region
+    Close,
+    /// The same or nearby regions
+    Default,
+    /// Half the world
+    Far,
+    Worldwide,
+}
+
+/// Response to an attempt to enter a lobby
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChatRoomEnterResponse {
+    Success,
+    DoesntExist,
+    NotAllowed,
+    Full,
+    Error,
+    Banned,
+    Limited,
+    ClanDisabled,
+    CommunityBan,
+    MemberBlockedYou,
+    YouBlockedMember,
+    RatelimitExceeded,
+}
+
+/// The kind of a lobby chat entry
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChatEntryType {
+    Invalid,
+    ChatMsg,
+    Typing,
+    InviteGame,
+    Emote,
+    LeftConversation,
+    Entered,
+    WasKicked,
+    WasBanned,
+    Disconnected,
+    HistoricalChat,
+    LinkBlocked,
+}
+
+bitflags! {
+    /// What happened to a lobby member, see `LobbyChatUpdate`
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    pub struct ChatMemberStateChange: u32 {
+        const ENTERED      = 0x0001;
+        const LEFT         = 0x0002;
+        const DISCONNECTED = 0x0004;
+        const KICKED       = 0x0008;
+        const BANNED       = 0x0010;
+    }
+}
+
+/// A message sent to a lobby's chat
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LobbyChatEntry {
+    pub sender: SteamId,
+    pub entry_type: ChatEntryType,
+    pub data: Vec<u8>,
+}
+
+// Steam rejects lobby chat messages larger than this
+pub(crate) const MAX_LOBBY_CHAT_MESSAGE: usize = 4096;
+
+/// Lobbies to gather users before a game, with shared metadata and a chat
+///
+/// Lobby ids are `SteamId`s, and the current user's membership changes are reported
+/// through [`LobbyEnter`](crate::callbacks::LobbyEnter) and
+/// [`LobbyChatUpdate`](crate::callbacks::LobbyChatUpdate) callbacks.
+#[derive(Clone)]
+pub struct Matchmaking {
+    pub(crate) backend: Arc<dyn Backend>,
+}
+
+impl Matchmaking {
+    pub(crate) fn new(backend: Arc<dyn Backend>) -> Self {
+        Self { backend }
+    }
+
+    /// Creates a lobby owned by the current user, who enters it right away
+    pub fn create_lobby(
+        &self,
+        lobby_type: LobbyType,
+        max_members: u32,
+    ) -> impl Future<Output = SResult<SteamId>> {
+        CallResultFuture::map(
+            self.backend
+                .matchmaking()
+                .create_lobby(lobby_type, max_members as i32),
+            |created: &LobbyCreated| {
+                created.result.into_result()?;
+                Ok(created.lobby)
+            },
+        )
+    }
+
+    /// Enters a lobby, check `LobbyEnter::response` to see whether it worked
+    pub fn join_lobby(&self, lobby: SteamId) -> impl Future<Output = SResult<LobbyEnter>> {
+        CallResultFuture::new(self.backend.matchmaking().join_lobby(lobby))
+    }
+
+    pub fn leave_lobby(&self, lobby: SteamId) {
+        self.backend.matchmaking().leave_lobby(lobby)
+    }
+
+    /// Starts a search for public lobbies, filtered by lobby metadata
+    pub fn request_lobby_list(&self) -> LobbyListQuery {
+        LobbyListQuery {
+            backend: self.backend.clone(),
+            string_filters: Vec::new(),
+            numerical_filters: Vec::new(),
+            near_filters: Vec::new(),
+            slots_available: None,
+            distance: None,
+            max_results: None,
+        }
+    }
+
+    /// Members of a lobby the current user is in
+    pub fn lobby_members(&self, lobby: SteamId) -> Vec<SteamId> {
+        let matchmaking = self.backend.matchmaking();
+        (0..matchmaking.get_num_lobby_members(lobby))
+            .map(|idx| matchmaking.get_lobby_member_by_index(lobby, idx))
+            .collect()
+    }
+
+    pub fn lobby_owner(&self, lobby: SteamId) -> SteamId {
+        self.backend.matchmaking().get_lobby_owner(lobby)
+    }
+
+    /// Hands the lobby to another member, only the owner can do this
+    pub fn set_lobby_owner(&self, lobby: SteamId, owner: SteamId) -> SResult<()> {
+        check(self.backend.matchmaking().set_lobby_owner(lobby, owner))
+    }
+
+    /// The most members a lobby can hold, `None` if it isn't known
+    pub fn lobby_member_limit(&self, lobby: SteamId) -> Option<u32> {
+        match self.backend.matchmaking().get_lobby_member_limit(lobby) {
+            0 => None,
+            limit => Some(limit as u32),
+        }
+    }
+
+    pub fn set_lobby_member_limit(&self, lobby: SteamId, max_members: u32) -> SResult<()> {
+        check(
+            self.backend
+                .matchmaking()
+                .set_lobby_member_limit(lobby, max_members as i32),
+        )
+    }
+
+    pub fn set_lobby_type(&self, lobby: SteamId, lobby_type: LobbyType) -> SResult<()> {
+        check(self.backend.matchmaking().set_lobby_type(lobby, lobby_type))
+    }
+
+    /// Stops or allows users from joining, without changing who can find the lobby
+    pub fn set_lobby_joinable(&self, lobby: SteamId, joinable: bool) -> SResult<()> {
+        check(
+            self.backend
+                .matchmaking()
+                .set_lobby_joinable(lobby, joinable),
+        )
+    }
+
+    /// A lobby metadata value, `None` if it isn't set
+    pub fn lobby_data(&self, lobby: SteamId, key: &str) -> Option<String> {
+        let value = self.backend.matchmaking().get_lobby_data(lobby, key);
+        (!value.is_empty()).then_some(value)
+    }
+
+    /// Sets a lobby metadata value, only the owner can do this
+    pub fn set_lobby_data(&self, lobby: SteamId, key: &str, value: &str) -> SResult<()> {
+        check(self.backend.matchmaking().set_lobby_data(lobby, key, value))
+    }
+
+    pub fn delete_lobby_data(&self, lobby: SteamId, key: &str) -> SResult<()> {
+        check(self.backend.matchmaking().delete_lobby_data(lobby, key))
+    }
+
+    /// Every metadata key and value of a lobby
+    pub fn all_lobby_data(&self, lobby: SteamId) -> Vec<(String, String)> {
+        let matchmaking = self.backend.matchmaking();
+        (0..matchmaking.get_lobby_data_count(lobby))
+            .filter_map(|idx| matchmaking.get_lobby_data_by_index(lobby, idx))
+            .collect()
+    }
+
+    /// Asks steam for the metadata of a lobby we aren't in, which arrives as a
+    /// [`LobbyDataUpdate`](crate::callbacks::LobbyDataUpdate)
+    pub fn request_lobby_data(&self, lobby: SteamId) -> SResult<()> {
+        check(self.backend.matchmaking().request_lobby_data(lobby))
+    }
+
+    /// A member's metadata value, `None` if it isn't set
+    pub fn lobby_member_data(&self, lobby: SteamId, member: SteamId, key: &str) -> Option<String> {
+        let value = self
+            .backend
+            .matchmaking()
+            .get_lobby_member_data(lobby, member, key);
+        (!value.is_empty()).then_some(value)
+    }
+
+    /// Sets a metadata value for the current user in a lobby
+    pub fn set_lobby_member_data(&self, lobby: SteamId, key: &str, value: &str) {
+        self.backend
+            .matchmaking()
+            .set_lobby_member_data(lobby, key, value)
+    }
+
+    /// Sends a message to everyone in the lobby, including ourselves
+    ///
+    /// Messages are received as [`LobbyChatMsg`](crate::callbacks::LobbyChatMsg)
+    /// callbacks and read with `lobby_chat_entry`.
+    pub fn send_lobby_chat_message(&self, lobby: SteamId, data: &[u8]) -> SResult<()> {
+        if data.len() > MAX_LOBBY_CHAT_MESSAGE {
+            return Err(SteamResult::InvalidParameter);
+        }
+        check(self.backend.matchmaking().send_lobby_chat_msg(lobby, data))
+    }
+
+    /// Reads the chat entry of a `LobbyChatMsg` callback
+    pub fn lobby_chat_entry(&self, lobby: SteamId, chat_id: u32) -> Option<LobbyChatEntry> {
+        self.backend
+            .matchmaking()
+            .get_lobby_chat_entry(lobby, chat_id as i32)
+    }
+}
+
+/// A search for lobbies, created by `Matchmaking::request_lobby_list`
+#[must_use = "queries do nothing until sent"]
+pub struct LobbyListQuery {
+    backend: Arc<dyn Backend>,
+    string_filters: Vec<(String, String, LobbyComparison)>,
+    numerical_filters: Vec<(String, i32, LobbyComparison)>,
+    near_filters: Vec<(String, i32)>,
+    slots_available: Option<u32>,
+    distance: Option<LobbyDistanceFilter>,
+    max_results: Option<u32>,
+}
+
+impl LobbyListQuery {
+    /// Only match lobbies whose value for `key` compares to `value`
+    pub fn string_filter(
+        mut self,
+        key: impl Into<String>,
+        value: impl Into<String>,
+        comparison: LobbyComparison,
+    ) -> Self {
+        self.string_filters
+            .push((key.into(), value.into(), comparison));
+        self
+    }
+
+    /// Only match lobbies whose value for `key` is a number that compares to `value`
+    pub fn numerical_filter(
+        mut self,
+        key: impl Into<String>,
+        value: i32,
+        comparison: LobbyComparison,
+    ) -> Self {
+        self.numerical_filters.push((key.into(), value, comparison));
+        self
+    }
+
+    /// Sort lobbies by how close their value for `key` is to `value`
+    ///
+    /// Earlier near filters take priority when sorting.
+    pub fn near_filter(mut self, key: impl Into<String>, value: i32) -> Self {
+        self.near_filters.push((key.into(), value));
+        self
+    }
+
+    /// Only match lobbies with room for this many more members
+    pub fn slots_available(mut self, slots: u32) -> Self {
+        self.slots_available = Some(slots);
+        self
+    }
+
+    pub fn distance(mut self, distance: LobbyDistanceFilter) -> Self {
+        self.distance = Some(distance);
+        self
+    }
+
+    /// Return at most this many lobbies
+    pub fn max_results(mut self, max_results: u32) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+
+    pub fn send(self) -> impl Future<Output = SResult<Vec<SteamId>>> {
+        // Filters apply to the next request only, so they're all set right before it
+        let matchmaking = self.backend.matchmaking();
+        for (key, value, comparison) in &self.string_filters {
+            matchmaking.add_request_lobby_list_string_filter(key, value, *comparison);
+        }
+        for (key, value, comparison) in &self.numerical_filters {
+            matchmaking.add_request_lobby_list_numerical_filter(key, *value, *comparison);
+        }
+        for (key, value) in &self.near_filters {
+            matchmaking.add_request_lobby_list_near_value_filter(key, *value);
+        }
+        if let Some(slots) = self.slots_available {
+            matchmaking.add_request_lobby_list_filter_slots_available(slots as i32);
+        }
+        if let Some(distance) = self.distance {
+            matchmaking.add_request_lobby_list_distance_filter(distance);
+        }
+        if let Some(max_results) = self.max_results {
+            matchmaking.add_request_lobby_list_result_count_filter(max_results as i32);
+        }
+        let api_call = matchmaking.request_lobby_list();
+
+        // Results are only valid until the next request, so read them right away
+        let backend = self.backend.clone();
+        CallResultFuture::map(api_call, move |list: &LobbyMatchList| {
+            let matchmaking = backend.matchmaking();
+            Ok((0..list.lobbies_matching as i32)
+                .map(|idx| matchmaking.get_lobby_by_index(idx))
+                .collect())
+        })
+    }
+}
+
+impl From<LobbyType> for bindings::ELobbyType {
+    fn from(lobby_type: LobbyType) -> Self {
+        match lobby_type {
+            LobbyType::Private => Self::k_ELobbyTypePrivate,
+            LobbyType::FriendsOnly => Self::k_ELobbyTypeFriendsOnly,
+            LobbyType::Public => Self::k_ELobbyTypePublic,
+            LobbyType::Invisible => Self::k_ELobbyTypeInvisible,
+            LobbyType::PrivateUnique => Self::k_ELobbyTypePrivateUnique,
+        }
+    }
+}
+
+impl From<LobbyComparison> for bindings::ELobbyComparison {
+    fn from(comparison: LobbyComparison) -> Self {
+        match comparison {
+            LobbyComparison::EqualToOrLessThan => Self::k_ELobbyComparisonEqualToOrLessThan,
+            LobbyComparison::LessThan => Self::k_ELobbyComparisonLessThan,
+            LobbyComparison::Equal => Self::k_ELobbyComparisonEqual,
+            LobbyComparison::GreaterThan => Self::k_ELobbyComparisonGreaterThan,
+            LobbyComparison::EqualToOrGreaterThan => Self::k_ELobbyComparisonEqualToOrGreaterThan,
+            LobbyComparison::NotEqual => Self::k_ELobbyComparisonNotEqual,
+        }
+    }
+}
+
+impl From<LobbyDistanceFilter> for bindings::ELobbyDistanceFilter {
+    fn from(distance: LobbyDistanceFilter) -> Self {
+        match distance {
+            LobbyDistanceFilter::Close => Self::k_ELobbyDistanceFilterClose,
+            LobbyDistanceFilter::Default => Self::k_ELobbyDistanceFilterDefault,
+            LobbyDistanceFilter::Far => Self::k_ELobbyDistanceFilterFar,
+            LobbyDistanceFilter::Worldwide => Self::k_ELobbyDistanceFilterWorldwide,
+        }
+    }
+}
+
+impl From<u32> for ChatRoomEnterResponse {
+    fn from(response: u32) -> Self {
+        match response {
+            1 => ChatRoomEnterResponse::Success,
+            2 => ChatRoomEnterResponse::DoesntExist,
+            3 => ChatRoomEnterResponse::NotAllowed,
+            4 => ChatRoomEnterResponse::Full,
+            6 => ChatRoomEnterResponse::Banned,
+            7 => ChatRoomEnterResponse::Limited,
+            8 => ChatRoomEnterResponse::ClanDisabled,
+            9 => ChatRoomEnterResponse::CommunityBan,
+            10 => ChatRoomEnterResponse::MemberBlockedYou,
+            11 => ChatRoomEnterResponse::YouBlockedMember,
+            15 => ChatRoomEnterResponse::RatelimitExceeded,
+            _ => ChatRoomEnterResponse::Error,
+        }
+    }
+}
+
+impl From<u8> for ChatEntryType {
+    fn from(entry_type: u8) -> Self {
+        match entry_type {
+            1 => ChatEntryType::ChatMsg,
+            2 => ChatEntryType::Typing,
+            3 => ChatEntryType::InviteGame,
+            4 => ChatEntryType::Emote,
+            6 => ChatEntryType::LeftConversation,
+            7 => ChatEntryType::Entered,
+            8 => ChatEntryType::WasKicked,
+            9 => ChatEntryType::WasBanned,
+            10 => ChatEntryType::Disconnected,
+            11 => ChatEntryType::HistoricalChat,
+            14 => ChatEntryType::LinkBlocked,
+            _ => ChatEntryType::Invalid,
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use super::*;
+
+/// Connectionless messaging with other users, similar to udp
+///
+/// A session is opened with a user the first time a message is sent to them. Messages
+/// from a user we haven't talked to yet trigger a `NetworkingMessagesSessionRequest`,
+/// and are only received once the session is accepted.
+#[derive(Clone)]
+pub struct NetworkingMessages {
+    pub(crate) backend: Arc<dyn Backend>,
+}
+
+impl NetworkingMessages {
+    pub(crate) fn new(backend: Arc<dyn Backend>) -> Self {
+        Self { backend }
+    }
+
+    /// Sends a message to a user, opening a session with them if needed
+    pub fn send_message_to_user(
+        &self,
+        identity: &NetworkingIdentity,
+        channel: i32,
+        data: &[u8],
+        flags: SendFlags,
+    ) -> SResult<()> {
+        self.backend
+            .networking_messages()
+            .send_message_to_user(identity, data, flags, channel)
+            .into_result()
+    }
+
+    /// Takes up to `max` messages that have arrived on a channel from any user
+    pub fn receive_messages_on_channel(&self, channel: i32, max: usize) -> Vec<NetworkingMessage> {
+        self.backend
+            .networking_messages()
+            .receive_messages_on_channel(channel, max)
+    }
+
+    /// Accepts a session requested through `NetworkingMessagesSessionRequest`
+    pub fn accept_session(&self, identity: &NetworkingIdentity) -> SResult<()> {
+        if !self
+            .backend
+            .networking_messages()
+            .accept_session_with_user(identity)
+        {
+            return Err(SteamResult::NoConnection);
+        }
+        Ok(())
+    }
+
+    /// Closes every channel with a user, dropping any messages that weren't received
+    pub fn close_session(&self, identity: &NetworkingIdentity) -> SResult<()> {
+        if !self
+            .backend
+            .networking_messages()
+            .close_session_with_user(identity)
+        {
+            return Err(SteamResult::NoConnection);
+        }
+        Ok(())
+    }
+
+    /// Closes one channel with a user, the session is closed along with its last channel
+    pub fn close_channel(&self, identity: &NetworkingIdentity, channel: i32) -> SResult<()> {
+        if !self
+            .backend
+            .networking_messages()
+            .close_channel_with_user(identity, channel)
+        {
+            return Err(SteamResult::NoConnection);
+        }
+        Ok(())
+    }
+
+    /// The state of the session with a user, `None` if there isn't one
+    pub fn session_info(&self, identity: &NetworkingIdentity) -> Option<NetConnectionInfo> {
+        self.backend
+            .networking_messages()
+            .get_session_connection_info(identity)
+    }
+}
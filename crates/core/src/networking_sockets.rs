@@ -0,0 +1,351 @@
+use super::*;
+use std::net::SocketAddr;
+
+use crate::callbacks::string_from_chars;
+use crate::networking_types::ip_addr_from_raw;
+use crate::result::check;
+
+/// Connection oriented networking over ip or steam's relay network
+#[derive(Clone)]
+pub struct NetworkingSockets {
+    pub(crate) backend: Arc<dyn Backend>,
+}
+
+/// Identifies a connection, as reported by `NetConnectionStatusChanged`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ConnectionHandle(pub(crate) u32);
+
+/// Identifies a listen socket, as reported in `NetConnectionInfo`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ListenSocketHandle(pub(crate) u32);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NetworkingConnectionState {
+    None,
+    Connecting,
+    FindingRoute,
+    Connected,
+    ClosedByPeer,
+    ProblemDetectedLocally,
+    FinWait,
+    Linger,
+    Dead,
+}
+
+/// A snapshot of a connection's state
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NetConnectionInfo {
+    pub remote_identity: NetworkingIdentity,
+    pub user_data: i64,
+    /// The socket this connection was accepted on, `None` if we initiated it
+    pub listen_socket: Option<ListenSocketHandle>,
+    pub remote_address: Option<SocketAddr>,
+    pub state: NetworkingConnectionState,
+    /// Why the connection was closed, one of the `k_ESteamNetConnectionEnd` codes
+    pub end_reason: i32,
+    pub end_debug: String,
+    pub description: String,
+}
+
+impl NetworkingSockets {
+    pub(crate) fn new(backend: Arc<dyn Backend>) -> Self {
+        Self { backend }
+    }
+
+    /// Listens for connections made with `connect_by_ip_address`
+    pub fn create_listen_socket_ip(&self, addr: SocketAddr) -> SResult<ListenSocket> {
+        let handle = self
+            .backend
+            .networking_sockets()
+            .create_listen_socket_ip(addr);
+        self.listen_socket(handle)
+    }
+
+    /// Listens for connections made with `connect_p2p` on a virtual port
+    pub fn create_listen_socket_p2p(&self, virtual_port: i32) -> SResult<ListenSocket> {
+        let handle = self
+            .backend
+            .networking_sockets()
+            .create_listen_socket_p2p(virtual_port);
+        self.listen_socket(handle)
+    }
+
+    /// Starts connecting to a listen socket, completion is reported by `NetConnectionStatusChanged`
+    pub fn connect_by_ip_address(&self, addr: SocketAddr) -> SResult<NetConnection> {
+        let handle = self
+            .backend
+            .networking_sockets()
+            .connect_by_ip_address(addr);
+        self.new_connection(handle)
+    }
+
+    /// Starts connecting to a peer's p2p listen socket, completion is reported by
+    /// `NetConnectionStatusChanged`
+    pub fn connect_p2p(
+        &self,
+        identity: &NetworkingIdentity,
+        virtual_port: i32,
+    ) -> SResult<NetConnection> {
+        let handle = self
+            .backend
+            .networking_sockets()
+            .connect_p2p(identity, virtual_port);
+        self.new_connection(handle)
+    }
+
+    /// Accepts an incoming connection reported by `NetConnectionStatusChanged`, taking
+    /// ownership of it
+    ///
+    /// Only works while the connection is still connecting, so each connection can be
+    /// accepted once. Fails with `InvalidState` otherwise.
+    pub fn accept_connection(&self, handle: ConnectionHandle) -> SResult<NetConnection> {
+        self.backend
+            .networking_sockets()
+            .accept_connection(handle.0)
+            .into_result()?;
+
+        Ok(NetConnection {
+            backend: self.backend.clone(),
+            handle: handle.0,
+            closed: false,
+        })
+    }
+
+    /// Turns down an incoming connection that is still connecting, telling the remote
+    /// host why
+    ///
+    /// Fails with `InvalidState` for any other connection, those are closed by their
+    /// [`NetConnection`].
+    pub fn reject_connection(
+        &self,
+        handle: ConnectionHandle,
+        reason: i32,
+        debug: &str,
+    ) -> SResult<()> {
+        let sockets = self.backend.networking_sockets();
+        let info = sockets
+            .get_connection_info(handle.0)
+            .ok_or(SteamResult::InvalidParameter)?;
+        if info.listen_socket.is_none() || info.state != NetworkingConnectionState::Connecting {
+            return Err(SteamResult::InvalidState);
+        }
+
+        check(sockets.close_connection(handle.0, reason, debug, false))
+    }
+
+    /// Looks up any connection without taking ownership of it
+    pub fn connection_info(&self, handle: ConnectionHandle) -> Option<NetConnectionInfo> {
+        self.backend
+            .networking_sockets()
+            .get_connection_info(handle.0)
+    }
+
+    /// Creates a group to receive messages from many connections at once
+    pub fn create_poll_group(&self) -> SResult<PollGroup> {
+        let handle = self.backend.networking_sockets().create_poll_group();
+        if handle == bindings::k_HSteamNetPollGroup_Invalid {
+            return Err(SteamResult::Generic);
+        }
+
+        Ok(PollGroup {
+            backend: self.backend.clone(),
+            handle,
+        })
+    }
+
+    fn listen_socket(&self, handle: u32) -> SResult<ListenSocket> {
+        if handle == bindings::k_HSteamListenSocket_Invalid {
+            return Err(SteamResult::Generic);
+        }
+
+        Ok(ListenSocket {
+            backend: self.backend.clone(),
+            handle,
+        })
+    }
+
+    fn new_connection(&self, handle: u32) -> SResult<NetConnection> {
+        if handle == bindings::k_HSteamNetConnection_Invalid {
+            return Err(SteamResult::Generic);
+        }
+
+        Ok(NetConnection {
+            backend: self.backend.clone(),
+            handle,
+            closed: false,
+        })
+    }
+}
+
+/// A socket accepting incoming connections, closed when dropped
+///
+/// Closing the socket also closes every connection that was accepted on it.
+pub struct ListenSocket {
+    backend: Arc<dyn Backend>,
+    handle: u32,
+}
+
+impl ListenSocket {
+    pub fn handle(&self) -> ListenSocketHandle {
+        ListenSocketHandle(self.handle)
+    }
+
+    /// The local address this socket is bound to, `None` for p2p sockets
+    pub fn address(&self) -> Option<SocketAddr> {
+        self.backend
+            .networking_sockets()
+            .get_listen_socket_address(self.handle)
+    }
+}
+
+impl Drop for ListenSocket {
+    fn drop(&mut self) {
+        self.backend
+            .networking_sockets()
+            .close_listen_socket(self.handle);
+    }
+}
+
+/// A connection to a remote host, closed when dropped
+pub struct NetConnection {
+    backend: Arc<dyn Backend>,
+    handle: u32,
+    closed: bool,
+}
+
+impl NetConnection {
+    pub fn handle(&self) -> ConnectionHandle {
+        ConnectionHandle(self.handle)
+    }
+
+    /// Queues a message for sending, returning its message number
+    pub fn send_message(&self, data: &[u8], flags: SendFlags) -> SResult<i64> {
+        self.backend
+            .networking_sockets()
+            .send_message_to_connection(self.handle, data, flags)
+    }
+
+    /// Sends any messages held back by nagle right away
+    pub fn flush_messages(&self) -> SResult<()> {
+        self.backend
+            .networking_sockets()
+            .flush_messages_on_connection(self.handle)
+            .into_result()
+    }
+
+    /// Takes up to `max` messages that have arrived on this connection
+    pub fn receive_messages(&self, max: usize) -> Vec<NetworkingMessage> {
+        self.backend
+            .networking_sockets()
+            .receive_messages_on_connection(self.handle, max)
+    }
+
+    pub fn info(&self) -> Option<NetConnectionInfo> {
+        self.backend
+            .networking_sockets()
+            .get_connection_info(self.handle)
+    }
+
+    /// Moves the connection's messages to a poll group, see [`PollGroup::receive_messages`]
+    pub fn set_poll_group(&self, group: &PollGroup) -> SResult<()> {
+        if !self
+            .backend
+            .networking_sockets()
+            .set_connection_poll_group(self.handle, group.handle)
+        {
+            return Err(SteamResult::InvalidParameter);
+        }
+        Ok(())
+    }
+
+    /// Closes the connection, telling the remote host why
+    ///
+    /// With `linger` set, reliable messages that are still queued are flushed first.
+    pub fn close(mut self, reason: i32, debug: &str, linger: bool) -> SResult<()> {
+        self.closed = true;
+        if !self
+            .backend
+            .networking_sockets()
+            .close_connection(self.handle, reason, debug, linger)
+        {
+            return Err(SteamResult::InvalidParameter);
+        }
+        Ok(())
+    }
+}
+
+impl Drop for NetConnection {
+    fn drop(&mut self) {
+        if !self.closed {
+            self.backend
+                .networking_sockets()
+                .close_connection(self.handle, 0, "", false);
+        }
+    }
+}
+
+/// A set of connections whose messages are received together, destroyed when dropped
+pub struct PollGroup {
+    backend: Arc<dyn Backend>,
+    handle: u32,
+}
+
+impl PollGroup {
+    /// Takes up to `max` messages that have arrived on any connection in the group
+    pub fn receive_messages(&self, max: usize) -> Vec<NetworkingMessage> {
+        self.backend
+            .networking_sockets()
+            .receive_messages_on_poll_group(self.handle, max)
+    }
+}
+
+impl Drop for PollGroup {
+    fn drop(&mut self) {
+        self.backend
+            .networking_sockets()
+            .destroy_poll_group(self.handle);
+    }
+}
+
+impl From<bindings::ESteamNetworkingConnectionState> for NetworkingConnectionState {
+    fn from(state: bindings::ESteamNetworkingConnectionState) -> Self {
+        use bindings::ESteamNetworkingConnectionState::*;
+        match state {
+            k_ESteamNetworkingConnectionState_Connecting => NetworkingConnectionState::Connecting,
+            k_ESteamNetworkingConnectionState_FindingRoute => {
+                NetworkingConnectionState::FindingRoute
+            }
+            k_ESteamNetworkingConnectionState_Connected => NetworkingConnectionState::Connected,
+            k_ESteamNetworkingConnectionState_ClosedByPeer => {
+                NetworkingConnectionState::ClosedByPeer
+            }
+            k_ESteamNetworkingConnectionState_ProblemDetectedLocally => {
+                NetworkingConnectionState::ProblemDetectedLocally
+            }
+            k_ESteamNetworkingConnectionState_FinWait => NetworkingConnectionState::FinWait,
+            k_ESteamNetworkingConnectionState_Linger => NetworkingConnectionState::Linger,
+            k_ESteamNetworkingConnectionState_Dead => NetworkingConnectionState::Dead,
+            _ => NetworkingConnectionState::None,
+        }
+    }
+}
+
+impl From<&bindings::SteamNetConnectionInfo_t> for NetConnectionInfo {
+    fn from(info: &bindings::SteamNetConnectionInfo_t) -> Self {
+        let listen_socket = info.m_hListenSocket;
+        let end_debug = info.m_szEndDebug;
+        let description = info.m_szConnectionDescription;
+
+        Self {
+            remote_identity: NetworkingIdentity::from(&{ info.m_identityRemote }),
+            user_data: info.m_nUserData,
+            listen_socket: (listen_socket != bindings::k_HSteamListenSocket_Invalid)
+                .then_some(ListenSocketHandle(listen_socket)),
+            remote_address: ip_addr_from_raw(&{ info.m_addrRemote }),
+            state: info.m_eState.into(),
+            end_reason: info.m_eEndReason,
+            end_debug: string_from_chars(&end_debug),
+            description: string_from_chars(&description),
+        }
+    }
+}
@@ -0,0 +1,210 @@
+use super::*;
+use std::ffi::{CStr, CString};
+use std::net::{IpAddr, Ipv6Addr, SocketAddr};
+
+use crate::backend::MessageBuffer;
+use crate::networking_sockets::ConnectionHandle;
+
+/// Who is on the other end of a networking connection or session
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum NetworkingIdentity {
+    Invalid,
+    SteamId(SteamId),
+    IpAddress(SocketAddr),
+    GenericString(String),
+    GenericBytes(Vec<u8>),
+}
+
+impl From<SteamId> for NetworkingIdentity {
+    fn from(id: SteamId) -> Self {
+        NetworkingIdentity::SteamId(id)
+    }
+}
+
+impl From<SocketAddr> for NetworkingIdentity {
+    fn from(addr: SocketAddr) -> Self {
+        NetworkingIdentity::IpAddress(addr)
+    }
+}
+
+impl From<&NetworkingIdentity> for bindings::SteamNetworkingIdentity {
+    fn from(identity: &NetworkingIdentity) -> Self {
+        unsafe {
+            let mut raw = std::mem::zeroed();
+            bindings::SteamAPI_SteamNetworkingIdentity_Clear(&mut raw);
+
+            // Values steam can't represent are left as an invalid identity
+            match identity {
+                NetworkingIdentity::Invalid => {}
+                NetworkingIdentity::SteamId(id) => {
+                    bindings::SteamAPI_SteamNetworkingIdentity_SetSteamID64(&mut raw, id.0)
+                }
+                NetworkingIdentity::IpAddress(addr) => {
+                    let addr = ip_addr_to_raw(*addr);
+                    bindings::SteamAPI_SteamNetworkingIdentity_SetIPAddr(&mut raw, &addr)
+                }
+                NetworkingIdentity::GenericString(string) => {
+                    if let Ok(string) = CString::new(string.as_str()) {
+                        bindings::SteamAPI_SteamNetworkingIdentity_SetGenericString(
+                            &mut raw,
+                            string.as_ptr(),
+                        );
+                    }
+                }
+                NetworkingIdentity::GenericBytes(bytes) => {
+                    bindings::SteamAPI_SteamNetworkingIdentity_SetGenericBytes(
+                        &mut raw,
+                        bytes.as_ptr() as *const _,
+                        bytes.len() as u32,
+                    );
+                }
+            }
+            raw
+        }
+    }
+}
+
+impl From<&bindings::SteamNetworkingIdentity> for NetworkingIdentity {
+    fn from(raw: &bindings::SteamNetworkingIdentity) -> Self {
+        // The accessors take a mutable pointer, so work on a copy
+        let mut raw = *raw;
+        let kind = raw.m_eType;
+
+        unsafe {
+            match kind {
+                bindings::ESteamNetworkingIdentityType::k_ESteamNetworkingIdentityType_SteamID => {
+                    NetworkingIdentity::SteamId(SteamId(
+                        bindings::SteamAPI_SteamNetworkingIdentity_GetSteamID64(&mut raw),
+                    ))
+                }
+                bindings::ESteamNetworkingIdentityType::k_ESteamNetworkingIdentityType_IPAddress => {
+                    let addr = bindings::SteamAPI_SteamNetworkingIdentity_GetIPAddr(&mut raw);
+                    match addr.as_ref().and_then(ip_addr_from_raw) {
+                        Some(addr) => NetworkingIdentity::IpAddress(addr),
+                        None => NetworkingIdentity::Invalid,
+                    }
+                }
+                bindings::ESteamNetworkingIdentityType::k_ESteamNetworkingIdentityType_GenericString => {
+                    let string = bindings::SteamAPI_SteamNetworkingIdentity_GetGenericString(&mut raw);
+                    if string.is_null() {
+                        return NetworkingIdentity::Invalid;
+                    }
+                    NetworkingIdentity::GenericString(
+                        CStr::from_ptr(string).to_string_lossy().into_owned(),
+                    )
+                }
+                bindings::ESteamNetworkingIdentityType::k_ESteamNetworkingIdentityType_GenericBytes => {
+                    let mut len = 0;
+                    let bytes =
+                        bindings::SteamAPI_SteamNetworkingIdentity_GetGenericBytes(&mut raw, &mut len);
+                    if bytes.is_null() {
+                        return NetworkingIdentity::Invalid;
+                    }
+                    NetworkingIdentity::GenericBytes(
+                        std::slice::from_raw_parts(bytes, len.max(0) as usize).to_vec(),
+                    )
+                }
+                _ => NetworkingIdentity::Invalid,
+            }
+        }
+    }
+}
+
+/// Steam stores every address as ipv6, with ipv4 addresses mapped into it
+pub(crate) fn ip_addr_to_raw(addr: SocketAddr) -> bindings::SteamNetworkingIPAddr {
+    let ip = match addr {
+        SocketAddr::V4(addr) => addr.ip().to_ipv6_mapped(),
+        SocketAddr::V6(addr) => *addr.ip(),
+    };
+    bindings::SteamNetworkingIPAddr {
+        __bindgen_anon_1: bindings::SteamNetworkingIPAddr__bindgen_ty_2 {
+            m_ipv6: ip.octets(),
+        },
+        m_port: addr.port(),
+    }
+}
+
+/// Reads an address, treating the all zeros address as unset
+pub(crate) fn ip_addr_from_raw(raw: &bindings::SteamNetworkingIPAddr) -> Option<SocketAddr> {
+    let ip = Ipv6Addr::from(unsafe { raw.__bindgen_anon_1.m_ipv6 });
+    if ip.is_unspecified() {
+        return None;
+    }
+
+    let ip = ip.to_ipv4_mapped().map_or(IpAddr::V6(ip), IpAddr::V4);
+    Some(SocketAddr::new(ip, raw.m_port))
+}
+
+bitflags! {
+    /// How a message should be sent
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    pub struct SendFlags: i32 {
+        const UNRELIABLE                  = bindings::k_nSteamNetworkingSend_Unreliable;
+        const NO_NAGLE                    = bindings::k_nSteamNetworkingSend_NoNagle;
+        const NO_DELAY                    = bindings::k_nSteamNetworkingSend_NoDelay;
+        const RELIABLE                    = bindings::k_nSteamNetworkingSend_Reliable;
+        const USE_CURRENT_THREAD          = bindings::k_nSteamNetworkingSend_UseCurrentThread;
+        const AUTO_RESTART_BROKEN_SESSION = bindings::k_nSteamNetworkingSend_AutoRestartBrokenSession;
+    }
+}
+
+/// A message received on a connection or channel, released when dropped
+#[derive(Debug)]
+pub struct NetworkingMessage {
+    data: Box<dyn MessageBuffer>,
+    connection: u32,
+    peer: NetworkingIdentity,
+    channel: i32,
+    reliable: bool,
+    message_number: i64,
+}
+
+impl NetworkingMessage {
+    pub(crate) fn new(
+        data: Box<dyn MessageBuffer>,
+        connection: u32,
+        peer: NetworkingIdentity,
+        channel: i32,
+        reliable: bool,
+        message_number: i64,
+    ) -> Self {
+        Self {
+            data,
+            connection,
+            peer,
+            channel,
+            reliable,
+            message_number,
+        }
+    }
+
+    pub fn data(&self) -> &[u8] {
+        self.data.data()
+    }
+
+    /// The connection this was received on, if it came through `NetworkingSockets`
+    pub fn connection(&self) -> Option<ConnectionHandle> {
+        (self.connection != bindings::k_HSteamNetConnection_Invalid)
+            .then_some(ConnectionHandle(self.connection))
+    }
+
+    /// Who sent the message
+    pub fn peer(&self) -> &NetworkingIdentity {
+        &self.peer
+    }
+
+    /// The channel this was received on, if it came through `NetworkingMessages`
+    pub fn channel(&self) -> i32 {
+        self.channel
+    }
+
+    pub fn is_reliable(&self) -> bool {
+        self.reliable
+    }
+
+    /// Increases by one for every message sent on the connection
+    pub fn message_number(&self) -> i64 {
+        self.message_number
+    }
+}
@@ -0,0 +1,198 @@
+use super::*;
+use std::io::{self, Cursor, Read, Write};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Files synced to the user's Steam Cloud storage
+#[derive(Clone)]
+pub struct RemoteStorage {
+    pub(crate) backend: Arc<dyn Backend>,
+}
+
+/// Cloud storage available to the app, in bytes
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Quota {
+    pub total: u64,
+    pub available: u64,
+}
+
+/// A file stored in the cloud, as listed by `RemoteStorage::files`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CloudFile {
+    pub name: String,
+    pub size: usize,
+}
+
+impl RemoteStorage {
+    pub(crate) fn new(backend: Arc<dyn Backend>) -> Self {
+        Self { backend }
+    }
+
+    /// Writes a whole file at once, replacing it if it exists
+    pub fn file_write(&self, name: &str, data: &[u8]) -> SResult<()> {
+        if !self.backend.remote_storage().file_write(name, data) {
+            return Err(SteamResult::Generic);
+        }
+        Ok(())
+    }
+
+    /// Reads a whole file at once
+    pub fn file_read(&self, name: &str) -> SResult<Vec<u8>> {
+        let size = self.file_size(name)?;
+        let mut data = vec![0; size];
+
+        let read = self.backend.remote_storage().file_read(name, &mut data);
+        if read as usize != size {
+            return Err(SteamResult::Generic);
+        }
+
+        Ok(data)
+    }
+
+    pub fn file_delete(&self, name: &str) -> SResult<()> {
+        if !self.backend.remote_storage().file_delete(name) {
+            return Err(SteamResult::FileNotFound);
+        }
+        Ok(())
+    }
+
+    pub fn file_exists(&self, name: &str) -> bool {
+        self.backend.remote_storage().file_exists(name)
+    }
+
+    /// Size of a file in bytes
+    pub fn file_size(&self, name: &str) -> SResult<usize> {
+        if !self.file_exists(name) {
+            return Err(SteamResult::FileNotFound);
+        }
+        Ok(self.backend.remote_storage().get_file_size(name) as usize)
+    }
+
+    /// When a file was last written
+    pub fn file_timestamp(&self, name: &str) -> SResult<SystemTime> {
+        if !self.file_exists(name) {
+            return Err(SteamResult::FileNotFound);
+        }
+        let secs = self.backend.remote_storage().get_file_timestamp(name);
+        Ok(UNIX_EPOCH + Duration::from_secs(secs.max(0) as u64))
+    }
+
+    pub fn quota(&self) -> SResult<Quota> {
+        let (total, available) = self
+            .backend
+            .remote_storage()
+            .get_quota()
+            .ok_or(SteamResult::Generic)?;
+        Ok(Quota { total, available })
+    }
+
+    /// Every file the app has stored in the cloud
+    pub fn files(&self) -> Vec<CloudFile> {
+        let storage = self.backend.remote_storage();
+        (0..storage.get_file_count())
+            .filter_map(|idx| storage.get_file_name_and_size(idx))
+            .map(|(name, size)| CloudFile {
+                name,
+                size: size.max(0) as usize,
+            })
+            .collect()
+    }
+
+    /// Opens a file for writing in chunks
+    ///
+    /// Nothing is stored until [`FileWriteStream::close`] is called, dropping the
+    /// stream cancels the write and leaves any existing file untouched.
+    pub fn file_write_stream(&self, name: &str) -> SResult<FileWriteStream> {
+        let handle = self.backend.remote_storage().file_write_stream_open(name);
+        if handle == bindings::k_UGCFileStreamHandleInvalid {
+            return Err(SteamResult::Generic);
+        }
+
+        Ok(FileWriteStream {
+            backend: self.backend.clone(),
+            handle,
+            closed: false,
+        })
+    }
+
+    /// Reads a whole file into memory and hands it out through `std::io::Read`
+    ///
+    /// Steam can only read part of a file asynchronously, so this isn't a streaming
+    /// reader: the entire file is loaded up front and later changes to it don't affect
+    /// the reader.
+    pub fn buffered_file_reader(&self, name: &str) -> SResult<BufferedFileReader> {
+        Ok(BufferedFileReader {
+            data: Cursor::new(self.file_read(name)?),
+        })
+    }
+}
+
+/// The most `FileWriteStreamWriteChunk` takes at once
+const MAX_CLOUD_FILE_CHUNK: usize = bindings::k_unMaxCloudFileChunkSize as usize;
+
+/// A cloud file being written, see `RemoteStorage::file_write_stream`
+pub struct FileWriteStream {
+    backend: Arc<dyn Backend>,
+    handle: u64,
+    closed: bool,
+}
+
+impl FileWriteStream {
+    /// Stores everything written so far as the file's new contents
+    pub fn close(mut self) -> SResult<()> {
+        self.closed = true;
+        if !self
+            .backend
+            .remote_storage()
+            .file_write_stream_close(self.handle)
+        {
+            return Err(SteamResult::Generic);
+        }
+        Ok(())
+    }
+}
+
+impl Write for FileWriteStream {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        // Steam refuses chunks over its limit, so big buffers go in several
+        let mut written = 0;
+        for chunk in buf.chunks(MAX_CLOUD_FILE_CHUNK) {
+            if !self
+                .backend
+                .remote_storage()
+                .file_write_stream_write_chunk(self.handle, chunk)
+            {
+                if written > 0 {
+                    break;
+                }
+                return Err(io::Error::other("failed to write chunk to steam cloud"));
+            }
+            written += chunk.len();
+        }
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Drop for FileWriteStream {
+    fn drop(&mut self) {
+        if !self.closed {
+            self.backend
+                .remote_storage()
+                .file_write_stream_cancel(self.handle);
+        }
+    }
+}
+
+/// The contents of a cloud file, see `RemoteStorage::buffered_file_reader`
+pub struct BufferedFileReader {
+    data: Cursor<Vec<u8>>,
+}
+
+impl Read for BufferedFileReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.data.read(buf)
+    }
+}
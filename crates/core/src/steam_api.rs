@@ -1,156 +1,453 @@
-use std::{collections::HashMap, ffi::c_void, sync::Mutex};
+use std::{
+    cell::Cell,
+    collections::HashMap,
+    net::Ipv4Addr,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        mpsc::{self, Receiver, Sender},
+        Mutex,
+    },
+    thread::LocalKey,
+};
 
-use crate::callbacks::Callback;
+use crate::backend::{
+    Backend, CallbackMessage, Payload, ServerBackend, SteamAPICall, SteamBackend,
+    SteamServerBackend,
+};
+use crate::callbacks::{Callback, Event};
 
 use super::*;
 
+type CallbackFn = Box<dyn FnMut(&Payload) + Send + 'static>;
+type CallResultFn = Box<dyn FnOnce(&Payload, bool) + Send + 'static>;
+
+struct Listener {
+    id: u64,
+    // Cleared on unregister, so a listener removed mid-dispatch isn't called
+    active: AtomicBool,
+    f: Mutex<CallbackFn>,
+}
+
 struct Callbacks {
-    callbacks: HashMap<i32, Box<dyn FnMut(*mut c_void) + Send + 'static>>,
-    call_results:
-        HashMap<bindings::SteamAPICall_t, Box<dyn FnOnce(*mut c_void, bool) + Send + 'static>>,
+    callbacks: HashMap<i32, Vec<Arc<Listener>>>,
+    call_results: HashMap<SteamAPICall, CallResultFn>,
+    channels: Vec<Sender<Event>>,
+}
+
+impl Callbacks {
+    fn new() -> Self {
+        Self {
+            callbacks: HashMap::new(),
+            call_results: HashMap::new(),
+            channels: Vec::new(),
+        }
+    }
+}
+
+/// Which api a listener is registered with, the client and a game server each have
+/// their own callbacks
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Pipe {
+    Client,
+    Server,
+}
+
+impl Pipe {
+    fn callbacks(self) -> &'static Mutex<Option<Callbacks>> {
+        match self {
+            Pipe::Client => &CLIENT_CALLBACKS,
+            Pipe::Server => &SERVER_CALLBACKS,
+        }
+    }
+
+    /// Taken while pulling callbacks off the pipe, steam's manual dispatch can only
+    /// be pumped by one thread at a time
+    fn pump(self) -> &'static Mutex<()> {
+        match self {
+            Pipe::Client => &CLIENT_PUMP,
+            Pipe::Server => &SERVER_PUMP,
+        }
+    }
+
+    fn dispatching(self) -> &'static LocalKey<Cell<bool>> {
+        match self {
+            Pipe::Client => &CLIENT_DISPATCHING,
+            Pipe::Server => &SERVER_DISPATCHING,
+        }
+    }
+}
+
+static NEXT_LISTENER: AtomicU64 = AtomicU64::new(1);
+
+static CLIENT_PUMP: Mutex<()> = Mutex::new(());
+static SERVER_PUMP: Mutex<()> = Mutex::new(());
+
+thread_local! {
+    static CLIENT_DISPATCHING: Cell<bool> = const { Cell::new(false) };
+    static SERVER_DISPATCHING: Cell<bool> = const { Cell::new(false) };
 }
 
 lazy_static! {
-    static ref USER: Mutex<Option<User>> = Mutex::new(None);
-    static ref APPS: Mutex<Option<Apps>> = Mutex::new(None);
-    static ref FRIENDS: Mutex<Option<Friends>> = Mutex::new(None);
-    static ref UTILS: Mutex<Option<Utils>> = Mutex::new(None);
+    static ref BACKEND: Mutex<Option<Arc<dyn Backend>>> = Mutex::new(None);
     static ref CLIENT_CALLBACKS: Mutex<Option<Callbacks>> = Mutex::new(None);
+    static ref SERVER_BACKEND: Mutex<Option<Arc<dyn ServerBackend>>> = Mutex::new(None);
+    static ref SERVER_CALLBACKS: Mutex<Option<Callbacks>> = Mutex::new(None);
 }
 
+/// Initializes the steamworks API, talking to the running steam client
 pub fn init() -> SResult<()> {
-    unsafe {
-        if !bindings::SteamAPI_Init() {
-            return Err(SteamResult::InitFailed);
-        }
-        bindings::SteamAPI_ManualDispatch_Init();
+    init_with(SteamBackend::new()?)
+}
 
-        let mut user_ref = USER.lock().unwrap();
-        *user_ref = Some(User::new());
+/// Initializes the API on top of the given backend, e.g. a
+/// [`FakeBackend`](crate::backend::FakeBackend) for headless tests
+pub fn init_with<B: Backend>(backend: B) -> SResult<()> {
+    let mut backend_ref = BACKEND.lock().unwrap();
+    *backend_ref = Some(Arc::new(backend));
 
-        let mut apps_ref = APPS.lock().unwrap();
-        *apps_ref = Some(Apps::new());
+    let mut callbacks_ref = CLIENT_CALLBACKS.lock().unwrap();
+    *callbacks_ref = Some(Callbacks::new());
 
-        let mut friends_ref = FRIENDS.lock().unwrap();
-        *friends_ref = Some(Friends::new());
+    Ok(())
+}
 
-        let mut utils_ref = UTILS.lock().unwrap();
-        *utils_ref = Some(Utils::new());
+/// Initializes a dedicated game server, which can run with or without the client api
+///
+/// `query_port` answers server browser queries, pass `u16::MAX` to share the game
+/// port instead.
+pub fn init_server(
+    ip: Ipv4Addr,
+    game_port: u16,
+    query_port: u16,
+    mode: ServerMode,
+    version: &str,
+) -> SResult<Server> {
+    init_server_with(SteamServerBackend::new(
+        ip, game_port, query_port, mode, version,
+    )?)
+}
 
-        let mut callbacks_ref = CLIENT_CALLBACKS.lock().unwrap();
-        *callbacks_ref = Some(Callbacks {
-            callbacks: HashMap::new(),
-            call_results: HashMap::new(),
-        });
+/// Initializes a game server on top of the given backend, e.g. a
+/// [`FakeServer`](crate::backend::FakeServer) for headless tests
+pub fn init_server_with<B: ServerBackend>(backend: B) -> SResult<Server> {
+    let backend: Arc<dyn ServerBackend> = Arc::new(backend);
+    *SERVER_BACKEND.lock().unwrap() = Some(backend.clone());
+    *SERVER_CALLBACKS.lock().unwrap() = Some(Callbacks::new());
 
-        Ok(())
-    }
+    Ok(Server::new(backend))
 }
 
 pub fn shutdown() {
-    unsafe { bindings::SteamAPI_Shutdown() }
+    if let Some(backend) = BACKEND.lock().unwrap().take() {
+        backend.shutdown();
+    }
+    CLIENT_CALLBACKS.lock().unwrap().take();
 }
 
-pub fn friends() -> Friends {
-    let option = FRIENDS.lock().unwrap().to_owned();
+pub fn shutdown_server() {
+    if let Some(backend) = SERVER_BACKEND.lock().unwrap().take() {
+        backend.shutdown();
+    }
+    SERVER_CALLBACKS.lock().unwrap().take();
+}
+
+pub(crate) fn backend() -> Arc<dyn Backend> {
+    let option = BACKEND.lock().unwrap().to_owned();
 
     option.unwrap()
 }
 
-pub fn user() -> User {
-    let option = USER.lock().unwrap().to_owned();
+pub fn friends() -> Friends {
+    Friends::new(backend())
+}
 
-    option.unwrap()
+pub fn user() -> User {
+    User::new(backend())
 }
 
 pub fn apps() -> Apps {
-    let option = APPS.lock().unwrap().to_owned();
-
-    option.unwrap()
+    Apps::new(backend())
 }
 
 pub fn utils() -> Utils {
-    let option = UTILS.lock().unwrap().to_owned();
+    Utils::new(backend())
+}
 
-    option.unwrap()
+pub fn user_stats() -> UserStats {
+    UserStats::new(backend())
 }
 
+pub fn remote_storage() -> RemoteStorage {
+    RemoteStorage::new(backend())
+}
+
+pub fn ugc() -> Ugc {
+    Ugc::new(backend())
+}
+
+pub fn networking_sockets() -> NetworkingSockets {
+    NetworkingSockets::new(backend())
+}
+
+pub fn networking_messages() -> NetworkingMessages {
+    NetworkingMessages::new(backend())
+}
+
+pub fn matchmaking() -> Matchmaking {
+    Matchmaking::new(backend())
+}
+
+/// Dispatches pending callbacks of the client and of the game server, whichever
+/// are initialized
 pub fn run_callbacks() {
     run_client_callbacks();
-    // run_server_callbacks();
+    run_server_callbacks();
+}
+
+struct DispatchGuard(Pipe);
+
+impl Drop for DispatchGuard {
+    fn drop(&mut self) {
+        self.0
+            .dispatching()
+            .with(|dispatching| dispatching.set(false));
+    }
 }
 
 pub(crate) fn run_client_callbacks() {
-    unsafe {
-        let pipe = bindings::SteamAPI_GetHSteamPipe();
-        bindings::SteamAPI_ManualDispatch_RunFrame(pipe);
-        let mut callback = std::mem::zeroed();
-        while bindings::SteamAPI_ManualDispatch_GetNextCallback(pipe, &mut callback) {
-            let mut callbacks_ref = CLIENT_CALLBACKS.lock().unwrap();
-
-            let callbacks = callbacks_ref.as_mut().unwrap();
-            if callback.m_iCallback == bindings::SteamAPICallCompleted_t_k_iCallback as i32 {
-                let apicall =
-                    &mut *(callback.m_pubParam as *mut _ as *mut bindings::SteamAPICallCompleted_t);
-                let mut apicall_result = vec![0; apicall.m_cubParam as usize];
-                let mut failed = false;
-
-                println!("{:?}", callback.m_iCallback as i32);
-
-                if bindings::SteamAPI_ManualDispatch_GetAPICallResult(
-                    pipe,
-                    apicall.m_hAsyncCall,
-                    apicall_result.as_mut_ptr() as *mut _,
-                    apicall.m_cubParam as _,
-                    apicall.m_iCallback,
-                    &mut failed,
-                ) {
-                    // The &{val} pattern here is to avoid taking a reference to a packed field
-                    // Since the value here is Copy, we can just copy it and borrow the copy
-                    if let Some(cb) = callbacks.call_results.remove(&{ apicall.m_hAsyncCall }) {
-                        cb(apicall_result.as_mut_ptr() as *mut _, failed);
-                    }
+    let Some(backend) = BACKEND.lock().unwrap().clone() else {
+        return;
+    };
+    dispatch(
+        Pipe::Client,
+        || backend.run_frame(),
+        || backend.next_callback(),
+    );
+}
+
+pub(crate) fn run_server_callbacks() {
+    let Some(backend) = SERVER_BACKEND.lock().unwrap().clone() else {
+        return;
+    };
+    dispatch(
+        Pipe::Server,
+        || backend.run_frame(),
+        || backend.next_callback(),
+    );
+}
+
+/// Dispatches pending callbacks without holding any lock while user code runs,
+/// so handlers are free to register listeners and make api calls of their own.
+///
+/// Calling this from inside one of the same pipe's handlers does nothing, the outer
+/// dispatch picks up whatever has been queued in the meantime.
+fn dispatch(
+    pipe: Pipe,
+    run_frame: impl FnOnce(),
+    mut next_callback: impl FnMut() -> Option<CallbackMessage>,
+) {
+    if pipe
+        .dispatching()
+        .with(|dispatching| dispatching.replace(true))
+    {
+        return;
+    }
+    let _guard = DispatchGuard(pipe);
+
+    {
+        let _pump = pipe.pump().lock().unwrap_or_else(|e| e.into_inner());
+        run_frame();
+    }
+    loop {
+        // Only held while talking to the pipe, handlers run without it
+        let message = {
+            let _pump = pipe.pump().lock().unwrap_or_else(|e| e.into_inner());
+            next_callback()
+        };
+        let Some(message) = message else {
+            break;
+        };
+
+        match message {
+            CallbackMessage::CallResult {
+                api_call,
+                payload,
+                failed,
+                ..
+            } => {
+                let cb = pipe
+                    .callbacks()
+                    .lock()
+                    .unwrap()
+                    .as_mut()
+                    .and_then(|callbacks| callbacks.call_results.remove(&api_call));
+
+                if let Some(cb) = cb {
+                    cb(&payload, failed);
                 }
-            } else {
-                if let Some(cb) = callbacks.callbacks.get_mut(&callback.m_iCallback) {
-                    cb(callback.m_pubParam as *mut _);
+            }
+            CallbackMessage::Callback { id, payload } => {
+                let listeners = {
+                    let mut callbacks_ref = pipe.callbacks().lock().unwrap();
+
+                    let Some(callbacks) = callbacks_ref.as_mut() else {
+                        break;
+                    };
+
+                    if !callbacks.channels.is_empty() {
+                        if let Some(event) = Event::decode(id, &payload) {
+                            // Receivers that have been dropped are pruned here
+                            callbacks
+                                .channels
+                                .retain(|channel| channel.send(event.clone()).is_ok());
+                        }
+                    }
+
+                    callbacks.callbacks.get(&id).cloned().unwrap_or_default()
+                };
+
+                for listener in listeners {
+                    if listener.active.load(Ordering::Acquire) {
+                        (listener.f.lock().unwrap())(&payload);
+                    }
                 }
             }
-            bindings::SteamAPI_ManualDispatch_FreeLastCallback(pipe);
         }
     }
 }
 
-pub fn register_callback<C, F>(mut f: F)
+/// Keeps a listener added by [`register_callback`] alive
+///
+/// The listener is removed when the handle is dropped or [`unregister`](Self::unregister)ed.
+#[must_use = "the callback is unregistered as soon as its handle is dropped"]
+#[derive(Debug)]
+pub struct CallbackHandle {
+    pipe: Pipe,
+    id: i32,
+    listener: u64,
+}
+
+impl CallbackHandle {
+    pub fn unregister(self) {}
+
+    /// Keeps the listener registered until `shutdown` without holding on to the handle
+    pub fn forget(self) {
+        std::mem::forget(self)
+    }
+}
+
+impl Drop for CallbackHandle {
+    fn drop(&mut self) {
+        let mut callbacks_ref = self.pipe.callbacks().lock().unwrap();
+
+        if let Some(callbacks) = callbacks_ref.as_mut() {
+            if let Some(listeners) = callbacks.callbacks.get_mut(&self.id) {
+                listeners.retain(|listener| {
+                    if listener.id != self.listener {
+                        return true;
+                    }
+                    listener.active.store(false, Ordering::Release);
+                    false
+                });
+            }
+        }
+    }
+}
+
+/// Adds a listener for `C`, alongside any others already listening for it
+pub fn register_callback<C, F>(f: F) -> CallbackHandle
 where
     C: Callback,
     F: FnMut(C) + Send + 'static,
 {
-    unsafe {
-        let mut callbacks_ref = CLIENT_CALLBACKS.lock().unwrap();
-
-        let callbacks = callbacks_ref.as_mut().unwrap();
-        callbacks.callbacks.insert(
-            C::ID,
-            Box::new(move |param| {
-                let param = C::from_raw(param);
-                f(param)
-            }),
-        );
-    }
+    register_listener(Pipe::Client, f)
 }
 
-pub fn register_call_result<C, F>(api_call: bindings::SteamAPICall_t, f: F)
+/// Like [`register_callback`], but listens for callbacks sent to the game server
+pub fn register_server_callback<C, F>(f: F) -> CallbackHandle
 where
-    F: for<'a> FnOnce(&'a C, bool) + 'static + Send,
+    C: Callback,
+    F: FnMut(C) + Send + 'static,
 {
-    unsafe {
-        let mut callbacks_ref = CLIENT_CALLBACKS.lock().unwrap();
-
-        let callbacks = callbacks_ref.as_mut().unwrap();
-        callbacks.call_results.insert(
-            api_call,
-            Box::new(move |param, failed| f(&*(param as *const C), failed)),
-        );
+    register_listener(Pipe::Server, f)
+}
+
+fn register_listener<C, F>(pipe: Pipe, mut f: F) -> CallbackHandle
+where
+    C: Callback,
+    F: FnMut(C) + Send + 'static,
+{
+    let mut callbacks_ref = pipe.callbacks().lock().unwrap();
+
+    let callbacks = callbacks_ref.as_mut().unwrap();
+    let listener = NEXT_LISTENER.fetch_add(1, Ordering::Relaxed);
+    callbacks
+        .callbacks
+        .entry(C::ID)
+        .or_default()
+        .push(Arc::new(Listener {
+            id: listener,
+            active: AtomicBool::new(true),
+            f: Mutex::new(Box::new(move |payload| {
+                if let Some(param) = payload.decode::<C>() {
+                    f(param)
+                }
+            })),
+        }));
+
+    CallbackHandle {
+        pipe,
+        id: C::ID,
+        listener,
     }
 }
+
+/// Returns a receiver that gets every [`Event`] dispatched by `run_callbacks`
+///
+/// Unlike [`register_callback`], nothing runs inside the dispatcher, so events can be
+/// drained whenever suits the game loop.
+pub fn event_channel() -> Receiver<Event> {
+    let (sender, receiver) = mpsc::channel();
+
+    let mut callbacks_ref = CLIENT_CALLBACKS.lock().unwrap();
+
+    let callbacks = callbacks_ref.as_mut().unwrap();
+    callbacks.channels.push(sender);
+
+    receiver
+}
+
+/// Runs `f` once the result of `api_call` is dispatched
+///
+/// `f` always runs, with `None` if the result's payload couldn't be decoded.
+pub fn register_call_result<C, F>(api_call: SteamAPICall, f: F)
+where
+    C: Callback,
+    F: for<'a> FnOnce(Option<&'a C>, bool) + 'static + Send,
+{
+    register_result(Pipe::Client, api_call, f)
+}
+
+/// Like [`register_call_result`], but for calls made through the game server api,
+/// whose results arrive on the game server's pipe
+pub fn register_server_call_result<C, F>(api_call: SteamAPICall, f: F)
+where
+    C: Callback,
+    F: for<'a> FnOnce(Option<&'a C>, bool) + 'static + Send,
+{
+    register_result(Pipe::Server, api_call, f)
+}
+
+fn register_result<C, F>(pipe: Pipe, api_call: SteamAPICall, f: F)
+where
+    C: Callback,
+    F: for<'a> FnOnce(Option<&'a C>, bool) + 'static + Send,
+{
+    let mut callbacks_ref = pipe.callbacks().lock().unwrap();
+
+    let callbacks = callbacks_ref.as_mut().unwrap();
+    callbacks.call_results.insert(
+        api_call,
+        Box::new(move |payload, failed| f(payload.decode::<C>().as_ref(), failed)),
+    );
+}
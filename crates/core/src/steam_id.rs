@@ -0,0 +1,404 @@
+//! Steam ids and the text forms they are shared in.
+//!
+//! A steam id packs, from the high bits down, an 8 bit universe, a 4 bit account type,
+//! a 20 bit instance and the 32 bit account id.
+
+use std::str::FromStr;
+
+use super::*;
+
+const ACCOUNT_ID_MASK: u64 = 0xFFFF_FFFF;
+const INSTANCE_SHIFT: u32 = 32;
+const INSTANCE_MASK: u64 = 0x000F_FFFF;
+const ACCOUNT_TYPE_SHIFT: u32 = 52;
+const ACCOUNT_TYPE_MASK: u64 = 0xF;
+const UNIVERSE_SHIFT: u32 = 56;
+
+/// The instance of individual accounts logged in through the desktop client
+pub const DESKTOP_INSTANCE: u32 = 1;
+// The highest instance an individual account can have (web)
+const MAX_USER_INSTANCE: u32 = 4;
+// Chat instance flags that tell clan chats and lobbies apart from plain chats
+const CHAT_INSTANCE_CLAN: u32 = 0x80000;
+const CHAT_INSTANCE_LOBBY: u32 = 0x40000;
+
+const COMMUNITY_PROFILE_URL: &str = "https://steamcommunity.com/profiles/";
+
+/// A user, game server, group, lobby or other steam account
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SteamId(pub(crate) u64);
+
+/// Which steam instance an account belongs to
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Universe {
+    Invalid,
+    Public,
+    Beta,
+    Internal,
+    Dev,
+}
+
+/// The kind of account a steam id refers to
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AccountType {
+    Invalid,
+    Individual,
+    Multiseat,
+    GameServer,
+    AnonGameServer,
+    Pending,
+    ContentServer,
+    Clan,
+    /// Group chats and lobbies
+    Chat,
+    ConsoleUser,
+    AnonUser,
+}
+
+/// Returned when a string isn't a steam id in any of the supported forms
+#[derive(Clone, Copy, Debug, Error, PartialEq, Eq)]
+#[error("Invalid steam id")]
+pub struct ParseSteamIdError;
+
+impl SteamId {
+    /// Builds an id from its parts, `instance` is truncated to 20 bits
+    pub const fn new(
+        account_id: u32,
+        instance: u32,
+        account_type: AccountType,
+        universe: Universe,
+    ) -> Self {
+        SteamId(
+            account_id as u64
+                | (instance as u64 & INSTANCE_MASK) << INSTANCE_SHIFT
+                | (account_type as u64) << ACCOUNT_TYPE_SHIFT
+                | (universe as u64) << UNIVERSE_SHIFT,
+        )
+    }
+
+    /// The id of a user on the public universe
+    pub const fn from_account_id(account_id: u32) -> Self {
+        Self::from_parts(account_id, Universe::Public, AccountType::Individual)
+    }
+
+    /// Builds an id with the instance steam gives new accounts of `account_type`
+    pub const fn from_parts(
+        account_id: u32,
+        universe: Universe,
+        account_type: AccountType,
+    ) -> Self {
+        let instance = match account_type {
+            AccountType::Individual => DESKTOP_INSTANCE,
+            _ => 0,
+        };
+        Self::new(account_id, instance, account_type, universe)
+    }
+
+    /// The raw 64 bit id
+    pub const fn raw(self) -> u64 {
+        self.0
+    }
+
+    /// The account id, unique among accounts of the same type and universe
+    pub const fn account_id(self) -> u32 {
+        (self.0 & ACCOUNT_ID_MASK) as u32
+    }
+
+    pub const fn instance(self) -> u32 {
+        ((self.0 >> INSTANCE_SHIFT) & INSTANCE_MASK) as u32
+    }
+
+    pub fn account_type(self) -> AccountType {
+        AccountType::from(((self.0 >> ACCOUNT_TYPE_SHIFT) & ACCOUNT_TYPE_MASK) as u8)
+    }
+
+    pub fn universe(self) -> Universe {
+        Universe::from((self.0 >> UNIVERSE_SHIFT) as u8)
+    }
+
+    /// Returns true if the id could belong to an account, following the SDK's `CSteamID::IsValid`
+    pub fn is_valid(self) -> bool {
+        if self.universe() == Universe::Invalid {
+            return false;
+        }
+
+        match self.account_type() {
+            AccountType::Invalid => false,
+            AccountType::Individual => {
+                self.account_id() != 0 && self.instance() <= MAX_USER_INSTANCE
+            }
+            AccountType::Clan => self.account_id() != 0 && self.instance() == 0,
+            AccountType::GameServer => self.account_id() != 0,
+            _ => true,
+        }
+    }
+
+    /// Formats the id the way older games and the console show it, e.g. `STEAM_1:0:1234`
+    ///
+    /// Only meaningful for individual accounts.
+    pub fn steam2(self) -> Steam2Id {
+        Steam2Id(self)
+    }
+
+    /// Formats the id the way the steam client shows it, e.g. `[U:1:2468]`
+    pub fn steam3(self) -> Steam3Id {
+        Steam3Id(self)
+    }
+
+    /// The user's steam community profile
+    pub fn community_url(self) -> String {
+        format!("{}{}", COMMUNITY_PROFILE_URL, self.0)
+    }
+}
+
+impl From<u64> for SteamId {
+    fn from(id: u64) -> Self {
+        SteamId(id)
+    }
+}
+
+impl From<SteamId> for u64 {
+    fn from(id: SteamId) -> Self {
+        id.0
+    }
+}
+
+impl fmt::Display for SteamId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Parses the 64 bit form, Steam2 (`STEAM_0:1:1234`), Steam3 (`[U:1:2468]`) or a
+/// community profile URL
+impl FromStr for SteamId {
+    type Err = ParseSteamIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if let Ok(id) = s.parse::<u64>() {
+            return Ok(SteamId(id));
+        }
+        if let Some(rest) = s.strip_prefix("STEAM_") {
+            return parse_steam2(rest);
+        }
+        if s.contains("steamcommunity.com") {
+            return parse_community_url(s);
+        }
+        parse_steam3(s)
+    }
+}
+
+/// A [`SteamId`] displayed in the Steam2 form, see [`SteamId::steam2`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Steam2Id(pub SteamId);
+
+impl fmt::Display for Steam2Id {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let id = self.0;
+        write!(
+            f,
+            "STEAM_{}:{}:{}",
+            id.universe() as u8,
+            id.account_id() & 1,
+            id.account_id() >> 1
+        )
+    }
+}
+
+/// A [`SteamId`] displayed in the Steam3 form, see [`SteamId::steam3`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Steam3Id(pub SteamId);
+
+impl fmt::Display for Steam3Id {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let id = self.0;
+        let instance = id.instance();
+        let letter = match id.account_type() {
+            AccountType::Chat if instance & CHAT_INSTANCE_CLAN != 0 => 'c',
+            AccountType::Chat if instance & CHAT_INSTANCE_LOBBY != 0 => 'L',
+            account_type => account_type.letter(),
+        };
+
+        write!(f, "[{}:{}:{}", letter, id.universe() as u8, id.account_id())?;
+        let show_instance = match id.account_type() {
+            AccountType::Individual => instance != DESKTOP_INSTANCE,
+            AccountType::AnonGameServer | AccountType::Multiseat => true,
+            _ => false,
+        };
+        if show_instance {
+            write!(f, ":{}", instance)?;
+        }
+        write!(f, "]")
+    }
+}
+
+fn parse_steam2(s: &str) -> Result<SteamId, ParseSteamIdError> {
+    let mut parts = s.split(':');
+    let (Some(universe), Some(low_bit), Some(high_bits), None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return Err(ParseSteamIdError);
+    };
+
+    let universe = match universe.parse::<u8>().map_err(|_| ParseSteamIdError)? {
+        // Older games print public ids as universe 0
+        0 => Universe::Public,
+        universe => Universe::from(universe),
+    };
+    let low_bit = match low_bit {
+        "0" => 0,
+        "1" => 1,
+        _ => return Err(ParseSteamIdError),
+    };
+    let high_bits = high_bits.parse::<u32>().map_err(|_| ParseSteamIdError)?;
+    let account_id = high_bits.checked_mul(2).ok_or(ParseSteamIdError)? | low_bit;
+
+    Ok(SteamId::from_parts(
+        account_id,
+        universe,
+        AccountType::Individual,
+    ))
+}
+
+fn parse_steam3(s: &str) -> Result<SteamId, ParseSteamIdError> {
+    let s = s
+        .strip_prefix('[')
+        .and_then(|s| s.strip_suffix(']'))
+        .unwrap_or(s);
+    let mut parts = s.split(':');
+    let (Some(letter), Some(universe), Some(account_id)) =
+        (parts.next(), parts.next(), parts.next())
+    else {
+        return Err(ParseSteamIdError);
+    };
+    let instance = parts
+        .next()
+        .map(|instance| instance.parse::<u32>().map_err(|_| ParseSteamIdError))
+        .transpose()?;
+    if parts.next().is_some() {
+        return Err(ParseSteamIdError);
+    }
+
+    let mut chars = letter.chars();
+    let (Some(letter), None) = (chars.next(), chars.next()) else {
+        return Err(ParseSteamIdError);
+    };
+    let (account_type, flags) = match letter {
+        'c' => (AccountType::Chat, CHAT_INSTANCE_CLAN),
+        'L' => (AccountType::Chat, CHAT_INSTANCE_LOBBY),
+        letter => (
+            AccountType::from_letter(letter).ok_or(ParseSteamIdError)?,
+            0,
+        ),
+    };
+    let universe = Universe::from(universe.parse::<u8>().map_err(|_| ParseSteamIdError)?);
+    let account_id = account_id.parse::<u32>().map_err(|_| ParseSteamIdError)?;
+    let instance = match (instance, account_type) {
+        (Some(instance), _) => instance,
+        (None, AccountType::Individual) => DESKTOP_INSTANCE,
+        (None, _) => 0,
+    };
+
+    Ok(SteamId::new(
+        account_id,
+        instance | flags,
+        account_type,
+        universe,
+    ))
+}
+
+fn parse_community_url(s: &str) -> Result<SteamId, ParseSteamIdError> {
+    let path = s
+        .strip_prefix("https://")
+        .or_else(|| s.strip_prefix("http://"))
+        .unwrap_or(s);
+    let path = path.strip_prefix("www.").unwrap_or(path);
+    let id = path
+        .strip_prefix("steamcommunity.com/profiles/")
+        .ok_or(ParseSteamIdError)?;
+    let id = id.strip_suffix('/').unwrap_or(id);
+
+    id.parse::<u64>()
+        .map(SteamId)
+        .map_err(|_| ParseSteamIdError)
+}
+
+impl AccountType {
+    fn letter(self) -> char {
+        match self {
+            AccountType::Invalid => 'I',
+            AccountType::Individual => 'U',
+            AccountType::Multiseat => 'M',
+            AccountType::GameServer => 'G',
+            AccountType::AnonGameServer => 'A',
+            AccountType::Pending => 'P',
+            AccountType::ContentServer => 'C',
+            AccountType::Clan => 'g',
+            AccountType::Chat => 'T',
+            AccountType::ConsoleUser => 'I',
+            AccountType::AnonUser => 'a',
+        }
+    }
+
+    fn from_letter(letter: char) -> Option<Self> {
+        Some(match letter {
+            'I' | 'i' => AccountType::Invalid,
+            'U' => AccountType::Individual,
+            'M' => AccountType::Multiseat,
+            'G' => AccountType::GameServer,
+            'A' => AccountType::AnonGameServer,
+            'P' => AccountType::Pending,
+            'C' => AccountType::ContentServer,
+            'g' => AccountType::Clan,
+            'T' => AccountType::Chat,
+            'a' => AccountType::AnonUser,
+            _ => return None,
+        })
+    }
+}
+
+impl From<u8> for AccountType {
+    fn from(account_type: u8) -> Self {
+        match account_type {
+            1 => AccountType::Individual,
+            2 => AccountType::Multiseat,
+            3 => AccountType::GameServer,
+            4 => AccountType::AnonGameServer,
+            5 => AccountType::Pending,
+            6 => AccountType::ContentServer,
+            7 => AccountType::Clan,
+            8 => AccountType::Chat,
+            9 => AccountType::ConsoleUser,
+            10 => AccountType::AnonUser,
+            _ => AccountType::Invalid,
+        }
+    }
+}
+
+impl From<bindings::EUniverse> for Universe {
+    fn from(universe: bindings::EUniverse) -> Self {
+        match universe {
+            bindings::EUniverse::k_EUniversePublic => Universe::Public,
+            bindings::EUniverse::k_EUniverseBeta => Universe::Beta,
+            bindings::EUniverse::k_EUniverseInternal => Universe::Internal,
+            bindings::EUniverse::k_EUniverseDev => Universe::Dev,
+            _ => Universe::Invalid,
+        }
+    }
+}
+
+impl From<u8> for Universe {
+    fn from(universe: u8) -> Self {
+        match universe {
+            1 => Universe::Public,
+            2 => Universe::Beta,
+            3 => Universe::Internal,
+            4 => Universe::Dev,
+            _ => Universe::Invalid,
+        }
+    }
+}
@@ -0,0 +1,768 @@
+use super::*;
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use crate::callbacks::{
+    string_from_chars, CreateItemResult, RemoteStorageSubscribePublishedFileResult,
+    RemoteStorageUnsubscribePublishedFileResult, SteamUGCQueryCompleted, SubmitItemUpdateResult,
+};
+use crate::result::check;
+
+/// The id of a workshop item
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PublishedFileId(pub u64);
+
+impl From<u64> for PublishedFileId {
+    fn from(id: u64) -> Self {
+        PublishedFileId(id)
+    }
+}
+
+/// Which of a user's lists `Ugc::query_user` looks at
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UserList {
+    Published,
+    VotedOn,
+    VotedUp,
+    VotedDown,
+    WillVoteLater,
+    Favorited,
+    Subscribed,
+    UsedOrPlayed,
+    Followed,
+}
+
+/// Kinds of workshop content a query matches
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UgcType {
+    Items,
+    ItemsMtx,
+    ItemsReadyToUse,
+    Collections,
+    Artwork,
+    Videos,
+    Screenshots,
+    AllGuides,
+    WebGuides,
+    IntegratedGuides,
+    UsableInGame,
+    ControllerBindings,
+    GameManagedItems,
+    All,
+}
+
+/// Sort order of a `Ugc::query_user` query
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UserListOrder {
+    CreationOrderDesc,
+    CreationOrderAsc,
+    TitleAsc,
+    LastUpdatedDesc,
+    SubscriptionDateDesc,
+    VoteScoreDesc,
+    ForModeration,
+}
+
+/// Ranking of a `Ugc::query_all` query
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UgcQueryType {
+    RankedByVote,
+    RankedByPublicationDate,
+    AcceptedForGameRankedByAcceptanceDate,
+    RankedByTrend,
+    FavoritedByFriendsRankedByPublicationDate,
+    CreatedByFriendsRankedByPublicationDate,
+    RankedByNumTimesReported,
+    CreatedByFollowedUsersRankedByPublicationDate,
+    NotYetRated,
+    RankedByTotalVotesAsc,
+    RankedByVotesUp,
+    RankedByTextSearch,
+    RankedByTotalUniqueSubscriptions,
+    RankedByPlaytimeTrend,
+    RankedByTotalPlaytime,
+    RankedByAveragePlaytimeTrend,
+    RankedByLifetimeAveragePlaytime,
+    RankedByPlaytimeSessionsTrend,
+    RankedByLifetimePlaytimeSessions,
+    RankedByLastUpdatedDate,
+}
+
+/// The type of a workshop item
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WorkshopFileType {
+    /// A normal item that can be subscribed to
+    Community,
+    Microtransaction,
+    Collection,
+    Art,
+    Video,
+    Screenshot,
+    Game,
+    Software,
+    Concept,
+    WebGuide,
+    IntegratedGuide,
+    Merch,
+    ControllerBinding,
+    SteamworksAccessInvite,
+    SteamVideo,
+    GameManagedItem,
+}
+
+/// Who can see a workshop item
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Visibility {
+    Public,
+    FriendsOnly,
+    Private,
+    Unlisted,
+}
+
+bitflags! {
+    /// The local state of a workshop item
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    pub struct ItemState: u32 {
+        const NONE             = 0x00;
+        const SUBSCRIBED       = 0x01;
+        const LEGACY_ITEM      = 0x02;
+        const INSTALLED        = 0x04;
+        const NEEDS_UPDATE     = 0x08;
+        const DOWNLOADING      = 0x10;
+        const DOWNLOAD_PENDING = 0x20;
+    }
+}
+
+/// What an item update is currently busy with
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UpdateStatus {
+    /// The update hasn't been submitted or has finished
+    Invalid,
+    PreparingConfig,
+    PreparingContent,
+    UploadingContent,
+    UploadingPreviewFile,
+    CommittingChanges,
+}
+
+/// Progress of a submitted item update
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UpdateProgress {
+    pub status: UpdateStatus,
+    pub bytes_processed: u64,
+    pub bytes_total: u64,
+}
+
+/// Details of a workshop item, as returned by a query
+#[derive(Clone, Debug, PartialEq)]
+pub struct PublishedFileDetails {
+    pub published_file_id: PublishedFileId,
+    pub result: SteamResult,
+    pub file_type: WorkshopFileType,
+    pub creator_app_id: AppId,
+    pub consumer_app_id: AppId,
+    pub title: String,
+    pub description: String,
+    pub owner: SteamId,
+    /// Seconds since the unix epoch
+    pub time_created: u32,
+    /// Seconds since the unix epoch
+    pub time_updated: u32,
+    pub visibility: Visibility,
+    pub banned: bool,
+    pub accepted_for_use: bool,
+    pub tags: Vec<String>,
+    pub tags_truncated: bool,
+    pub file_name: String,
+    pub file_size: u32,
+    pub url: String,
+    pub votes_up: u32,
+    pub votes_down: u32,
+    pub score: f32,
+}
+
+/// A page of query results
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueryResults {
+    pub items: Vec<PublishedFileDetails>,
+    /// Results matching the query across all pages
+    pub total_matching: u32,
+    /// Whether the results came from the local cache
+    pub cached: bool,
+}
+
+/// Where a subscribed item has been installed
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InstallInfo {
+    pub folder: PathBuf,
+    pub size_on_disk: u64,
+    /// Seconds since the unix epoch
+    pub timestamp: u32,
+}
+
+/// Steam Workshop items
+#[derive(Clone)]
+pub struct Ugc {
+    pub(crate) backend: Arc<dyn Backend>,
+}
+
+impl Ugc {
+    pub(crate) fn new(backend: Arc<dyn Backend>) -> Self {
+        Self { backend }
+    }
+
+    /// Queries the items on one of a user's lists
+    pub fn query_user(
+        &self,
+        user: SteamId,
+        list: UserList,
+        ugc_type: UgcType,
+        order: UserListOrder,
+    ) -> UgcQuery {
+        self.query(
+            QueryKind::User {
+                account_id: user.account_id(),
+                list,
+                order,
+            },
+            ugc_type,
+        )
+    }
+
+    /// Queries every item of the app
+    pub fn query_all(&self, query_type: UgcQueryType, ugc_type: UgcType) -> UgcQuery {
+        self.query(QueryKind::All { query_type }, ugc_type)
+    }
+
+    fn query(&self, kind: QueryKind, ugc_type: UgcType) -> UgcQuery {
+        UgcQuery {
+            backend: self.backend.clone(),
+            kind,
+            ugc_type,
+            app_id: self.backend.utils().get_app_id(),
+            page: 1,
+            required_tags: Vec::new(),
+            excluded_tags: Vec::new(),
+            match_any_tag: false,
+            search_text: None,
+            long_description: false,
+        }
+    }
+
+    /// Creates a new, empty item owned by the current user
+    ///
+    /// Fill it in with `start_item_update`.
+    pub fn create_item(
+        &self,
+        file_type: WorkshopFileType,
+    ) -> impl Future<Output = SResult<CreateItemResult>> {
+        let app_id = self.backend.utils().get_app_id();
+        CallResultFuture::map(
+            self.backend.ugc().create_item(app_id, file_type),
+            |created: &CreateItemResult| {
+                created.result.into_result()?;
+                Ok(created.clone())
+            },
+        )
+    }
+
+    /// Starts changing an item, nothing is sent until `ItemUpdate::submit`
+    pub fn start_item_update(&self, id: PublishedFileId) -> SResult<ItemUpdate> {
+        let app_id = self.backend.utils().get_app_id();
+        let handle = self.backend.ugc().start_item_update(app_id, id);
+        if handle == bindings::k_UGCUpdateHandleInvalid {
+            return Err(SteamResult::Generic);
+        }
+
+        Ok(ItemUpdate {
+            backend: self.backend.clone(),
+            handle,
+        })
+    }
+
+    pub fn subscribe_item(&self, id: PublishedFileId) -> impl Future<Output = SResult<()>> {
+        CallResultFuture::map(
+            self.backend.ugc().subscribe_item(id),
+            |subscribed: &RemoteStorageSubscribePublishedFileResult| {
+                subscribed.result.into_result()
+            },
+        )
+    }
+
+    pub fn unsubscribe_item(&self, id: PublishedFileId) -> impl Future<Output = SResult<()>> {
+        CallResultFuture::map(
+            self.backend.ugc().unsubscribe_item(id),
+            |unsubscribed: &RemoteStorageUnsubscribePublishedFileResult| {
+                unsubscribed.result.into_result()
+            },
+        )
+    }
+
+    /// Starts downloading or updating an item, which is reported with a
+    /// [`DownloadItemResult`](crate::callbacks::DownloadItemResult) callback
+    pub fn download_item(&self, id: PublishedFileId, high_priority: bool) -> SResult<()> {
+        if !self.backend.ugc().download_item(id, high_priority) {
+            return Err(SteamResult::Generic);
+        }
+        Ok(())
+    }
+
+    pub fn item_state(&self, id: PublishedFileId) -> ItemState {
+        self.backend.ugc().get_item_state(id)
+    }
+
+    /// Where an item is installed, if it is
+    pub fn item_install_info(&self, id: PublishedFileId) -> Option<InstallInfo> {
+        self.backend.ugc().get_item_install_info(id)
+    }
+
+    /// Downloaded and total bytes of an item that is being downloaded
+    pub fn item_download_info(&self, id: PublishedFileId) -> Option<(u64, u64)> {
+        self.backend.ugc().get_item_download_info(id)
+    }
+
+    pub fn subscribed_items(&self) -> Vec<PublishedFileId> {
+        self.backend.ugc().get_subscribed_items()
+    }
+}
+
+enum QueryKind {
+    User {
+        account_id: u32,
+        list: UserList,
+        order: UserListOrder,
+    },
+    All {
+        query_type: UgcQueryType,
+    },
+}
+
+/// A workshop query, created by `Ugc::query_user` or `Ugc::query_all`
+#[must_use = "queries do nothing until sent"]
+pub struct UgcQuery {
+    backend: Arc<dyn Backend>,
+    kind: QueryKind,
+    ugc_type: UgcType,
+    app_id: AppId,
+    page: u32,
+    required_tags: Vec<String>,
+    excluded_tags: Vec<String>,
+    match_any_tag: bool,
+    search_text: Option<String>,
+    long_description: bool,
+}
+
+impl UgcQuery {
+    /// The page of results to return, starting at 1
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = page;
+        self
+    }
+
+    /// Only match items with this tag
+    pub fn require_tag(mut self, tag: impl Into<String>) -> Self {
+        self.required_tags.push(tag.into());
+        self
+    }
+
+    /// Skip items with this tag
+    pub fn exclude_tag(mut self, tag: impl Into<String>) -> Self {
+        self.excluded_tags.push(tag.into());
+        self
+    }
+
+    /// Match items with any of the required tags rather than all of them
+    pub fn match_any_tag(mut self, match_any: bool) -> Self {
+        self.match_any_tag = match_any;
+        self
+    }
+
+    /// Only match items whose title or description contain the text
+    pub fn search_text(mut self, text: impl Into<String>) -> Self {
+        self.search_text = Some(text.into());
+        self
+    }
+
+    /// Return the full description rather than a truncated one
+    pub fn long_description(mut self, long_description: bool) -> Self {
+        self.long_description = long_description;
+        self
+    }
+
+    pub fn send(self) -> impl Future<Output = SResult<QueryResults>> {
+        let ugc = self.backend.ugc();
+        let handle = match self.kind {
+            QueryKind::User {
+                account_id,
+                list,
+                order,
+            } => ugc.create_query_user_ugc_request(
+                account_id,
+                list,
+                self.ugc_type,
+                order,
+                self.app_id,
+                self.page,
+            ),
+            QueryKind::All { query_type } => {
+                ugc.create_query_all_ugc_request(query_type, self.ugc_type, self.app_id, self.page)
+            }
+        };
+
+        let query = (handle != bindings::k_UGCQueryHandleInvalid).then(|| QueryHandle {
+            backend: self.backend.clone(),
+            handle,
+        });
+        let api_call = match &query {
+            Some(query) if self.apply(query.handle) => ugc.send_query_ugc_request(query.handle),
+            _ => bindings::k_uAPICallInvalid,
+        };
+
+        // Results are read while the call result is dispatched, before the handle goes
+        let backend = self.backend.clone();
+        let results = CallResultFuture::map(api_call, move |completed: &SteamUGCQueryCompleted| {
+            let ugc = backend.ugc();
+            let items = (0..completed.results_returned)
+                .filter_map(|index| ugc.get_query_ugc_result(completed.handle, index))
+                .collect();
+
+            completed.result.into_result()?;
+            Ok(QueryResults {
+                items,
+                total_matching: completed.total_matching_results,
+                cached: completed.cached_data,
+            })
+        });
+
+        async move {
+            let _query = query;
+            results.await
+        }
+    }
+
+    fn apply(&self, handle: u64) -> bool {
+        let ugc = self.backend.ugc();
+
+        self.required_tags
+            .iter()
+            .all(|tag| ugc.add_required_tag(handle, tag))
+            && self
+                .excluded_tags
+                .iter()
+                .all(|tag| ugc.add_excluded_tag(handle, tag))
+            && ugc.set_match_any_tag(handle, self.match_any_tag)
+            && self
+                .search_text
+                .as_ref()
+                .is_none_or(|text| ugc.set_search_text(handle, text))
+            && ugc.set_return_long_description(handle, self.long_description)
+    }
+}
+
+/// Pending changes to a workshop item, see `Ugc::start_item_update`
+pub struct ItemUpdate {
+    backend: Arc<dyn Backend>,
+    handle: u64,
+}
+
+impl ItemUpdate {
+    pub fn set_title(&self, title: &str) -> SResult<()> {
+        check(self.backend.ugc().set_item_title(self.handle, title))
+    }
+
+    pub fn set_description(&self, description: &str) -> SResult<()> {
+        check(
+            self.backend
+                .ugc()
+                .set_item_description(self.handle, description),
+        )
+    }
+
+    /// Uploads the contents of a folder as the item's content
+    pub fn set_content(&self, folder: &Path) -> SResult<()> {
+        check(self.backend.ugc().set_item_content(self.handle, folder))
+    }
+
+    pub fn set_preview(&self, file: &Path) -> SResult<()> {
+        check(self.backend.ugc().set_item_preview(self.handle, file))
+    }
+
+    pub fn set_tags(&self, tags: &[&str]) -> SResult<()> {
+        check(self.backend.ugc().set_item_tags(self.handle, tags))
+    }
+
+    pub fn set_visibility(&self, visibility: Visibility) -> SResult<()> {
+        check(
+            self.backend
+                .ugc()
+                .set_item_visibility(self.handle, visibility),
+        )
+    }
+
+    /// Uploads the changes, resolving once they have been committed
+    pub fn submit(self, change_note: Option<&str>) -> ItemUpdateSubmission {
+        let api_call = self
+            .backend
+            .ugc()
+            .submit_item_update(self.handle, change_note);
+        ItemUpdateSubmission {
+            backend: self.backend,
+            handle: self.handle,
+            result: CallResultFuture::map(api_call, |submitted: &SubmitItemUpdateResult| {
+                submitted.result.into_result()?;
+                Ok(submitted.clone())
+            }),
+        }
+    }
+}
+
+/// A submitted item update, resolving once it has finished uploading
+#[must_use = "futures do nothing unless polled"]
+pub struct ItemUpdateSubmission {
+    backend: Arc<dyn Backend>,
+    handle: u64,
+    result: CallResultFuture<SubmitItemUpdateResult>,
+}
+
+impl ItemUpdateSubmission {
+    /// How far along the upload is, meant to be polled e.g. once per frame
+    pub fn progress(&self) -> UpdateProgress {
+        self.backend.ugc().get_item_update_progress(self.handle)
+    }
+}
+
+impl Future for ItemUpdateSubmission {
+    type Output = SResult<SubmitItemUpdateResult>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.result).poll(cx)
+    }
+}
+
+/// A query handle, released when dropped so failed and abandoned queries don't leak
+struct QueryHandle {
+    backend: Arc<dyn Backend>,
+    handle: u64,
+}
+
+impl Drop for QueryHandle {
+    fn drop(&mut self) {
+        self.backend.ugc().release_query_ugc_request(self.handle);
+    }
+}
+
+impl From<&bindings::SteamUGCDetails_t> for PublishedFileDetails {
+    fn from(details: &bindings::SteamUGCDetails_t) -> Self {
+        let tags = string_from_chars(&details.m_rgchTags);
+        Self {
+            published_file_id: PublishedFileId(details.m_nPublishedFileId),
+            result: details.m_eResult.into(),
+            file_type: details.m_eFileType.into(),
+            creator_app_id: AppId(details.m_nCreatorAppID),
+            consumer_app_id: AppId(details.m_nConsumerAppID),
+            title: string_from_chars(&details.m_rgchTitle),
+            description: string_from_chars(&details.m_rgchDescription),
+            owner: SteamId(details.m_ulSteamIDOwner),
+            time_created: details.m_rtimeCreated,
+            time_updated: details.m_rtimeUpdated,
+            visibility: details.m_eVisibility.into(),
+            banned: details.m_bBanned,
+            accepted_for_use: details.m_bAcceptedForUse,
+            tags: tags
+                .split(',')
+                .filter(|tag| !tag.is_empty())
+                .map(String::from)
+                .collect(),
+            tags_truncated: details.m_bTagsTruncated,
+            file_name: string_from_chars(&details.m_pchFileName),
+            file_size: details.m_nFileSize.max(0) as u32,
+            url: string_from_chars(&details.m_rgchURL),
+            votes_up: details.m_unVotesUp,
+            votes_down: details.m_unVotesDown,
+            score: details.m_flScore,
+        }
+    }
+}
+
+impl From<UserList> for bindings::EUserUGCList {
+    fn from(list: UserList) -> Self {
+        match list {
+            UserList::Published => Self::k_EUserUGCList_Published,
+            UserList::VotedOn => Self::k_EUserUGCList_VotedOn,
+            UserList::VotedUp => Self::k_EUserUGCList_VotedUp,
+            UserList::VotedDown => Self::k_EUserUGCList_VotedDown,
+            UserList::WillVoteLater => Self::k_EUserUGCList_WillVoteLater,
+            UserList::Favorited => Self::k_EUserUGCList_Favorited,
+            UserList::Subscribed => Self::k_EUserUGCList_Subscribed,
+            UserList::UsedOrPlayed => Self::k_EUserUGCList_UsedOrPlayed,
+            UserList::Followed => Self::k_EUserUGCList_Followed,
+        }
+    }
+}
+
+impl From<UgcType> for bindings::EUGCMatchingUGCType {
+    fn from(ugc_type: UgcType) -> Self {
+        match ugc_type {
+            UgcType::Items => Self::k_EUGCMatchingUGCType_Items,
+            UgcType::ItemsMtx => Self::k_EUGCMatchingUGCType_Items_Mtx,
+            UgcType::ItemsReadyToUse => Self::k_EUGCMatchingUGCType_Items_ReadyToUse,
+            UgcType::Collections => Self::k_EUGCMatchingUGCType_Collections,
+            UgcType::Artwork => Self::k_EUGCMatchingUGCType_Artwork,
+            UgcType::Videos => Self::k_EUGCMatchingUGCType_Videos,
+            UgcType::Screenshots => Self::k_EUGCMatchingUGCType_Screenshots,
+            UgcType::AllGuides => Self::k_EUGCMatchingUGCType_AllGuides,
+            UgcType::WebGuides => Self::k_EUGCMatchingUGCType_WebGuides,
+            UgcType::IntegratedGuides => Self::k_EUGCMatchingUGCType_IntegratedGuides,
+            UgcType::UsableInGame => Self::k_EUGCMatchingUGCType_UsableInGame,
+            UgcType::ControllerBindings => Self::k_EUGCMatchingUGCType_ControllerBindings,
+            UgcType::GameManagedItems => Self::k_EUGCMatchingUGCType_GameManagedItems,
+            UgcType::All => Self::k_EUGCMatchingUGCType_All,
+        }
+    }
+}
+
+impl From<UserListOrder> for bindings::EUserUGCListSortOrder {
+    fn from(order: UserListOrder) -> Self {
+        match order {
+            UserListOrder::CreationOrderDesc => Self::k_EUserUGCListSortOrder_CreationOrderDesc,
+            UserListOrder::CreationOrderAsc => Self::k_EUserUGCListSortOrder_CreationOrderAsc,
+            UserListOrder::TitleAsc => Self::k_EUserUGCListSortOrder_TitleAsc,
+            UserListOrder::LastUpdatedDesc => Self::k_EUserUGCListSortOrder_LastUpdatedDesc,
+            UserListOrder::SubscriptionDateDesc => {
+                Self::k_EUserUGCListSortOrder_SubscriptionDateDesc
+            }
+            UserListOrder::VoteScoreDesc => Self::k_EUserUGCListSortOrder_VoteScoreDesc,
+            UserListOrder::ForModeration => Self::k_EUserUGCListSortOrder_ForModeration,
+        }
+    }
+}
+
+impl From<UgcQueryType> for bindings::EUGCQuery {
+    fn from(query_type: UgcQueryType) -> Self {
+        use UgcQueryType::*;
+
+        match query_type {
+            RankedByVote => Self::k_EUGCQuery_RankedByVote,
+            RankedByPublicationDate => Self::k_EUGCQuery_RankedByPublicationDate,
+            AcceptedForGameRankedByAcceptanceDate => {
+                Self::k_EUGCQuery_AcceptedForGameRankedByAcceptanceDate
+            }
+            RankedByTrend => Self::k_EUGCQuery_RankedByTrend,
+            FavoritedByFriendsRankedByPublicationDate => {
+                Self::k_EUGCQuery_FavoritedByFriendsRankedByPublicationDate
+            }
+            CreatedByFriendsRankedByPublicationDate => {
+                Self::k_EUGCQuery_CreatedByFriendsRankedByPublicationDate
+            }
+            RankedByNumTimesReported => Self::k_EUGCQuery_RankedByNumTimesReported,
+            CreatedByFollowedUsersRankedByPublicationDate => {
+                Self::k_EUGCQuery_CreatedByFollowedUsersRankedByPublicationDate
+            }
+            NotYetRated => Self::k_EUGCQuery_NotYetRated,
+            RankedByTotalVotesAsc => Self::k_EUGCQuery_RankedByTotalVotesAsc,
+            RankedByVotesUp => Self::k_EUGCQuery_RankedByVotesUp,
+            RankedByTextSearch => Self::k_EUGCQuery_RankedByTextSearch,
+            RankedByTotalUniqueSubscriptions => Self::k_EUGCQuery_RankedByTotalUniqueSubscriptions,
+            RankedByPlaytimeTrend => Self::k_EUGCQuery_RankedByPlaytimeTrend,
+            RankedByTotalPlaytime => Self::k_EUGCQuery_RankedByTotalPlaytime,
+            RankedByAveragePlaytimeTrend => Self::k_EUGCQuery_RankedByAveragePlaytimeTrend,
+            RankedByLifetimeAveragePlaytime => Self::k_EUGCQuery_RankedByLifetimeAveragePlaytime,
+            RankedByPlaytimeSessionsTrend => Self::k_EUGCQuery_RankedByPlaytimeSessionsTrend,
+            RankedByLifetimePlaytimeSessions => Self::k_EUGCQuery_RankedByLifetimePlaytimeSessions,
+            RankedByLastUpdatedDate => Self::k_EUGCQuery_RankedByLastUpdatedDate,
+        }
+    }
+}
+
+impl From<WorkshopFileType> for bindings::EWorkshopFileType {
+    fn from(file_type: WorkshopFileType) -> Self {
+        use WorkshopFileType::*;
+
+        match file_type {
+            Community => Self::k_EWorkshopFileTypeFirst,
+            Microtransaction => Self::k_EWorkshopFileTypeMicrotransaction,
+            Collection => Self::k_EWorkshopFileTypeCollection,
+            Art => Self::k_EWorkshopFileTypeArt,
+            Video => Self::k_EWorkshopFileTypeVideo,
+            Screenshot => Self::k_EWorkshopFileTypeScreenshot,
+            Game => Self::k_EWorkshopFileTypeGame,
+            Software => Self::k_EWorkshopFileTypeSoftware,
+            Concept => Self::k_EWorkshopFileTypeConcept,
+            WebGuide => Self::k_EWorkshopFileTypeWebGuide,
+            IntegratedGuide => Self::k_EWorkshopFileTypeIntegratedGuide,
+            Merch => Self::k_EWorkshopFileTypeMerch,
+            ControllerBinding => Self::k_EWorkshopFileTypeControllerBinding,
+            SteamworksAccessInvite => Self::k_EWorkshopFileTypeSteamworksAccessInvite,
+            SteamVideo => Self::k_EWorkshopFileTypeSteamVideo,
+            GameManagedItem => Self::k_EWorkshopFileTypeGameManagedItem,
+        }
+    }
+}
+
+impl From<bindings::EWorkshopFileType> for WorkshopFileType {
+    fn from(file_type: bindings::EWorkshopFileType) -> Self {
+        use bindings::EWorkshopFileType::*;
+
+        match file_type {
+            k_EWorkshopFileTypeMicrotransaction => Self::Microtransaction,
+            k_EWorkshopFileTypeCollection => Self::Collection,
+            k_EWorkshopFileTypeArt => Self::Art,
+            k_EWorkshopFileTypeVideo => Self::Video,
+            k_EWorkshopFileTypeScreenshot => Self::Screenshot,
+            k_EWorkshopFileTypeGame => Self::Game,
+            k_EWorkshopFileTypeSoftware => Self::Software,
+            k_EWorkshopFileTypeConcept => Self::Concept,
+            k_EWorkshopFileTypeWebGuide => Self::WebGuide,
+            k_EWorkshopFileTypeIntegratedGuide => Self::IntegratedGuide,
+            k_EWorkshopFileTypeMerch => Self::Merch,
+            k_EWorkshopFileTypeControllerBinding => Self::ControllerBinding,
+            k_EWorkshopFileTypeSteamworksAccessInvite => Self::SteamworksAccessInvite,
+            k_EWorkshopFileTypeSteamVideo => Self::SteamVideo,
+            k_EWorkshopFileTypeGameManagedItem => Self::GameManagedItem,
+            _ => Self::Community,
+        }
+    }
+}
+
+impl From<Visibility> for bindings::ERemoteStoragePublishedFileVisibility {
+    fn from(visibility: Visibility) -> Self {
+        match visibility {
+            Visibility::Public => Self::k_ERemoteStoragePublishedFileVisibilityPublic,
+            Visibility::FriendsOnly => Self::k_ERemoteStoragePublishedFileVisibilityFriendsOnly,
+            Visibility::Private => Self::k_ERemoteStoragePublishedFileVisibilityPrivate,
+            Visibility::Unlisted => Self::k_ERemoteStoragePublishedFileVisibilityUnlisted,
+        }
+    }
+}
+
+impl From<bindings::ERemoteStoragePublishedFileVisibility> for Visibility {
+    fn from(visibility: bindings::ERemoteStoragePublishedFileVisibility) -> Self {
+        use bindings::ERemoteStoragePublishedFileVisibility::*;
+
+        match visibility {
+            k_ERemoteStoragePublishedFileVisibilityFriendsOnly => Self::FriendsOnly,
+            k_ERemoteStoragePublishedFileVisibilityPrivate => Self::Private,
+            k_ERemoteStoragePublishedFileVisibilityUnlisted => Self::Unlisted,
+            _ => Self::Public,
+        }
+    }
+}
+
+impl From<bindings::EItemUpdateStatus> for UpdateStatus {
+    fn from(status: bindings::EItemUpdateStatus) -> Self {
+        use bindings::EItemUpdateStatus::*;
+
+        match status {
+            k_EItemUpdateStatusPreparingConfig => Self::PreparingConfig,
+            k_EItemUpdateStatusPreparingContent => Self::PreparingContent,
+            k_EItemUpdateStatusUploadingContent => Self::UploadingContent,
+            k_EItemUpdateStatusUploadingPreviewFile => Self::UploadingPreviewFile,
+            k_EItemUpdateStatusCommittingChanges => Self::CommittingChanges,
+            _ => Self::Invalid,
+        }
+    }
+}
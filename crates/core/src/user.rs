@@ -1,59 +1,266 @@
 use super::*;
+#[cfg(feature = "web-api-ticket")]
+use std::future::poll_fn;
+use std::future::Future;
+#[cfg(feature = "web-api-ticket")]
+use std::sync::Mutex;
+#[cfg(feature = "web-api-ticket")]
+use std::task::{Poll, Waker};
+
+use crate::callbacks::EncryptedAppTicketResponse;
+#[cfg(feature = "web-api-ticket")]
+use crate::callbacks::GetTicketForWebApiResponse;
+
+// Steam's own samples use this size for session tickets
+const AUTH_TICKET_BUFFER: usize = 1024;
+// Large enough for most encrypted app tickets, bigger ones are retried
+const ENCRYPTED_APP_TICKET_BUFFER: usize = 1024;
+
+/// Identifies a session ticket, as reported by `GetAuthSessionTicketResponse`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AuthTicketHandle(pub(crate) u32);
+
+/// Why an auth session was refused or ended, see `ValidateAuthTicketResponse`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AuthSessionResponse {
+    Ok,
+    UserNotConnectedToSteam,
+    NoLicenseOrExpired,
+    VacBanned,
+    LoggedInElsewhere,
+    VacCheckTimedOut,
+    AuthTicketCanceled,
+    AuthTicketInvalidAlreadyUsed,
+    AuthTicketInvalid,
+    PublisherIssuedBan,
+    AuthTicketNetworkIdentityFailure,
+}
 
 #[derive(Clone)]
 pub struct User {
-    pub(crate) user: *mut bindings::ISteamUser,
+    pub(crate) backend: Arc<dyn Backend>,
 }
 
-unsafe impl Send for User {}
-unsafe impl Sync for User {}
-
 impl User {
-    pub(crate) fn new() -> Self {
-        unsafe {
-            Self {
-                user: bindings::SteamAPI_SteamUser_v022(),
-            }
-        }
+    pub(crate) fn new(backend: Arc<dyn Backend>) -> Self {
+        Self { backend }
     }
 
     /// Get the steam id of the current logged in user
     pub fn get_steam_id(&self) -> SteamId {
-        unsafe { SteamId(bindings::SteamAPI_ISteamUser_GetSteamID(self.user)) }
+        self.backend.user().get_steam_id()
     }
 
-    pub fn request_encrypted_app_ticket(&self) -> u64 {
-        unsafe {
-            let api_call = bindings::SteamAPI_ISteamUser_RequestEncryptedAppTicket(
-                self.user,
-                std::ptr::null_mut(),
-                0,
-            );
+    /// Request a fresh encrypted app ticket, resolving once it can be
+    /// retrieved with `get_encrypted_app_ticket()`
+    ///
+    /// `data` is included in the ticket, e.g. to tie it to a login on our own backend.
+    pub fn request_encrypted_app_ticket(&self, data: &[u8]) -> impl Future<Output = SResult<()>> {
+        let call = CallResultFuture::<EncryptedAppTicketResponse>::new(
+            self.backend.user().request_encrypted_app_ticket(data),
+        );
 
-            api_call
-        }
+        async move { SteamResult::from(call.await?.result).into_result() }
+    }
+
+    /// Requests a fresh encrypted app ticket and resolves to its bytes
+    pub fn request_encrypted_app_ticket_async(
+        &self,
+        data: &[u8],
+    ) -> impl Future<Output = SResult<Vec<u8>>> {
+        let backend = self.backend.clone();
+        CallResultFuture::map(
+            self.backend.user().request_encrypted_app_ticket(data),
+            move |response: &EncryptedAppTicketResponse| {
+                SteamResult::from(response.result).into_result()?;
+                encrypted_app_ticket(&*backend)
+            },
+        )
     }
 
     /// Retrieve a encrypted app ticket
     /// If called without calling `request_encryped_app_ticket()`, will likely result in
     /// stale ticket.
-    pub fn get_encrypted_app_ticket(&self) -> Result<Vec<i32>, &str> {
-        unsafe {
-            let mut ticket_buffer = vec![0; 1024];
-            let mut ticket_len = 0;
-
-            if !bindings::SteamAPI_ISteamUser_GetEncryptedAppTicket(
-                self.user,
-                ticket_buffer.as_mut_ptr() as *mut _,
-                1024,
-                &mut ticket_len,
-            ) {
-                return Err("Failed to get encrypted app ticket");
-            };
-
-            ticket_buffer.truncate(ticket_len as usize);
-
-            Ok(ticket_buffer)
+    pub fn get_encrypted_app_ticket(&self) -> SResult<Vec<u8>> {
+        encrypted_app_ticket(&*self.backend)
+    }
+
+    /// Creates a ticket proving who we are, to send to a game server or another player
+    ///
+    /// The ticket can be sent once `GetAuthSessionTicketResponse` reports it as ready,
+    /// and is cancelled when dropped.
+    pub fn get_auth_session_ticket(&self) -> SResult<AuthTicket> {
+        let mut data = vec![0; AUTH_TICKET_BUFFER];
+        let (handle, len) = self
+            .backend
+            .user()
+            .get_auth_session_ticket(&mut data)
+            .ok_or(SteamResult::Generic)?;
+        data.truncate(len as usize);
+
+        Ok(AuthTicket {
+            backend: self.backend.clone(),
+            handle,
+            data,
+        })
+    }
+
+    /// Creates a ticket for a web service to check with `ISteamUserAuth/AuthenticateUserTicket`
+    ///
+    /// `identity` names the service the ticket is meant for. Resolves once
+    /// `GetTicketForWebApiResponse` delivers the ticket, which is cancelled when dropped,
+    /// or when the future is dropped before then.
+    ///
+    /// Needs the `web-api-ticket` feature and Steamworks SDK 1.57 or newer, the bundled
+    /// SDK predates these tickets.
+    #[cfg(feature = "web-api-ticket")]
+    pub fn get_auth_ticket_for_web_api(
+        &self,
+        identity: &str,
+    ) -> impl Future<Output = SResult<AuthTicket>> {
+        // Listen before asking, so the response can't be dispatched unnoticed
+        let wait = Arc::new(Mutex::new(WebApiTicketWait::default()));
+        let handle = {
+            let wait = wait.clone();
+            steam_api::register_callback(move |response: GetTicketForWebApiResponse| {
+                let waker = {
+                    let mut wait = wait.lock().unwrap();
+                    wait.responses.push(response);
+                    wait.waker.take()
+                };
+                if let Some(waker) = waker {
+                    waker.wake();
+                }
+            })
+        };
+
+        let ticket = self
+            .backend
+            .user()
+            .get_auth_ticket_for_web_api(identity)
+            .map(|handle| AuthTicket {
+                backend: self.backend.clone(),
+                handle,
+                data: Vec::new(),
+            });
+
+        async move {
+            let _handle = handle;
+            let mut ticket = ticket.ok_or(SteamResult::Generic)?;
+
+            let response = poll_fn(|cx| {
+                let mut wait = wait.lock().unwrap();
+                match wait
+                    .responses
+                    .iter()
+                    .position(|response| response.ticket == ticket.handle())
+                {
+                    Some(i) => Poll::Ready(wait.responses.swap_remove(i)),
+                    None => {
+                        wait.waker = Some(cx.waker().clone());
+                        Poll::Pending
+                    }
+                }
+            })
+            .await;
+
+            response.result.into_result()?;
+            ticket.data = response.data;
+            Ok(ticket)
+        }
+    }
+
+    /// Starts validating a ticket another player sent us
+    ///
+    /// The result arrives as a `ValidateAuthTicketResponse`, and the session stays
+    /// open until `end_auth_session`. A ticket refused straight away fails with
+    /// `InvalidPassword` when it's malformed, `DuplicateRequest`, `InvalidProtocolVersion`,
+    /// `NoMatch` when it's for another game, or `Expired`.
+    pub fn begin_auth_session(&self, ticket: &[u8], steam_id: SteamId) -> SResult<()> {
+        self.backend
+            .user()
+            .begin_auth_session(ticket, steam_id)
+            .into_result()
+    }
+
+    /// Ends a session started by `begin_auth_session`
+    pub fn end_auth_session(&self, steam_id: SteamId) {
+        self.backend.user().end_auth_session(steam_id)
+    }
+}
+
+#[cfg(feature = "web-api-ticket")]
+#[derive(Default)]
+struct WebApiTicketWait {
+    responses: Vec<GetTicketForWebApiResponse>,
+    waker: Option<Waker>,
+}
+
+fn encrypted_app_ticket(backend: &dyn Backend) -> SResult<Vec<u8>> {
+    let mut ticket = vec![0; ENCRYPTED_APP_TICKET_BUFFER];
+    loop {
+        let (ok, len) = backend.user().get_encrypted_app_ticket(&mut ticket);
+        if ok {
+            ticket.truncate(len as usize);
+            return Ok(ticket);
+        }
+
+        // Steam reports how much room the ticket needs when the buffer is too small
+        if len as usize <= ticket.len() {
+            return Err(SteamResult::Generic);
+        }
+        ticket.resize(len as usize, 0);
+    }
+}
+
+/// A ticket from `User::get_auth_session_ticket` or `User::get_auth_ticket_for_web_api`,
+/// cancelled when dropped
+///
+/// Cancelling ends every auth session that was started with the ticket.
+pub struct AuthTicket {
+    backend: Arc<dyn Backend>,
+    handle: u32,
+    data: Vec<u8>,
+}
+
+impl AuthTicket {
+    pub fn handle(&self) -> AuthTicketHandle {
+        AuthTicketHandle(self.handle)
+    }
+
+    /// The bytes to send to whoever is authenticating us
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+}
+
+impl Drop for AuthTicket {
+    fn drop(&mut self) {
+        self.backend.user().cancel_auth_ticket(self.handle);
+    }
+}
+
+impl From<bindings::EAuthSessionResponse> for AuthSessionResponse {
+    fn from(response: bindings::EAuthSessionResponse) -> Self {
+        use bindings::EAuthSessionResponse::*;
+        match response {
+            k_EAuthSessionResponseOK => AuthSessionResponse::Ok,
+            k_EAuthSessionResponseUserNotConnectedToSteam => {
+                AuthSessionResponse::UserNotConnectedToSteam
+            }
+            k_EAuthSessionResponseNoLicenseOrExpired => AuthSessionResponse::NoLicenseOrExpired,
+            k_EAuthSessionResponseVACBanned => AuthSessionResponse::VacBanned,
+            k_EAuthSessionResponseLoggedInElseWhere => AuthSessionResponse::LoggedInElsewhere,
+            k_EAuthSessionResponseVACCheckTimedOut => AuthSessionResponse::VacCheckTimedOut,
+            k_EAuthSessionResponseAuthTicketCanceled => AuthSessionResponse::AuthTicketCanceled,
+            k_EAuthSessionResponseAuthTicketInvalidAlreadyUsed => {
+                AuthSessionResponse::AuthTicketInvalidAlreadyUsed
+            }
+            k_EAuthSessionResponsePublisherIssuedBan => AuthSessionResponse::PublisherIssuedBan,
+            k_EAuthSessionResponseAuthTicketNetworkIdentityFailure => {
+                AuthSessionResponse::AuthTicketNetworkIdentityFailure
+            }
+            _ => AuthSessionResponse::AuthTicketInvalid,
         }
     }
 }
@@ -0,0 +1,249 @@
+use super::*;
+use std::future::Future;
+
+use crate::callbacks::{
+    LeaderboardFindResult, LeaderboardScoreUploaded, LeaderboardScoresDownloaded,
+};
+use crate::result::check;
+
+/// Most game specific values a leaderboard entry can hold
+pub const MAX_LEADERBOARD_DETAILS: usize = bindings::k_cLeaderboardDetailsMax as usize;
+
+/// Handle to a leaderboard, as returned by `UserStats::find_or_create_leaderboard`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Leaderboard(pub(crate) u64);
+
+/// How entries of a leaderboard are ranked
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LeaderboardSortMethod {
+    /// The lowest score is ranked first, e.g. for the fastest time
+    Ascending,
+    /// The highest score is ranked first
+    Descending,
+}
+
+/// How the steam client shows the scores of a leaderboard
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LeaderboardDisplayType {
+    Numeric,
+    TimeSeconds,
+    TimeMilliSeconds,
+}
+
+/// What happens when a user that already has an entry uploads a new score
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UploadScoreMethod {
+    /// Only replace the entry if the new score ranks better
+    KeepBest,
+    /// Always replace the entry
+    ForceUpdate,
+}
+
+/// Which entries of a leaderboard to download
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LeaderboardRange {
+    /// Entries ranked `start` to `end` inclusive, starting at 1
+    Global { start: i32, end: i32 },
+    /// Entries surrounding the current user, who is always included
+    AroundUser { before: u32, after: u32 },
+    /// Entries of the current user and their friends
+    Friends,
+}
+
+/// A single downloaded leaderboard entry
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LeaderboardEntry {
+    pub steam_id: SteamId,
+    pub global_rank: i32,
+    pub score: i32,
+    /// Game specific data uploaded along with the score
+    pub details: Vec<i32>,
+}
+
+impl From<LeaderboardSortMethod> for bindings::ELeaderboardSortMethod {
+    fn from(method: LeaderboardSortMethod) -> Self {
+        match method {
+            LeaderboardSortMethod::Ascending => Self::k_ELeaderboardSortMethodAscending,
+            LeaderboardSortMethod::Descending => Self::k_ELeaderboardSortMethodDescending,
+        }
+    }
+}
+
+impl From<LeaderboardDisplayType> for bindings::ELeaderboardDisplayType {
+    fn from(display: LeaderboardDisplayType) -> Self {
+        match display {
+            LeaderboardDisplayType::Numeric => Self::k_ELeaderboardDisplayTypeNumeric,
+            LeaderboardDisplayType::TimeSeconds => Self::k_ELeaderboardDisplayTypeTimeSeconds,
+            LeaderboardDisplayType::TimeMilliSeconds => {
+                Self::k_ELeaderboardDisplayTypeTimeMilliSeconds
+            }
+        }
+    }
+}
+
+impl From<UploadScoreMethod> for bindings::ELeaderboardUploadScoreMethod {
+    fn from(method: UploadScoreMethod) -> Self {
+        match method {
+            UploadScoreMethod::KeepBest => Self::k_ELeaderboardUploadScoreMethodKeepBest,
+            UploadScoreMethod::ForceUpdate => Self::k_ELeaderboardUploadScoreMethodForceUpdate,
+        }
+    }
+}
+
+/// Stats and achievements of the current user
+///
+/// Values can only be read once steam has sent them, which it does at startup
+/// or after `request_current_stats()` as a [`UserStatsReceived`](crate::callbacks::UserStatsReceived)
+/// callback. Changes are kept locally until `store_stats()` uploads them.
+#[derive(Clone)]
+pub struct UserStats {
+    pub(crate) backend: Arc<dyn Backend>,
+}
+
+impl UserStats {
+    pub(crate) fn new(backend: Arc<dyn Backend>) -> Self {
+        Self { backend }
+    }
+
+    /// Asks steam for the current user's stats and achievements
+    pub fn request_current_stats(&self) -> SResult<()> {
+        check(self.backend.user_stats().request_current_stats())
+    }
+
+    /// Fails if the stat doesn't exist, isn't an integer or stats haven't been received yet
+    pub fn get_stat_i32(&self, name: &str) -> SResult<i32> {
+        self.backend
+            .user_stats()
+            .get_stat_i32(name)
+            .ok_or(SteamResult::Generic)
+    }
+
+    /// Fails if the stat doesn't exist, isn't a float or stats haven't been received yet
+    pub fn get_stat_f32(&self, name: &str) -> SResult<f32> {
+        self.backend
+            .user_stats()
+            .get_stat_f32(name)
+            .ok_or(SteamResult::Generic)
+    }
+
+    pub fn set_stat_i32(&self, name: &str, value: i32) -> SResult<()> {
+        check(self.backend.user_stats().set_stat_i32(name, value))
+    }
+
+    pub fn set_stat_f32(&self, name: &str, value: f32) -> SResult<()> {
+        check(self.backend.user_stats().set_stat_f32(name, value))
+    }
+
+    /// Whether the achievement has been unlocked
+    pub fn get_achievement(&self, name: &str) -> SResult<bool> {
+        self.backend
+            .user_stats()
+            .get_achievement(name)
+            .ok_or(SteamResult::Generic)
+    }
+
+    /// Unlocks an achievement, the unlock notification is shown after `store_stats()`
+    pub fn set_achievement(&self, name: &str) -> SResult<()> {
+        check(self.backend.user_stats().set_achievement(name))
+    }
+
+    /// Locks an achievement again, mostly useful while testing
+    pub fn clear_achievement(&self, name: &str) -> SResult<()> {
+        check(self.backend.user_stats().clear_achievement(name))
+    }
+
+    /// Shows a progress notification for a locked achievement
+    ///
+    /// Fails once `current` reaches `max`, unlock the achievement with `set_achievement()` instead.
+    pub fn indicate_achievement_progress(&self, name: &str, current: u32, max: u32) -> SResult<()> {
+        check(
+            self.backend
+                .user_stats()
+                .indicate_achievement_progress(name, current, max),
+        )
+    }
+
+    /// Uploads changed stats and achievements, answered with a
+    /// [`UserStatsStored`](crate::callbacks::UserStatsStored) callback
+    pub fn store_stats(&self) -> SResult<()> {
+        check(self.backend.user_stats().store_stats())
+    }
+
+    /// Looks up a leaderboard by name, creating it with the given settings if it doesn't exist
+    pub fn find_or_create_leaderboard(
+        &self,
+        name: &str,
+        sort_method: LeaderboardSortMethod,
+        display_type: LeaderboardDisplayType,
+    ) -> impl Future<Output = SResult<Leaderboard>> {
+        CallResultFuture::map(
+            self.backend
+                .user_stats()
+                .find_or_create_leaderboard(name, sort_method, display_type),
+            |found: &LeaderboardFindResult| {
+                if found.found {
+                    Ok(found.leaderboard)
+                } else {
+                    Err(SteamResult::Generic)
+                }
+            },
+        )
+    }
+
+    /// Uploads a score for the current user
+    ///
+    /// `details` can hold up to `MAX_LEADERBOARD_DETAILS` game specific values, e.g. to
+    /// replay the run, more fail with `InvalidParameter`.
+    pub fn upload_leaderboard_score(
+        &self,
+        leaderboard: Leaderboard,
+        method: UploadScoreMethod,
+        score: i32,
+        details: &[i32],
+    ) -> impl Future<Output = SResult<LeaderboardScoreUploaded>> {
+        let call = (details.len() <= MAX_LEADERBOARD_DETAILS).then(|| {
+            CallResultFuture::map(
+                self.backend.user_stats().upload_leaderboard_score(
+                    leaderboard,
+                    method,
+                    score,
+                    details,
+                ),
+                |uploaded: &LeaderboardScoreUploaded| {
+                    if uploaded.success {
+                        Ok(uploaded.clone())
+                    } else {
+                        Err(SteamResult::Generic)
+                    }
+                },
+            )
+        });
+
+        async move { call.ok_or(SteamResult::InvalidParameter)?.await }
+    }
+
+    pub fn download_leaderboard_entries(
+        &self,
+        leaderboard: Leaderboard,
+        range: LeaderboardRange,
+    ) -> impl Future<Output = SResult<Vec<LeaderboardEntry>>> {
+        let backend = self.backend.clone();
+
+        // The downloaded entries are read while the call result is dispatched,
+        // steam doesn't keep them around for long
+        CallResultFuture::map(
+            self.backend
+                .user_stats()
+                .download_leaderboard_entries(leaderboard, range),
+            move |downloaded: &LeaderboardScoresDownloaded| {
+                Ok((0..downloaded.entry_count)
+                    .filter_map(|index| {
+                        backend
+                            .user_stats()
+                            .get_downloaded_leaderboard_entry(downloaded.entries, index)
+                    })
+                    .collect())
+            },
+        )
+    }
+}
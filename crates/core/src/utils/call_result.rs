@@ -0,0 +1,85 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+use super::super::*;
+use crate::backend::SteamAPICall;
+use crate::callbacks::Callback;
+
+struct Pending<C> {
+    result: Option<SResult<C>>,
+    waker: Option<Waker>,
+}
+
+/// Resolves to the result of an asynchronous steam api call
+///
+/// The result is delivered by `steam_api::run_callbacks`, so the future only makes
+/// progress while callbacks are being pumped.
+#[must_use = "futures do nothing unless polled"]
+pub struct CallResultFuture<T> {
+    pending: Arc<Mutex<Pending<T>>>,
+}
+
+impl<C: Callback> CallResultFuture<C> {
+    pub(crate) fn new(api_call: SteamAPICall) -> Self {
+        Self::map(api_call, |r: &C| Ok(r.clone()))
+    }
+}
+
+impl<T: Send + 'static> CallResultFuture<T> {
+    /// Like `new`, but turns the call result into `T` while it is being dispatched,
+    /// for results that hand out handles which are only valid at that point
+    pub(crate) fn map<C, F>(api_call: SteamAPICall, f: F) -> Self
+    where
+        C: Callback,
+        F: FnOnce(&C) -> SResult<T> + Send + 'static,
+    {
+        let pending = Arc::new(Mutex::new(Pending {
+            result: None,
+            waker: None,
+        }));
+
+        // Steam hands out an invalid handle when the call couldn't be started
+        if api_call == bindings::k_uAPICallInvalid {
+            pending.lock().unwrap().result = Some(Err(SteamResult::Generic));
+            return Self { pending };
+        }
+
+        let pending_ref = pending.clone();
+        steam_api::register_call_result::<C, _>(api_call, move |r, failed| {
+            // A result steam couldn't fetch can be too short to decode
+            let result = match r {
+                Some(r) if !failed => f(r),
+                _ => Err(SteamResult::IOFailure),
+            };
+
+            let waker = {
+                let mut pending = pending_ref.lock().unwrap();
+                pending.result = Some(result);
+                pending.waker.take()
+            };
+
+            if let Some(waker) = waker {
+                waker.wake();
+            }
+        });
+
+        Self { pending }
+    }
+}
+
+impl<T> Future for CallResultFuture<T> {
+    type Output = SResult<T>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut pending = self.pending.lock().unwrap();
+        match pending.result.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                pending.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
@@ -1,13 +1,915 @@
 use super::super::*;
-use std::ffi::c_void;
+use std::ffi::{c_char, c_void};
 
-pub unsafe trait Callback {
+use crate::backend::Payload;
+
+/// A steam callback or call result that can be decoded from its raw sdk struct
+///
+/// # Safety
+///
+/// `ID` and `SIZE` must match the sdk struct that `from_raw` reads.
+pub unsafe trait Callback: Clone + Send + 'static {
     const ID: i32;
     const SIZE: i32;
+
+    /// # Safety
+    ///
+    /// `raw` must point to at least `SIZE` bytes holding the sdk struct for `ID`.
+    /// It is not guaranteed to be aligned.
     unsafe fn from_raw(raw: *mut c_void) -> Self;
 }
 
+/// Reads a fixed size, nul terminated sdk string without trusting the terminator
+pub(crate) fn string_from_chars(chars: &[c_char]) -> String {
+    let bytes: Vec<u8> = chars
+        .iter()
+        .take_while(|&&c| c != 0)
+        .map(|&c| c as u8)
+        .collect();
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
 #[derive(Debug, Clone)]
 pub struct EncryptedAppTicketResponse {
     pub result: bindings::EResult,
 }
+
+unsafe impl Callback for EncryptedAppTicketResponse {
+    const ID: i32 = bindings::EncryptedAppTicketResponse_t_k_iCallback as i32;
+    const SIZE: i32 = std::mem::size_of::<bindings::EncryptedAppTicketResponse_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut c_void) -> Self {
+        let val = std::ptr::read_unaligned(raw as *const bindings::EncryptedAppTicketResponse_t);
+        Self {
+            result: val.m_eResult,
+        }
+    }
+}
+
+/// Sent when a connection to the steam servers has been established
+#[derive(Debug, Clone)]
+pub struct SteamServersConnected;
+
+unsafe impl Callback for SteamServersConnected {
+    const ID: i32 = bindings::SteamServersConnected_t_k_iCallback as i32;
+    const SIZE: i32 = std::mem::size_of::<bindings::SteamServersConnected_t>() as i32;
+
+    unsafe fn from_raw(_raw: *mut c_void) -> Self {
+        Self
+    }
+}
+
+/// Sent when the connection to the steam servers has been lost
+#[derive(Debug, Clone)]
+pub struct SteamServersDisconnected {
+    pub result: SteamResult,
+}
+
+unsafe impl Callback for SteamServersDisconnected {
+    const ID: i32 = bindings::SteamServersDisconnected_t_k_iCallback as i32;
+    const SIZE: i32 = std::mem::size_of::<bindings::SteamServersDisconnected_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut c_void) -> Self {
+        let val = std::ptr::read_unaligned(raw as *const bindings::SteamServersDisconnected_t);
+        Self {
+            result: val.m_eResult.into(),
+        }
+    }
+}
+
+/// Sent when a connection attempt to the steam servers has failed
+#[derive(Debug, Clone)]
+pub struct SteamServerConnectFailure {
+    pub result: SteamResult,
+    pub still_retrying: bool,
+}
+
+unsafe impl Callback for SteamServerConnectFailure {
+    const ID: i32 = bindings::SteamServerConnectFailure_t_k_iCallback as i32;
+    const SIZE: i32 = std::mem::size_of::<bindings::SteamServerConnectFailure_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut c_void) -> Self {
+        let val = std::ptr::read_unaligned(raw as *const bindings::SteamServerConnectFailure_t);
+        Self {
+            result: val.m_eResult.into(),
+            still_retrying: val.m_bStillRetrying,
+        }
+    }
+}
+
+/// Sent when the steam overlay is opened or closed
+#[derive(Debug, Clone)]
+pub struct GameOverlayActivated {
+    pub active: bool,
+}
+
+unsafe impl Callback for GameOverlayActivated {
+    const ID: i32 = bindings::GameOverlayActivated_t_k_iCallback as i32;
+    const SIZE: i32 = std::mem::size_of::<bindings::GameOverlayActivated_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut c_void) -> Self {
+        let val = std::ptr::read_unaligned(raw as *const bindings::GameOverlayActivated_t);
+        Self {
+            active: val.m_bActive != 0,
+        }
+    }
+}
+
+/// Sent when the stats and achievements of a user have been received from steam
+#[derive(Debug, Clone)]
+pub struct UserStatsReceived {
+    pub game_id: u64,
+    pub result: SteamResult,
+    pub steam_id: SteamId,
+}
+
+unsafe impl Callback for UserStatsReceived {
+    const ID: i32 = bindings::UserStatsReceived_t_k_iCallback as i32;
+    const SIZE: i32 = std::mem::size_of::<bindings::UserStatsReceived_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut c_void) -> Self {
+        let val = std::ptr::read_unaligned(raw as *const bindings::UserStatsReceived_t);
+        Self {
+            game_id: val.m_nGameID,
+            result: val.m_eResult.into(),
+            steam_id: SteamId(val.m_steamIDUser.m_steamid.m_unAll64Bits),
+        }
+    }
+}
+
+/// Sent once `UserStats::store_stats` has finished uploading the current stats
+#[derive(Debug, Clone)]
+pub struct UserStatsStored {
+    pub game_id: u64,
+    pub result: SteamResult,
+}
+
+unsafe impl Callback for UserStatsStored {
+    const ID: i32 = bindings::UserStatsStored_t_k_iCallback as i32;
+    const SIZE: i32 = std::mem::size_of::<bindings::UserStatsStored_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut c_void) -> Self {
+        let val = std::ptr::read_unaligned(raw as *const bindings::UserStatsStored_t);
+        Self {
+            game_id: val.m_nGameID,
+            result: val.m_eResult.into(),
+        }
+    }
+}
+
+/// Sent when an achievement has been unlocked, or its progress indicated
+///
+/// Both progress values are 0 for an unlock.
+#[derive(Debug, Clone)]
+pub struct UserAchievementStored {
+    pub game_id: u64,
+    pub group_achievement: bool,
+    pub achievement_name: String,
+    pub current_progress: u32,
+    pub max_progress: u32,
+}
+
+unsafe impl Callback for UserAchievementStored {
+    const ID: i32 = bindings::UserAchievementStored_t_k_iCallback as i32;
+    const SIZE: i32 = std::mem::size_of::<bindings::UserAchievementStored_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut c_void) -> Self {
+        let val = std::ptr::read_unaligned(raw as *const bindings::UserAchievementStored_t);
+        Self {
+            game_id: val.m_nGameID,
+            group_achievement: val.m_bGroupAchievement,
+            achievement_name: string_from_chars(&val.m_rgchAchievementName),
+            current_progress: val.m_nCurProgress,
+            max_progress: val.m_nMaxProgress,
+        }
+    }
+}
+
+/// Result of `UserStats::find_or_create_leaderboard`
+#[derive(Debug, Clone)]
+pub struct LeaderboardFindResult {
+    pub leaderboard: Leaderboard,
+    pub found: bool,
+}
+
+unsafe impl Callback for LeaderboardFindResult {
+    const ID: i32 = bindings::LeaderboardFindResult_t_k_iCallback as i32;
+    const SIZE: i32 = std::mem::size_of::<bindings::LeaderboardFindResult_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut c_void) -> Self {
+        let val = std::ptr::read_unaligned(raw as *const bindings::LeaderboardFindResult_t);
+        Self {
+            leaderboard: Leaderboard(val.m_hSteamLeaderboard),
+            found: val.m_bLeaderboardFound != 0,
+        }
+    }
+}
+
+/// Result of `UserStats::download_leaderboard_entries`
+#[derive(Debug, Clone)]
+pub struct LeaderboardScoresDownloaded {
+    pub leaderboard: Leaderboard,
+    /// Handle to read the downloaded entries from
+    pub entries: u64,
+    pub entry_count: i32,
+}
+
+unsafe impl Callback for LeaderboardScoresDownloaded {
+    const ID: i32 = bindings::LeaderboardScoresDownloaded_t_k_iCallback as i32;
+    const SIZE: i32 = std::mem::size_of::<bindings::LeaderboardScoresDownloaded_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut c_void) -> Self {
+        let val = std::ptr::read_unaligned(raw as *const bindings::LeaderboardScoresDownloaded_t);
+        Self {
+            leaderboard: Leaderboard(val.m_hSteamLeaderboard),
+            entries: val.m_hSteamLeaderboardEntries,
+            entry_count: val.m_cEntryCount,
+        }
+    }
+}
+
+/// Result of `UserStats::upload_leaderboard_score`
+#[derive(Debug, Clone)]
+pub struct LeaderboardScoreUploaded {
+    pub success: bool,
+    pub leaderboard: Leaderboard,
+    pub score: i32,
+    /// False if the score was kept because the previous one ranked better
+    pub score_changed: bool,
+    pub global_rank_new: i32,
+    /// 0 if the user had no entry before
+    pub global_rank_previous: i32,
+}
+
+unsafe impl Callback for LeaderboardScoreUploaded {
+    const ID: i32 = bindings::LeaderboardScoreUploaded_t_k_iCallback as i32;
+    const SIZE: i32 = std::mem::size_of::<bindings::LeaderboardScoreUploaded_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut c_void) -> Self {
+        let val = std::ptr::read_unaligned(raw as *const bindings::LeaderboardScoreUploaded_t);
+        Self {
+            success: val.m_bSuccess != 0,
+            leaderboard: Leaderboard(val.m_hSteamLeaderboard),
+            score: val.m_nScore,
+            score_changed: val.m_bScoreChanged != 0,
+            global_rank_new: val.m_nGlobalRankNew,
+            global_rank_previous: val.m_nGlobalRankPrevious,
+        }
+    }
+}
+
+/// Result of sending a workshop query
+#[derive(Debug, Clone)]
+pub struct SteamUGCQueryCompleted {
+    pub handle: u64,
+    pub result: SteamResult,
+    pub results_returned: u32,
+    pub total_matching_results: u32,
+    pub cached_data: bool,
+}
+
+unsafe impl Callback for SteamUGCQueryCompleted {
+    const ID: i32 = bindings::SteamUGCQueryCompleted_t_k_iCallback as i32;
+    const SIZE: i32 = std::mem::size_of::<bindings::SteamUGCQueryCompleted_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut c_void) -> Self {
+        let val = std::ptr::read_unaligned(raw as *const bindings::SteamUGCQueryCompleted_t);
+        Self {
+            handle: val.m_handle,
+            result: val.m_eResult.into(),
+            results_returned: val.m_unNumResultsReturned,
+            total_matching_results: val.m_unTotalMatchingResults,
+            cached_data: val.m_bCachedData,
+        }
+    }
+}
+
+/// Result of `Ugc::create_item`
+#[derive(Debug, Clone)]
+pub struct CreateItemResult {
+    pub result: SteamResult,
+    pub published_file_id: PublishedFileId,
+    pub needs_to_accept_agreement: bool,
+}
+
+unsafe impl Callback for CreateItemResult {
+    const ID: i32 = bindings::CreateItemResult_t_k_iCallback as i32;
+    const SIZE: i32 = std::mem::size_of::<bindings::CreateItemResult_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut c_void) -> Self {
+        let val = std::ptr::read_unaligned(raw as *const bindings::CreateItemResult_t);
+        Self {
+            result: val.m_eResult.into(),
+            published_file_id: PublishedFileId(val.m_nPublishedFileId),
+            needs_to_accept_agreement: val.m_bUserNeedsToAcceptWorkshopLegalAgreement,
+        }
+    }
+}
+
+/// Result of `ItemUpdate::submit`
+#[derive(Debug, Clone)]
+pub struct SubmitItemUpdateResult {
+    pub result: SteamResult,
+    pub needs_to_accept_agreement: bool,
+    pub published_file_id: PublishedFileId,
+}
+
+unsafe impl Callback for SubmitItemUpdateResult {
+    const ID: i32 = bindings::SubmitItemUpdateResult_t_k_iCallback as i32;
+    const SIZE: i32 = std::mem::size_of::<bindings::SubmitItemUpdateResult_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut c_void) -> Self {
+        let val = std::ptr::read_unaligned(raw as *const bindings::SubmitItemUpdateResult_t);
+        Self {
+            result: val.m_eResult.into(),
+            needs_to_accept_agreement: val.m_bUserNeedsToAcceptWorkshopLegalAgreement,
+            published_file_id: PublishedFileId(val.m_nPublishedFileId),
+        }
+    }
+}
+
+/// Result of `Ugc::subscribe_item`
+#[derive(Debug, Clone)]
+pub struct RemoteStorageSubscribePublishedFileResult {
+    pub result: SteamResult,
+    pub published_file_id: PublishedFileId,
+}
+
+unsafe impl Callback for RemoteStorageSubscribePublishedFileResult {
+    const ID: i32 = bindings::RemoteStorageSubscribePublishedFileResult_t_k_iCallback as i32;
+    const SIZE: i32 =
+        std::mem::size_of::<bindings::RemoteStorageSubscribePublishedFileResult_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut c_void) -> Self {
+        let val = std::ptr::read_unaligned(
+            raw as *const bindings::RemoteStorageSubscribePublishedFileResult_t,
+        );
+        Self {
+            result: val.m_eResult.into(),
+            published_file_id: PublishedFileId(val.m_nPublishedFileId),
+        }
+    }
+}
+
+/// Result of `Ugc::unsubscribe_item`
+#[derive(Debug, Clone)]
+pub struct RemoteStorageUnsubscribePublishedFileResult {
+    pub result: SteamResult,
+    pub published_file_id: PublishedFileId,
+}
+
+unsafe impl Callback for RemoteStorageUnsubscribePublishedFileResult {
+    const ID: i32 = bindings::RemoteStorageUnsubscribePublishedFileResult_t_k_iCallback as i32;
+    const SIZE: i32 =
+        std::mem::size_of::<bindings::RemoteStorageUnsubscribePublishedFileResult_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut c_void) -> Self {
+        let val = std::ptr::read_unaligned(
+            raw as *const bindings::RemoteStorageUnsubscribePublishedFileResult_t,
+        );
+        Self {
+            result: val.m_eResult.into(),
+            published_file_id: PublishedFileId(val.m_nPublishedFileId),
+        }
+    }
+}
+
+/// Sent when a workshop item has finished downloading
+#[derive(Debug, Clone)]
+pub struct DownloadItemResult {
+    pub app_id: AppId,
+    pub published_file_id: PublishedFileId,
+    pub result: SteamResult,
+}
+
+unsafe impl Callback for DownloadItemResult {
+    const ID: i32 = bindings::DownloadItemResult_t_k_iCallback as i32;
+    const SIZE: i32 = std::mem::size_of::<bindings::DownloadItemResult_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut c_void) -> Self {
+        let val = std::ptr::read_unaligned(raw as *const bindings::DownloadItemResult_t);
+        Self {
+            app_id: AppId(val.m_unAppID),
+            published_file_id: PublishedFileId(val.m_nPublishedFileId),
+            result: val.m_eResult.into(),
+        }
+    }
+}
+
+/// Sent when a workshop item has been installed or updated
+#[derive(Debug, Clone)]
+pub struct ItemInstalled {
+    pub app_id: AppId,
+    pub published_file_id: PublishedFileId,
+}
+
+unsafe impl Callback for ItemInstalled {
+    const ID: i32 = bindings::ItemInstalled_t_k_iCallback as i32;
+    const SIZE: i32 = std::mem::size_of::<bindings::ItemInstalled_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut c_void) -> Self {
+        let val = std::ptr::read_unaligned(raw as *const bindings::ItemInstalled_t);
+        Self {
+            app_id: AppId(val.m_unAppID),
+            published_file_id: PublishedFileId(val.m_nPublishedFileId),
+        }
+    }
+}
+
+/// Sent whenever a connection is created, destroyed or changes state
+///
+/// Incoming connections arrive as `Connecting` with `info.listen_socket` set, take
+/// them with `NetworkingSockets::accept_connection` or turn them down with
+/// `NetworkingSockets::reject_connection`.
+#[derive(Debug, Clone)]
+pub struct NetConnectionStatusChanged {
+    pub connection: ConnectionHandle,
+    pub info: NetConnectionInfo,
+    pub old_state: NetworkingConnectionState,
+}
+
+unsafe impl Callback for NetConnectionStatusChanged {
+    const ID: i32 = bindings::SteamNetConnectionStatusChangedCallback_t_k_iCallback as i32;
+    const SIZE: i32 =
+        std::mem::size_of::<bindings::SteamNetConnectionStatusChangedCallback_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut c_void) -> Self {
+        let val = std::ptr::read_unaligned(
+            raw as *const bindings::SteamNetConnectionStatusChangedCallback_t,
+        );
+        Self {
+            connection: ConnectionHandle(val.m_hConn),
+            info: NetConnectionInfo::from(&val.m_info),
+            old_state: val.m_eOldState.into(),
+        }
+    }
+}
+
+/// Sent when a user we don't have a session with sends us a message
+///
+/// Accept it with `NetworkingMessages::accept_session` to receive their messages.
+#[derive(Debug, Clone)]
+pub struct NetworkingMessagesSessionRequest {
+    pub remote_identity: NetworkingIdentity,
+}
+
+unsafe impl Callback for NetworkingMessagesSessionRequest {
+    const ID: i32 = bindings::SteamNetworkingMessagesSessionRequest_t_k_iCallback as i32;
+    const SIZE: i32 =
+        std::mem::size_of::<bindings::SteamNetworkingMessagesSessionRequest_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut c_void) -> Self {
+        let val = std::ptr::read_unaligned(
+            raw as *const bindings::SteamNetworkingMessagesSessionRequest_t,
+        );
+        Self {
+            remote_identity: NetworkingIdentity::from(&val.m_identityRemote),
+        }
+    }
+}
+
+/// Sent when a session couldn't be established or was lost
+#[derive(Debug, Clone)]
+pub struct NetworkingMessagesSessionFailed {
+    pub info: NetConnectionInfo,
+}
+
+unsafe impl Callback for NetworkingMessagesSessionFailed {
+    const ID: i32 = bindings::SteamNetworkingMessagesSessionFailed_t_k_iCallback as i32;
+    const SIZE: i32 =
+        std::mem::size_of::<bindings::SteamNetworkingMessagesSessionFailed_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut c_void) -> Self {
+        let val = std::ptr::read_unaligned(
+            raw as *const bindings::SteamNetworkingMessagesSessionFailed_t,
+        );
+        Self {
+            info: NetConnectionInfo::from(&{ val.m_info }),
+        }
+    }
+}
+
+/// Result of `Matchmaking::create_lobby`
+#[derive(Debug, Clone)]
+pub struct LobbyCreated {
+    pub result: SteamResult,
+    pub lobby: SteamId,
+}
+
+unsafe impl Callback for LobbyCreated {
+    const ID: i32 = bindings::LobbyCreated_t_k_iCallback as i32;
+    const SIZE: i32 = std::mem::size_of::<bindings::LobbyCreated_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut c_void) -> Self {
+        let val = std::ptr::read_unaligned(raw as *const bindings::LobbyCreated_t);
+        Self {
+            result: val.m_eResult.into(),
+            lobby: SteamId(val.m_ulSteamIDLobby),
+        }
+    }
+}
+
+/// Result of a lobby search
+#[derive(Debug, Clone)]
+pub struct LobbyMatchList {
+    pub lobbies_matching: u32,
+}
+
+unsafe impl Callback for LobbyMatchList {
+    const ID: i32 = bindings::LobbyMatchList_t_k_iCallback as i32;
+    const SIZE: i32 = std::mem::size_of::<bindings::LobbyMatchList_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut c_void) -> Self {
+        let val = std::ptr::read_unaligned(raw as *const bindings::LobbyMatchList_t);
+        Self {
+            lobbies_matching: val.m_nLobbiesMatching,
+        }
+    }
+}
+
+/// Sent when the current user has entered a lobby, or failed to
+#[derive(Debug, Clone)]
+pub struct LobbyEnter {
+    pub lobby: SteamId,
+    pub chat_permissions: u32,
+    pub locked: bool,
+    pub response: ChatRoomEnterResponse,
+}
+
+unsafe impl Callback for LobbyEnter {
+    const ID: i32 = bindings::LobbyEnter_t_k_iCallback as i32;
+    const SIZE: i32 = std::mem::size_of::<bindings::LobbyEnter_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut c_void) -> Self {
+        let val = std::ptr::read_unaligned(raw as *const bindings::LobbyEnter_t);
+        Self {
+            lobby: SteamId(val.m_ulSteamIDLobby),
+            chat_permissions: val.m_rgfChatPermissions,
+            locked: val.m_bLocked,
+            response: val.m_EChatRoomEnterResponse.into(),
+        }
+    }
+}
+
+/// Sent when a user joins or leaves a lobby the current user is in
+#[derive(Debug, Clone)]
+pub struct LobbyChatUpdate {
+    pub lobby: SteamId,
+    pub user_changed: SteamId,
+    /// Who caused the change, e.g. the user that kicked `user_changed`
+    pub making_change: SteamId,
+    pub state_change: ChatMemberStateChange,
+}
+
+unsafe impl Callback for LobbyChatUpdate {
+    const ID: i32 = bindings::LobbyChatUpdate_t_k_iCallback as i32;
+    const SIZE: i32 = std::mem::size_of::<bindings::LobbyChatUpdate_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut c_void) -> Self {
+        let val = std::ptr::read_unaligned(raw as *const bindings::LobbyChatUpdate_t);
+        Self {
+            lobby: SteamId(val.m_ulSteamIDLobby),
+            user_changed: SteamId(val.m_ulSteamIDUserChanged),
+            making_change: SteamId(val.m_ulSteamIDMakingChange),
+            state_change: ChatMemberStateChange::from_bits_truncate(val.m_rgfChatMemberStateChange),
+        }
+    }
+}
+
+/// Sent when the metadata of a lobby or one of its members has changed
+#[derive(Debug, Clone)]
+pub struct LobbyDataUpdate {
+    pub lobby: SteamId,
+    /// The member whose data changed, or the lobby itself for lobby data
+    pub member: SteamId,
+    pub success: bool,
+}
+
+unsafe impl Callback for LobbyDataUpdate {
+    const ID: i32 = bindings::LobbyDataUpdate_t_k_iCallback as i32;
+    const SIZE: i32 = std::mem::size_of::<bindings::LobbyDataUpdate_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut c_void) -> Self {
+        let val = std::ptr::read_unaligned(raw as *const bindings::LobbyDataUpdate_t);
+        Self {
+            lobby: SteamId(val.m_ulSteamIDLobby),
+            member: SteamId(val.m_ulSteamIDMember),
+            success: val.m_bSuccess != 0,
+        }
+    }
+}
+
+/// Sent when a chat message has been sent to a lobby, read it with
+/// `Matchmaking::lobby_chat_entry`
+#[derive(Debug, Clone)]
+pub struct LobbyChatMsg {
+    pub lobby: SteamId,
+    pub user: SteamId,
+    pub entry_type: ChatEntryType,
+    pub chat_id: u32,
+}
+
+unsafe impl Callback for LobbyChatMsg {
+    const ID: i32 = bindings::LobbyChatMsg_t_k_iCallback as i32;
+    const SIZE: i32 = std::mem::size_of::<bindings::LobbyChatMsg_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut c_void) -> Self {
+        let val = std::ptr::read_unaligned(raw as *const bindings::LobbyChatMsg_t);
+        Self {
+            lobby: SteamId(val.m_ulSteamIDLobby),
+            user: SteamId(val.m_ulSteamIDUser),
+            entry_type: val.m_eChatEntryType.into(),
+            chat_id: val.m_iChatID,
+        }
+    }
+}
+
+/// Sent to a game server after logging on, saying whether it is VAC secured
+#[derive(Debug, Clone)]
+pub struct GSPolicyResponse {
+    pub secure: bool,
+}
+
+unsafe impl Callback for GSPolicyResponse {
+    const ID: i32 = bindings::GSPolicyResponse_t_k_iCallback as i32;
+    const SIZE: i32 = std::mem::size_of::<bindings::GSPolicyResponse_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut c_void) -> Self {
+        let val = std::ptr::read_unaligned(raw as *const bindings::GSPolicyResponse_t);
+        Self {
+            secure: val.m_bSecure != 0,
+        }
+    }
+}
+
+/// Sent when a ticket from `User::get_auth_session_ticket` is ready to be sent, or
+/// couldn't be created
+#[derive(Debug, Clone)]
+pub struct GetAuthSessionTicketResponse {
+    pub ticket: AuthTicketHandle,
+    pub result: SteamResult,
+}
+
+unsafe impl Callback for GetAuthSessionTicketResponse {
+    const ID: i32 = bindings::GetAuthSessionTicketResponse_t_k_iCallback as i32;
+    const SIZE: i32 = std::mem::size_of::<bindings::GetAuthSessionTicketResponse_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut c_void) -> Self {
+        let val = std::ptr::read_unaligned(raw as *const bindings::GetAuthSessionTicketResponse_t);
+        Self {
+            ticket: AuthTicketHandle(val.m_hAuthTicket),
+            result: val.m_eResult.into(),
+        }
+    }
+}
+
+/// Sent when a ticket from `User::get_auth_ticket_for_web_api` is ready, or couldn't
+/// be created
+#[cfg(feature = "web-api-ticket")]
+#[derive(Debug, Clone)]
+pub struct GetTicketForWebApiResponse {
+    pub ticket: AuthTicketHandle,
+    pub result: SteamResult,
+    pub data: Vec<u8>,
+}
+
+#[cfg(feature = "web-api-ticket")]
+unsafe impl Callback for GetTicketForWebApiResponse {
+    const ID: i32 = bindings::GetTicketForWebApiResponse_t_k_iCallback;
+    const SIZE: i32 = std::mem::size_of::<bindings::GetTicketForWebApiResponse_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut c_void) -> Self {
+        let val = std::ptr::read_unaligned(raw as *const bindings::GetTicketForWebApiResponse_t);
+        let len = (val.m_cubTicket.max(0) as usize).min(val.m_rgubTicket.len());
+        Self {
+            ticket: AuthTicketHandle(val.m_hAuthTicket),
+            result: val.m_eResult.into(),
+            data: val.m_rgubTicket[..len].to_vec(),
+        }
+    }
+}
+
+/// The outcome of a `begin_auth_session`, sent again if the session is ended later on,
+/// e.g. because the ticket was cancelled
+#[derive(Debug, Clone)]
+pub struct ValidateAuthTicketResponse {
+    pub steam_id: SteamId,
+    pub response: AuthSessionResponse,
+    /// Who owns the game, differs from `steam_id` when it is borrowed through family sharing
+    pub owner_steam_id: SteamId,
+}
+
+unsafe impl Callback for ValidateAuthTicketResponse {
+    const ID: i32 = bindings::ValidateAuthTicketResponse_t_k_iCallback as i32;
+    const SIZE: i32 = std::mem::size_of::<bindings::ValidateAuthTicketResponse_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut c_void) -> Self {
+        let val = std::ptr::read_unaligned(raw as *const bindings::ValidateAuthTicketResponse_t);
+        Self {
+            steam_id: SteamId(val.m_SteamID.m_steamid.m_unAll64Bits),
+            response: val.m_eAuthSessionResponse.into(),
+            owner_steam_id: SteamId(val.m_OwnerSteamID.m_steamid.m_unAll64Bits),
+        }
+    }
+}
+
+/// Sent when a DLC installed with `Apps::install_dlc` has finished downloading
+#[derive(Debug, Clone)]
+pub struct DlcInstalled {
+    pub app_id: AppId,
+}
+
+unsafe impl Callback for DlcInstalled {
+    const ID: i32 = bindings::DlcInstalled_t_k_iCallback as i32;
+    const SIZE: i32 = std::mem::size_of::<bindings::DlcInstalled_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut c_void) -> Self {
+        let val = std::ptr::read_unaligned(raw as *const bindings::DlcInstalled_t);
+        Self {
+            app_id: AppId(val.m_nAppID),
+        }
+    }
+}
+
+/// Sent when the game is launched again through a steam URL while it's already running,
+/// the new parameters can be read with `Apps::get_launch_query_param` and
+/// `Apps::get_launch_command_line`
+#[derive(Debug, Clone)]
+pub struct NewUrlLaunchParameters;
+
+unsafe impl Callback for NewUrlLaunchParameters {
+    const ID: i32 = bindings::NewUrlLaunchParameters_t_k_iCallback as i32;
+    const SIZE: i32 = std::mem::size_of::<bindings::NewUrlLaunchParameters_t>() as i32;
+
+    unsafe fn from_raw(_raw: *mut c_void) -> Self {
+        Self
+    }
+}
+
+/// Sent when a user's name, status, game or other public state has changed, or has
+/// been downloaded after `Friends::request_user_information`
+#[derive(Debug, Clone)]
+pub struct PersonaStateChange {
+    pub steam_id: SteamId,
+    pub changes: PersonaChange,
+}
+
+unsafe impl Callback for PersonaStateChange {
+    const ID: i32 = bindings::PersonaStateChange_t_k_iCallback as i32;
+    const SIZE: i32 = std::mem::size_of::<bindings::PersonaStateChange_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut c_void) -> Self {
+        let val = std::ptr::read_unaligned(raw as *const bindings::PersonaStateChange_t);
+        Self {
+            steam_id: SteamId(val.m_ulSteamID),
+            changes: PersonaChange::from_bits_truncate(val.m_nChangeFlags as u32),
+        }
+    }
+}
+
+/// Sent when a friend's rich presence has changed or has been downloaded
+#[derive(Debug, Clone)]
+pub struct FriendRichPresenceUpdate {
+    pub steam_id: SteamId,
+    pub app_id: AppId,
+}
+
+unsafe impl Callback for FriendRichPresenceUpdate {
+    const ID: i32 = bindings::FriendRichPresenceUpdate_t_k_iCallback as i32;
+    const SIZE: i32 = std::mem::size_of::<bindings::FriendRichPresenceUpdate_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut c_void) -> Self {
+        let val = std::ptr::read_unaligned(raw as *const bindings::FriendRichPresenceUpdate_t);
+        Self {
+            steam_id: SteamId(val.m_steamIDFriend.m_steamid.m_unAll64Bits),
+            app_id: AppId(val.m_nAppID),
+        }
+    }
+}
+
+/// Sent when an avatar that was still loading, e.g. from `Friends::get_avatar`, is ready
+#[derive(Debug, Clone)]
+pub struct AvatarImageLoaded {
+    pub steam_id: SteamId,
+    /// Handle to read the image with
+    pub image: i32,
+    pub width: u32,
+    pub height: u32,
+}
+
+unsafe impl Callback for AvatarImageLoaded {
+    const ID: i32 = bindings::AvatarImageLoaded_t_k_iCallback as i32;
+    const SIZE: i32 = std::mem::size_of::<bindings::AvatarImageLoaded_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut c_void) -> Self {
+        let val = std::ptr::read_unaligned(raw as *const bindings::AvatarImageLoaded_t);
+        Self {
+            steam_id: SteamId(val.m_steamID.m_steamid.m_unAll64Bits),
+            image: val.m_iImage,
+            width: val.m_iWide.max(0) as u32,
+            height: val.m_iTall.max(0) as u32,
+        }
+    }
+}
+
+/// Sent when the user closes the keyboard opened by `Utils::show_gamepad_text_input`
+#[derive(Debug, Clone)]
+pub struct GamepadTextInputDismissed {
+    /// False if the user cancelled
+    pub submitted: bool,
+    /// Length of the entered text in bytes
+    pub text_len: u32,
+    pub app_id: AppId,
+}
+
+unsafe impl Callback for GamepadTextInputDismissed {
+    const ID: i32 = bindings::GamepadTextInputDismissed_t_k_iCallback as i32;
+    const SIZE: i32 = std::mem::size_of::<bindings::GamepadTextInputDismissed_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut c_void) -> Self {
+        let val = std::ptr::read_unaligned(raw as *const bindings::GamepadTextInputDismissed_t);
+        Self {
+            submitted: val.m_bSubmitted,
+            text_len: val.m_unSubmittedText,
+            app_id: AppId(val.m_unAppID),
+        }
+    }
+}
+
+/// Every callback that can be received through `steam_api::event_channel`
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum Event {
+    SteamServersConnected(SteamServersConnected),
+    SteamServersDisconnected(SteamServersDisconnected),
+    SteamServerConnectFailure(SteamServerConnectFailure),
+    GameOverlayActivated(GameOverlayActivated),
+    UserStatsReceived(UserStatsReceived),
+    UserStatsStored(UserStatsStored),
+    UserAchievementStored(UserAchievementStored),
+    DownloadItemResult(DownloadItemResult),
+    ItemInstalled(ItemInstalled),
+    NetConnectionStatusChanged(NetConnectionStatusChanged),
+    NetworkingMessagesSessionRequest(NetworkingMessagesSessionRequest),
+    NetworkingMessagesSessionFailed(NetworkingMessagesSessionFailed),
+    LobbyEnter(LobbyEnter),
+    LobbyChatUpdate(LobbyChatUpdate),
+    LobbyDataUpdate(LobbyDataUpdate),
+    LobbyChatMsg(LobbyChatMsg),
+    GetAuthSessionTicketResponse(GetAuthSessionTicketResponse),
+    #[cfg(feature = "web-api-ticket")]
+    GetTicketForWebApiResponse(GetTicketForWebApiResponse),
+    ValidateAuthTicketResponse(ValidateAuthTicketResponse),
+    DlcInstalled(DlcInstalled),
+    NewUrlLaunchParameters(NewUrlLaunchParameters),
+    PersonaStateChange(PersonaStateChange),
+    FriendRichPresenceUpdate(FriendRichPresenceUpdate),
+    AvatarImageLoaded(AvatarImageLoaded),
+    GamepadTextInputDismissed(GamepadTextInputDismissed),
+}
+
+impl Event {
+    pub(crate) fn decode(id: i32, payload: &Payload) -> Option<Event> {
+        match id {
+            SteamServersConnected::ID => payload.decode().map(Event::SteamServersConnected),
+            SteamServersDisconnected::ID => payload.decode().map(Event::SteamServersDisconnected),
+            SteamServerConnectFailure::ID => payload.decode().map(Event::SteamServerConnectFailure),
+            GameOverlayActivated::ID => payload.decode().map(Event::GameOverlayActivated),
+            UserStatsReceived::ID => payload.decode().map(Event::UserStatsReceived),
+            UserStatsStored::ID => payload.decode().map(Event::UserStatsStored),
+            UserAchievementStored::ID => payload.decode().map(Event::UserAchievementStored),
+            DownloadItemResult::ID => payload.decode().map(Event::DownloadItemResult),
+            ItemInstalled::ID => payload.decode().map(Event::ItemInstalled),
+            NetConnectionStatusChanged::ID => {
+                payload.decode().map(Event::NetConnectionStatusChanged)
+            }
+            NetworkingMessagesSessionRequest::ID => payload
+                .decode()
+                .map(Event::NetworkingMessagesSessionRequest),
+            NetworkingMessagesSessionFailed::ID => {
+                payload.decode().map(Event::NetworkingMessagesSessionFailed)
+            }
+            LobbyEnter::ID => payload.decode().map(Event::LobbyEnter),
+            LobbyChatUpdate::ID => payload.decode().map(Event::LobbyChatUpdate),
+            LobbyDataUpdate::ID => payload.decode().map(Event::LobbyDataUpdate),
+            LobbyChatMsg::ID => payload.decode().map(Event::LobbyChatMsg),
+            GetAuthSessionTicketResponse::ID => {
+                payload.decode().map(Event::GetAuthSessionTicketResponse)
+            }
+            #[cfg(feature = "web-api-ticket")]
+            GetTicketForWebApiResponse::ID => {
+                payload.decode().map(Event::GetTicketForWebApiResponse)
+            }
+            ValidateAuthTicketResponse::ID => {
+                payload.decode().map(Event::ValidateAuthTicketResponse)
+            }
+            DlcInstalled::ID => payload.decode().map(Event::DlcInstalled),
+            NewUrlLaunchParameters::ID => payload.decode().map(Event::NewUrlLaunchParameters),
+            PersonaStateChange::ID => payload.decode().map(Event::PersonaStateChange),
+            FriendRichPresenceUpdate::ID => payload.decode().map(Event::FriendRichPresenceUpdate),
+            AvatarImageLoaded::ID => payload.decode().map(Event::AvatarImageLoaded),
+            GamepadTextInputDismissed::ID => payload.decode().map(Event::GamepadTextInputDismissed),
+            _ => None,
+        }
+    }
+}
@@ -1,42 +1,361 @@
-use steamstacks_bindings as bindings;
+use std::future::{poll_fn, Future};
+use std::sync::{Arc, Mutex};
+use std::task::{Poll, Waker};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
+use crate::backend::Backend;
+use crate::{bindings, steam_api, AppId, SResult, Universe};
+
+pub mod call_result;
 pub mod callbacks;
 pub mod result;
 
+pub use call_result::*;
 pub use callbacks::*;
 pub use result::*;
 
+// Battery level steam reports when running on AC power
+const AC_POWER: u8 = 255;
+
+/// Which IP protocol an IPv6 connectivity check covers
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Ipv6ConnectivityProtocol {
+    Http,
+    Udp,
+}
+
+/// Whether steam managed to reach its servers over IPv6
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Ipv6ConnectivityState {
+    /// Not checked yet
+    Unknown,
+    Good,
+    Bad,
+}
+
+/// How the gamepad keyboard shows what is typed
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GamepadTextInputMode {
+    Normal,
+    /// Hides the entered characters
+    Password,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GamepadTextInputLineMode {
+    SingleLine,
+    MultipleLines,
+}
+
+/// Layout of the floating keyboard
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FloatingGamepadTextInputMode {
+    SingleLine,
+    MultipleLines,
+    Email,
+    Numeric,
+}
+
+/// The game's own text field, in screen pixels, which the floating keyboard avoids covering
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TextFieldRect {
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub height: i32,
+}
+
+/// A decoded image, e.g. an avatar
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Image {
+    pub width: u32,
+    pub height: u32,
+    /// `width * height` pixels, 4 bytes each
+    pub rgba: Vec<u8>,
+}
+
+/// Reads an image from one of steam's image handles, sized by what steam reports for it
+pub(crate) fn load_image(backend: &dyn Backend, handle: i32) -> SResult<Image> {
+    let (width, height) = backend
+        .utils()
+        .get_image_size(handle)
+        .ok_or(SteamResult::InvalidParameter)?;
+    let mut rgba = vec![0; width as usize * height as usize * 4];
+    if !backend.utils().get_image_rgba(handle, &mut rgba) {
+        return Err(SteamResult::Generic);
+    }
+
+    Ok(Image {
+        width,
+        height,
+        rgba,
+    })
+}
+
 #[derive(Clone)]
 pub struct Utils {
-    pub(crate) utils: *mut bindings::ISteamUtils,
+    pub(crate) backend: Arc<dyn Backend>,
 }
 
-unsafe impl Send for Utils {}
-unsafe impl Sync for Utils {}
-
 impl Utils {
-    pub(crate) fn new() -> Self {
-        Self {
-            utils: unsafe { bindings::SteamAPI_SteamUtils_v010() },
+    pub(crate) fn new(backend: Arc<dyn Backend>) -> Self {
+        Self { backend }
+    }
+
+    pub fn get_app_id(&self) -> AppId {
+        self.backend.utils().get_app_id()
+    }
+
+    /// The time according to steam's servers, unaffected by the local clock
+    pub fn get_server_real_time(&self) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(self.backend.utils().get_server_real_time() as u64)
+    }
+
+    /// The two letter ISO 3166-1 code of the country the user's IP is in, e.g. `US`
+    pub fn get_ip_country(&self) -> String {
+        self.backend.utils().get_ip_country()
+    }
+
+    /// The language the steam client is running in, e.g. `english`
+    pub fn get_steam_ui_language(&self) -> String {
+        self.backend.utils().get_steam_ui_language()
+    }
+
+    pub fn get_connected_universe(&self) -> Universe {
+        self.backend.utils().get_connected_universe()
+    }
+
+    /// Seconds since the user last interacted with the game
+    pub fn get_seconds_since_app_active(&self) -> u32 {
+        self.backend.utils().get_seconds_since_app_active()
+    }
+
+    /// Seconds since the user last interacted with the computer
+    pub fn get_seconds_since_computer_active(&self) -> u32 {
+        self.backend.utils().get_seconds_since_computer_active()
+    }
+
+    /// Battery level in percent, `None` when running on AC power
+    pub fn get_current_battery_power(&self) -> Option<u8> {
+        match self.backend.utils().get_current_battery_power() {
+            AC_POWER => None,
+            percent => Some(percent),
         }
     }
 
-    pub fn get_app_id(&self) -> u32 {
-        unsafe { bindings::SteamAPI_ISteamUtils_GetAppID(self.utils) }
+    pub fn is_steam_running_on_steam_deck(&self) -> bool {
+        self.backend.utils().is_steam_running_on_steam_deck()
+    }
+
+    pub fn is_steam_in_big_picture_mode(&self) -> bool {
+        self.backend.utils().is_steam_in_big_picture_mode()
+    }
+
+    pub fn is_steam_running_in_vr(&self) -> bool {
+        self.backend.utils().is_steam_running_in_vr()
+    }
+
+    /// Returns true if the game was started through the Steam China launcher
+    pub fn is_steam_china_launcher(&self) -> bool {
+        self.backend.utils().is_steam_china_launcher()
     }
 
-    pub fn get_image_size(&self, handle: i32, width: &mut u32, height: &mut u32) -> bool {
-        unsafe { bindings::SteamAPI_ISteamUtils_GetImageSize(self.utils, handle, width, height) }
+    pub fn get_ipv6_connectivity_state(
+        &self,
+        protocol: Ipv6ConnectivityProtocol,
+    ) -> Ipv6ConnectivityState {
+        self.backend.utils().get_ipv6_connectivity_state(protocol)
     }
 
-    pub fn get_image_rgba(&self, handle: i32, dest: &mut Vec<u8>, size: usize) -> bool {
-        unsafe {
-            bindings::SteamAPI_ISteamUtils_GetImageRGBA(
-                self.utils,
-                handle,
-                dest.as_mut_ptr(),
-                size as i32,
-            )
+    /// Opens the big picture gamepad keyboard, `GamepadTextInputDismissed` is sent once it closes
+    ///
+    /// Returns false if the keyboard couldn't be shown, e.g. outside of big picture
+    /// mode or when either text contains a nul byte.
+    pub fn show_gamepad_text_input(
+        &self,
+        mode: GamepadTextInputMode,
+        line_mode: GamepadTextInputLineMode,
+        description: &str,
+        max_chars: u32,
+        existing: &str,
+    ) -> bool {
+        self.backend.utils().show_gamepad_text_input(
+            mode,
+            line_mode,
+            description,
+            max_chars,
+            existing,
+        )
+    }
+
+    /// The text last submitted through the gamepad keyboard, `None` if it was cancelled
+    pub fn get_entered_gamepad_text(&self) -> Option<String> {
+        entered_gamepad_text(&*self.backend)
+    }
+
+    /// Shows the gamepad keyboard and waits for the user to close it
+    ///
+    /// Resolves to the submitted text, or `None` if the keyboard couldn't be shown
+    /// or the user cancelled.
+    pub fn request_text_input(
+        &self,
+        mode: GamepadTextInputMode,
+        line_mode: GamepadTextInputLineMode,
+        description: &str,
+        max_chars: u32,
+        existing: &str,
+    ) -> impl Future<Output = Option<String>> {
+        let backend = self.backend.clone();
+
+        // Listen before showing, so the keyboard can't close unnoticed
+        let dismissed = Arc::new(Mutex::new(TextInputWait::default()));
+        let handle = {
+            let dismissed = dismissed.clone();
+            steam_api::register_callback(move |input: GamepadTextInputDismissed| {
+                let waker = {
+                    let mut dismissed = dismissed.lock().unwrap();
+                    dismissed.submitted = Some(input.submitted);
+                    dismissed.waker.take()
+                };
+                if let Some(waker) = waker {
+                    waker.wake();
+                }
+            })
+        };
+        let shown = self.show_gamepad_text_input(mode, line_mode, description, max_chars, existing);
+
+        async move {
+            let _handle = handle;
+            if !shown {
+                return None;
+            }
+
+            let submitted = poll_fn(|cx| {
+                let mut dismissed = dismissed.lock().unwrap();
+                match dismissed.submitted {
+                    Some(submitted) => Poll::Ready(submitted),
+                    None => {
+                        dismissed.waker = Some(cx.waker().clone());
+                        Poll::Pending
+                    }
+                }
+            })
+            .await;
+
+            if submitted {
+                entered_gamepad_text(&*backend)
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Opens the floating keyboard, placed so it doesn't cover `field`
+    ///
+    /// Returns false if the keyboard couldn't be shown.
+    pub fn show_floating_gamepad_text_input(
+        &self,
+        mode: FloatingGamepadTextInputMode,
+        field: TextFieldRect,
+    ) -> bool {
+        self.backend
+            .utils()
+            .show_floating_gamepad_text_input(mode, field)
+    }
+
+    /// Closes the floating keyboard, returns false if none was open
+    pub fn dismiss_floating_gamepad_text_input(&self) -> bool {
+        self.backend.utils().dismiss_floating_gamepad_text_input()
+    }
+
+    /// The width and height of an image, `None` if the handle isn't valid
+    pub fn get_image_size(&self, handle: i32) -> Option<(u32, u32)> {
+        self.backend.utils().get_image_size(handle)
+    }
+
+    /// Reads an image, e.g. an avatar handle from `Friends`
+    ///
+    /// Fails with `InvalidParameter` if the handle isn't valid.
+    pub fn get_image(&self, handle: i32) -> SResult<Image> {
+        load_image(&*self.backend, handle)
+    }
+}
+
+#[derive(Default)]
+struct TextInputWait {
+    submitted: Option<bool>,
+    waker: Option<Waker>,
+}
+
+fn entered_gamepad_text(backend: &dyn Backend) -> Option<String> {
+    let len = backend.utils().get_entered_gamepad_text_length();
+    // Room for the nul terminator, in case steam's length leaves it out
+    let mut text = vec![0; len as usize + 1];
+    if !backend.utils().get_entered_gamepad_text_input(&mut text) {
+        return None;
+    }
+
+    let end = text.iter().position(|&c| c == 0).unwrap_or(text.len());
+    text.truncate(end);
+    Some(String::from_utf8_lossy(&text).into_owned())
+}
+
+impl From<GamepadTextInputMode> for bindings::EGamepadTextInputMode {
+    fn from(mode: GamepadTextInputMode) -> Self {
+        match mode {
+            GamepadTextInputMode::Normal => Self::k_EGamepadTextInputModeNormal,
+            GamepadTextInputMode::Password => Self::k_EGamepadTextInputModePassword,
+        }
+    }
+}
+
+impl From<GamepadTextInputLineMode> for bindings::EGamepadTextInputLineMode {
+    fn from(mode: GamepadTextInputLineMode) -> Self {
+        match mode {
+            GamepadTextInputLineMode::SingleLine => Self::k_EGamepadTextInputLineModeSingleLine,
+            GamepadTextInputLineMode::MultipleLines => {
+                Self::k_EGamepadTextInputLineModeMultipleLines
+            }
+        }
+    }
+}
+
+impl From<FloatingGamepadTextInputMode> for bindings::EFloatingGamepadTextInputMode {
+    fn from(mode: FloatingGamepadTextInputMode) -> Self {
+        match mode {
+            FloatingGamepadTextInputMode::SingleLine => {
+                Self::k_EFloatingGamepadTextInputModeModeSingleLine
+            }
+            FloatingGamepadTextInputMode::MultipleLines => {
+                Self::k_EFloatingGamepadTextInputModeModeMultipleLines
+            }
+            FloatingGamepadTextInputMode::Email => Self::k_EFloatingGamepadTextInputModeModeEmail,
+            FloatingGamepadTextInputMode::Numeric => {
+                Self::k_EFloatingGamepadTextInputModeModeNumeric
+            }
+        }
+    }
+}
+
+impl From<Ipv6ConnectivityProtocol> for bindings::ESteamIPv6ConnectivityProtocol {
+    fn from(protocol: Ipv6ConnectivityProtocol) -> Self {
+        match protocol {
+            Ipv6ConnectivityProtocol::Http => Self::k_ESteamIPv6ConnectivityProtocol_HTTP,
+            Ipv6ConnectivityProtocol::Udp => Self::k_ESteamIPv6ConnectivityProtocol_UDP,
+        }
+    }
+}
+
+impl From<bindings::ESteamIPv6ConnectivityState> for Ipv6ConnectivityState {
+    fn from(state: bindings::ESteamIPv6ConnectivityState) -> Self {
+        match state {
+            bindings::ESteamIPv6ConnectivityState::k_ESteamIPv6ConnectivityState_Good => {
+                Ipv6ConnectivityState::Good
+            }
+            bindings::ESteamIPv6ConnectivityState::k_ESteamIPv6ConnectivityState_Bad => {
+                Ipv6ConnectivityState::Bad
+            }
+            _ => Ipv6ConnectivityState::Unknown,
         }
     }
 }
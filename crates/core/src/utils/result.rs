@@ -387,6 +387,25 @@ pub enum SteamResult {
     WGNetworkSendExceeded,
 }
 
+impl SteamResult {
+    /// Converts `SteamResult::Ok` into `Ok(())` and every other value into an error
+    pub fn into_result(self) -> crate::SResult<()> {
+        match self {
+            SteamResult::Ok => Ok(()),
+            err => Err(err),
+        }
+    }
+}
+
+/// Converts the `bool` most steam calls return into a result, failing with `Generic`
+pub(crate) fn check(ok: bool) -> crate::SResult<()> {
+    if ok {
+        Ok(())
+    } else {
+        Err(SteamResult::Generic)
+    }
+}
+
 impl From<bindings::EResult> for SteamResult {
     fn from(r: bindings::EResult) -> Self {
         match r {
@@ -536,6 +555,21 @@ impl From<bindings::EResult> for SteamResult {
     }
 }
 
+impl From<bindings::EBeginAuthSessionResult> for SteamResult {
+    fn from(r: bindings::EBeginAuthSessionResult) -> Self {
+        use bindings::EBeginAuthSessionResult::*;
+        match r {
+            k_EBeginAuthSessionResultOK => SteamResult::Ok,
+            k_EBeginAuthSessionResultInvalidTicket => SteamResult::InvalidPassword,
+            k_EBeginAuthSessionResultDuplicateRequest => SteamResult::DuplicateRequest,
+            k_EBeginAuthSessionResultInvalidVersion => SteamResult::InvalidProtocolVersion,
+            k_EBeginAuthSessionResultGameMismatch => SteamResult::NoMatch,
+            k_EBeginAuthSessionResultExpiredTicket => SteamResult::Expired,
+            _ => SteamResult::Generic,
+        }
+    }
+}
+
 impl TryFrom<i64> for SteamResult {
     type Error = InvalidErrorCode;
 
@@ -0,0 +1,216 @@
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use steamstacks::backend::{FakeBackend, FakeDlc};
+use steamstacks::callbacks::{DlcInstalled, NewUrlLaunchParameters};
+use steamstacks::{steam_api, AppId, DepotId, DlcInfo, SteamId, TimedTrial};
+
+mod common;
+
+use common::init;
+
+#[test]
+fn dlcs_are_listed_and_installed() {
+    let mut unowned = FakeDlc::new(AppId(482), "Soundtrack");
+    unowned.available = false;
+    let _guard = init(
+        FakeBackend::new()
+            .with_dlc(FakeDlc::new(AppId(481), "Expansion"))
+            .with_dlc(unowned),
+    );
+    let apps = steam_api::apps();
+
+    let installed = Arc::new(Mutex::new(Vec::new()));
+    let _handle = {
+        let installed = installed.clone();
+        steam_api::register_callback(move |dlc: DlcInstalled| {
+            installed.lock().unwrap().push(dlc.app_id);
+        })
+    };
+
+    assert_eq!(apps.get_dlc_count(), 2);
+    assert_eq!(
+        apps.get_dlcs(),
+        [
+            DlcInfo {
+                app_id: AppId(481),
+                available: true,
+                name: "Expansion".to_owned()
+            },
+            DlcInfo {
+                app_id: AppId(482),
+                available: false,
+                name: "Soundtrack".to_owned()
+            }
+        ]
+    );
+    assert_eq!(apps.get_dlc_by_index(2), None);
+
+    apps.install_dlc(AppId(481));
+    apps.install_dlc(AppId(482));
+    steam_api::run_callbacks();
+    assert_eq!(*installed.lock().unwrap(), [AppId(481)]);
+    assert!(apps.is_dlc_installed(AppId(481)));
+    assert!(!apps.is_dlc_installed(AppId(482)));
+
+    apps.uninstall_dlc(AppId(481));
+    assert!(!apps.is_dlc_installed(AppId(481)));
+
+    steam_api::shutdown();
+}
+
+#[test]
+fn reports_branch_languages_and_install_info() {
+    let _guard = init(
+        FakeBackend::new()
+            .with_beta("public-test")
+            .with_languages("german", &["english", "german", "french"])
+            .with_installed_app(AppId(480), "/games/spacewar", [DepotId(481), DepotId(482)])
+            .with_build_id(1234),
+    );
+    let apps = steam_api::apps();
+
+    assert_eq!(apps.get_current_beta_name().as_deref(), Some("public-test"));
+    assert_eq!(apps.get_current_game_language(), "german");
+    assert_eq!(
+        apps.get_available_game_languages(),
+        ["english", "german", "french"]
+    );
+    assert!(apps.is_app_installed(AppId(480)));
+    assert_eq!(
+        apps.get_app_install_dir(AppId(480)),
+        Some(PathBuf::from("/games/spacewar"))
+    );
+    assert_eq!(apps.get_app_install_dir(AppId(570)), None);
+    assert_eq!(
+        apps.get_installed_depots(AppId(480)),
+        [DepotId(481), DepotId(482)]
+    );
+    assert_eq!(apps.get_app_build_id(), 1234);
+
+    steam_api::shutdown();
+}
+
+#[test]
+fn reports_how_the_app_is_owned() {
+    let me = SteamId::from(1);
+    let lender = SteamId::from(2);
+    let _guard = init(FakeBackend::new().with_user(me, "Player"));
+    let apps = steam_api::apps();
+    assert_eq!(apps.get_app_owner(), me);
+    assert!(!apps.is_subscribed_from_family_sharing());
+    assert_eq!(apps.get_timed_trial(), None);
+    steam_api::shutdown();
+    drop(_guard);
+
+    let _guard = init(
+        FakeBackend::new()
+            .with_user(me, "Player")
+            .with_family_sharing(lender)
+            .with_free_weekend()
+            .with_timed_trial(3600, 600)
+            .with_vac_ban(),
+    );
+    let apps = steam_api::apps();
+    assert_eq!(apps.get_app_owner(), lender);
+    assert!(apps.is_subscribed_from_family_sharing());
+    assert!(apps.is_subscribed_from_free_weekend());
+    assert_eq!(
+        apps.get_timed_trial(),
+        Some(TimedTrial {
+            allowed: Duration::from_secs(3600),
+            played: Duration::from_secs(600)
+        })
+    );
+    assert!(apps.is_vac_banned());
+
+    steam_api::shutdown();
+}
+
+#[test]
+fn launch_parameters_follow_url_launches() {
+    let backend = FakeBackend::new()
+        .with_launch_query_param("connect", "10.0.0.1:27015")
+        .with_launch_command_line(r#"+map "dust 2" -name \"Ace\" """#)
+        .with_process_args(["game", "-windowed"]);
+    let _guard = init(backend.clone());
+    let apps = steam_api::apps();
+
+    let relaunches = Arc::new(Mutex::new(0));
+    let _handle = {
+        let relaunches = relaunches.clone();
+        steam_api::register_callback(move |_: NewUrlLaunchParameters| {
+            *relaunches.lock().unwrap() += 1;
+        })
+    };
+
+    assert_eq!(
+        apps.get_launch_query_param("connect").as_deref(),
+        Some("10.0.0.1:27015")
+    );
+    assert_eq!(apps.get_launch_query_param("lobby"), None);
+    assert_eq!(
+        apps.get_launch_command_line(),
+        ["+map", "dust 2", "-name", "\"Ace\"", ""]
+    );
+
+    assert_eq!(
+        apps.get_launch_args(),
+        [
+            "game",
+            "-windowed",
+            "+map",
+            "dust 2",
+            "-name",
+            "\"Ace\"",
+            ""
+        ]
+    );
+
+    backend.queue_url_launch(&[("lobby", "109775241021923328")], "");
+    steam_api::run_callbacks();
+    assert_eq!(*relaunches.lock().unwrap(), 1);
+    assert_eq!(apps.get_launch_query_param("connect"), None);
+    assert_eq!(
+        apps.get_launch_query_param("lobby").as_deref(),
+        Some("109775241021923328")
+    );
+    assert!(apps.get_launch_command_line().is_empty());
+
+    steam_api::shutdown();
+}
+
+#[cfg(unix)]
+fn invalid_unicode(valid: &str) -> OsString {
+    use std::os::unix::ffi::OsStringExt;
+    let mut bytes = valid.as_bytes().to_vec();
+    bytes.push(0xff);
+    OsString::from_vec(bytes)
+}
+
+#[cfg(windows)]
+fn invalid_unicode(valid: &str) -> OsString {
+    use std::os::windows::ffi::OsStringExt;
+    // An unpaired surrogate
+    let mut wide: Vec<u16> = valid.encode_utf16().collect();
+    wide.push(0xd800);
+    OsString::from_wide(&wide)
+}
+
+#[test]
+fn launch_args_survive_invalid_unicode() {
+    let _guard = init(
+        FakeBackend::new()
+            .with_launch_command_line("+connect 10.0.0.1")
+            .with_process_args([OsString::from("game"), invalid_unicode("-name=")]),
+    );
+
+    assert_eq!(
+        steam_api::apps().get_launch_args(),
+        ["game", "-name=\u{fffd}", "+connect", "10.0.0.1"]
+    );
+
+    steam_api::shutdown();
+}
@@ -0,0 +1,93 @@
+use std::sync::{Arc, Mutex};
+
+use steamstacks::backend::FakeBackend;
+use steamstacks::callbacks::{GetAuthSessionTicketResponse, ValidateAuthTicketResponse};
+use steamstacks::result::SteamResult;
+use steamstacks::{steam_api, AuthSessionResponse, SteamId};
+
+mod common;
+
+use common::init;
+
+#[test]
+fn session_tickets_are_cancelled_when_dropped() {
+    let backend = FakeBackend::new();
+    let _guard = init(backend.clone());
+    let user = steam_api::user();
+
+    let ready = Arc::new(Mutex::new(Vec::new()));
+    let _handle = {
+        let ready = ready.clone();
+        steam_api::register_callback(move |response: GetAuthSessionTicketResponse| {
+            ready
+                .lock()
+                .unwrap()
+                .push((response.ticket, response.result));
+        })
+    };
+
+    let first = user.get_auth_session_ticket().unwrap();
+    let second = user.get_auth_session_ticket().unwrap();
+    assert!(!first.data().is_empty());
+    assert_ne!(first.handle(), second.handle());
+    steam_api::run_callbacks();
+    assert_eq!(
+        *ready.lock().unwrap(),
+        [
+            (first.handle(), SteamResult::Ok),
+            (second.handle(), SteamResult::Ok)
+        ]
+    );
+
+    let second_handle = second.handle();
+    drop(first);
+    assert_eq!(backend.auth_tickets(), [second_handle]);
+    drop(second);
+    assert!(backend.auth_tickets().is_empty());
+
+    steam_api::shutdown();
+}
+
+#[test]
+fn tickets_are_validated_against_their_owner() {
+    let me = SteamId::from(1);
+    let _guard = init(FakeBackend::new().with_user(me, "Player"));
+    let user = steam_api::user();
+
+    let responses = Arc::new(Mutex::new(Vec::new()));
+    let _handle = {
+        let responses = responses.clone();
+        steam_api::register_callback(move |response: ValidateAuthTicketResponse| {
+            responses
+                .lock()
+                .unwrap()
+                .push((response.steam_id, response.response));
+        })
+    };
+
+    let ticket = user.get_auth_session_ticket().unwrap();
+    user.begin_auth_session(ticket.data(), me).unwrap();
+    user.begin_auth_session(ticket.data(), SteamId::from(2))
+        .unwrap();
+    assert_eq!(
+        user.begin_auth_session(ticket.data(), me),
+        Err(SteamResult::DuplicateRequest)
+    );
+    assert_eq!(
+        user.begin_auth_session(&[], SteamId::from(3)),
+        Err(SteamResult::InvalidPassword)
+    );
+    steam_api::run_callbacks();
+    assert_eq!(
+        *responses.lock().unwrap(),
+        [
+            (me, AuthSessionResponse::Ok),
+            (SteamId::from(2), AuthSessionResponse::AuthTicketInvalid)
+        ]
+    );
+
+    user.end_auth_session(me);
+    user.begin_auth_session(ticket.data(), me).unwrap();
+
+    steam_api::shutdown();
+}
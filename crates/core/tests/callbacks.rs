@@ -0,0 +1,246 @@
+use std::sync::{Arc, Mutex};
+
+use steamstacks::backend::{FakeBackend, FakeServer};
+use steamstacks::callbacks::{
+    EncryptedAppTicketResponse, Event, GameOverlayActivated, SteamServersConnected,
+};
+use steamstacks::steam_api;
+use steamstacks_bindings as bindings;
+
+mod common;
+
+use common::{block_on, init};
+
+fn response() -> EncryptedAppTicketResponse {
+    EncryptedAppTicketResponse {
+        result: bindings::EResult::k_EResultOK,
+    }
+}
+
+fn listen(log: &Arc<Mutex<Vec<&'static str>>>, name: &'static str) -> steam_api::CallbackHandle {
+    let log = log.clone();
+    steam_api::register_callback(move |_: EncryptedAppTicketResponse| {
+        log.lock().unwrap().push(name);
+    })
+}
+
+#[test]
+fn every_listener_receives_the_callback() {
+    let fake = FakeBackend::new();
+    let _guard = init(fake.clone());
+
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let _ui = listen(&log, "ui");
+    let _telemetry = listen(&log, "telemetry");
+
+    fake.queue_callback(response());
+    steam_api::run_callbacks();
+    assert_eq!(*log.lock().unwrap(), ["ui", "telemetry"]);
+
+    steam_api::shutdown();
+}
+
+#[test]
+fn dropped_and_unregistered_listeners_stop_receiving() {
+    let fake = FakeBackend::new();
+    let _guard = init(fake.clone());
+
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let ui = listen(&log, "ui");
+    let telemetry = listen(&log, "telemetry");
+    let networking = listen(&log, "networking");
+
+    drop(ui);
+    fake.queue_callback(response());
+    steam_api::run_callbacks();
+    assert_eq!(*log.lock().unwrap(), ["telemetry", "networking"]);
+
+    telemetry.unregister();
+    fake.queue_callback(response());
+    steam_api::run_callbacks();
+    assert_eq!(
+        *log.lock().unwrap(),
+        ["telemetry", "networking", "networking"]
+    );
+
+    drop(networking);
+    steam_api::shutdown();
+}
+
+#[test]
+fn forgotten_listeners_stay_registered() {
+    let fake = FakeBackend::new();
+    let _guard = init(fake.clone());
+
+    let log = Arc::new(Mutex::new(Vec::new()));
+    listen(&log, "ui").forget();
+
+    fake.queue_callback(response());
+    steam_api::run_callbacks();
+    assert_eq!(*log.lock().unwrap(), ["ui"]);
+
+    steam_api::shutdown();
+}
+
+#[test]
+fn event_channel_receives_dispatched_events() {
+    let fake = FakeBackend::new();
+    let _guard = init(fake.clone());
+
+    let events = steam_api::event_channel();
+    fake.queue_callback(SteamServersConnected);
+    fake.queue_callback(response());
+    fake.queue_callback(GameOverlayActivated { active: true });
+    assert!(events.try_recv().is_err());
+
+    steam_api::run_callbacks();
+    let received: Vec<_> = events.try_iter().collect();
+    assert!(matches!(
+        received[..],
+        [
+            Event::SteamServersConnected(_),
+            Event::GameOverlayActivated(GameOverlayActivated { active: true })
+        ]
+    ));
+
+    steam_api::shutdown();
+}
+
+#[test]
+fn dropped_event_channels_are_ignored() {
+    let fake = FakeBackend::new();
+    let _guard = init(fake.clone());
+
+    let kept = steam_api::event_channel();
+    drop(steam_api::event_channel());
+
+    fake.queue_callback(SteamServersConnected);
+    steam_api::run_callbacks();
+    assert_eq!(kept.try_iter().count(), 1);
+
+    steam_api::shutdown();
+}
+
+#[test]
+fn handlers_can_register_listeners_and_make_api_calls() {
+    let fake = FakeBackend::new().with_encrypted_app_ticket(vec![1, 2, 3]);
+    let _guard = init(fake.clone());
+
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let handles = Arc::new(Mutex::new(Vec::new()));
+    let ticket = Arc::new(Mutex::new(None));
+
+    let _connected = {
+        let (log, handles, ticket) = (log.clone(), handles.clone(), ticket.clone());
+        steam_api::register_callback(move |_: SteamServersConnected| {
+            handles.lock().unwrap().push(listen(&log, "late"));
+            let request = steam_api::user().request_encrypted_app_ticket(&[]);
+            *ticket.lock().unwrap() = Some(Box::pin(request));
+        })
+    };
+
+    fake.queue_callback(SteamServersConnected);
+    steam_api::run_callbacks();
+
+    let request = ticket
+        .lock()
+        .unwrap()
+        .take()
+        .expect("handler made the call");
+    assert_eq!(block_on(request), Ok(()));
+
+    fake.queue_callback(response());
+    steam_api::run_callbacks();
+    assert_eq!(*log.lock().unwrap(), ["late"]);
+
+    handles.lock().unwrap().clear();
+    steam_api::shutdown();
+}
+
+#[test]
+fn handlers_can_unregister_listeners_mid_dispatch() {
+    let fake = FakeBackend::new();
+    let _guard = init(fake.clone());
+
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let later = Arc::new(Mutex::new(None));
+
+    let first = {
+        let (log, later) = (log.clone(), later.clone());
+        steam_api::register_callback(move |_: EncryptedAppTicketResponse| {
+            log.lock().unwrap().push("first");
+            // Dropping the later listener's handle must skip it for this dispatch too
+            later.lock().unwrap().take();
+        })
+    };
+    *later.lock().unwrap() = Some(listen(&log, "later"));
+
+    fake.queue_callback(response());
+    fake.queue_callback(response());
+    steam_api::run_callbacks();
+    assert_eq!(*log.lock().unwrap(), ["first", "first"]);
+
+    first.unregister();
+    steam_api::shutdown();
+}
+
+#[test]
+fn threads_take_turns_pumping_the_pipe() {
+    let fake = FakeBackend::new();
+    let _guard = init(fake.clone());
+
+    let received = Arc::new(Mutex::new(0));
+    let _handle = {
+        let received = received.clone();
+        steam_api::register_callback(move |_: EncryptedAppTicketResponse| {
+            *received.lock().unwrap() += 1;
+        })
+    };
+    for _ in 0..1000 {
+        fake.queue_callback(response());
+    }
+
+    std::thread::scope(|scope| {
+        for _ in 0..4 {
+            scope.spawn(|| {
+                for _ in 0..100 {
+                    steam_api::run_callbacks();
+                }
+            });
+        }
+    });
+    assert_eq!(*received.lock().unwrap(), 1000);
+    assert_eq!(fake.overlapping_pumps(), 0);
+
+    steam_api::shutdown();
+}
+
+#[test]
+fn client_handlers_can_run_server_callbacks() {
+    let fake = FakeBackend::new();
+    let _guard = init(fake.clone());
+    let server = steam_api::init_server_with(FakeServer::new()).unwrap();
+
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let _server_handle = {
+        let log = log.clone();
+        steam_api::register_server_callback(move |_: SteamServersConnected| {
+            log.lock().unwrap().push("server");
+        })
+    };
+    let _client_handle = {
+        let log = log.clone();
+        steam_api::register_callback(move |_: EncryptedAppTicketResponse| {
+            steam_api::run_callbacks();
+            log.lock().unwrap().push("client");
+        })
+    };
+
+    server.log_on_anonymous();
+    fake.queue_callback(response());
+    steam_api::run_callbacks();
+    assert_eq!(*log.lock().unwrap(), ["server", "client"]);
+
+    steam_api::shutdown_server();
+    steam_api::shutdown();
+}
@@ -0,0 +1,50 @@
+use std::future::Future;
+use std::pin::pin;
+use std::sync::{Mutex, MutexGuard};
+use std::task::{Context, Poll, Waker};
+use std::time::{Duration, Instant};
+
+use steamstacks::backend::{FakeBackend, FakeServer};
+use steamstacks::{steam_api, Server};
+
+// The api is a process wide singleton, so tests take turns initializing it
+static LOCK: Mutex<()> = Mutex::new(());
+
+pub fn init(backend: FakeBackend) -> MutexGuard<'static, ()> {
+    let guard = LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    steam_api::init_with(backend).unwrap();
+    guard
+}
+
+/// Like `init`, but starts a game server without the client api
+#[allow(dead_code)]
+pub fn init_server(backend: FakeServer) -> (MutexGuard<'static, ()>, Server) {
+    let guard = LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let server = steam_api::init_server_with(backend).unwrap();
+    (guard, server)
+}
+
+// The fake backend answers within a few pumps, anything near this is stuck
+const BLOCK_ON_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Polls a future to completion, pumping callbacks in between
+///
+/// Panics if the future is still pending after `BLOCK_ON_TIMEOUT`, so a result that
+/// never arrives fails the test instead of hanging it.
+#[allow(dead_code)]
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let mut cx = Context::from_waker(Waker::noop());
+    let started = Instant::now();
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+        assert!(
+            started.elapsed() < BLOCK_ON_TIMEOUT,
+            "future still pending after {:?}",
+            BLOCK_ON_TIMEOUT
+        );
+        steam_api::run_callbacks();
+    }
+}
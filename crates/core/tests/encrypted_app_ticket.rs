@@ -0,0 +1,90 @@
+#![cfg(feature = "encrypted-app-ticket")]
+
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use steamstacks::result::SteamResult;
+use steamstacks::{AccountType, AppId, DecryptedAppTicket, Universe, ENCRYPTED_APP_TICKET_KEY_LEN};
+
+// What `cargo run -p testing -- <path>` passes to `request_encrypted_app_ticket`
+const FIXTURE_USER_DATA: &[u8] = b"steamstacks fixture";
+
+// Encrypted app tickets were introduced long after this, a ticket claiming to be
+// older means the issue time was read from the wrong place
+const TICKETS_INTRODUCED: Duration = Duration::from_secs(1_262_304_000);
+
+fn fixture(name: &str) -> Vec<u8> {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+        .join(name);
+    std::fs::read(&path).unwrap_or_else(|e| {
+        panic!(
+            "missing fixture {}: {}, see tests/fixtures/README.md",
+            path.display(),
+            e
+        )
+    })
+}
+
+fn spacewar_key() -> [u8; ENCRYPTED_APP_TICKET_KEY_LEN] {
+    fixture("spacewar.key")
+        .try_into()
+        .expect("spacewar.key should hold the raw symmetric key")
+}
+
+#[test]
+fn decrypts_a_captured_spacewar_ticket() {
+    let ticket = DecryptedAppTicket::decrypt(&fixture("spacewar.ticket"), &spacewar_key()).unwrap();
+
+    assert!(ticket.is_for_app(AppId(480)));
+    assert!(!ticket.is_for_app(AppId(481)));
+    assert_eq!(ticket.app_id(), AppId(480));
+    assert_eq!(ticket.user_data(), FIXTURE_USER_DATA);
+
+    let issued = ticket.issue_time();
+    assert!(issued > UNIX_EPOCH + TICKETS_INTRODUCED);
+    assert!(issued < SystemTime::now());
+
+    let steam_id = ticket.steam_id();
+    assert!(steam_id.is_valid());
+    assert_eq!(steam_id.account_type(), AccountType::Individual);
+    assert_eq!(steam_id.universe(), Universe::Public);
+}
+
+#[test]
+fn rejects_a_tampered_ticket() {
+    let mut ticket = fixture("spacewar.ticket");
+    let last = ticket.len() - 1;
+    ticket[last] ^= 0xff;
+
+    assert_eq!(
+        DecryptedAppTicket::decrypt(&ticket, &spacewar_key()),
+        Err(SteamResult::EncryptionFailure)
+    );
+}
+
+#[test]
+fn rejects_a_ticket_with_the_wrong_key() {
+    let mut key = spacewar_key();
+    key[0] ^= 0xff;
+
+    assert_eq!(
+        DecryptedAppTicket::decrypt(&fixture("spacewar.ticket"), &key),
+        Err(SteamResult::EncryptionFailure)
+    );
+}
+
+#[test]
+fn rejects_tickets_that_do_not_decrypt() {
+    let key = [7; ENCRYPTED_APP_TICKET_KEY_LEN];
+
+    assert_eq!(
+        DecryptedAppTicket::decrypt(&[], &key),
+        Err(SteamResult::EncryptionFailure)
+    );
+    assert_eq!(
+        DecryptedAppTicket::decrypt(&[0x08, 0x01, 0x10, 0x02], &key),
+        Err(SteamResult::EncryptionFailure)
+    );
+}
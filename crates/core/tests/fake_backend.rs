@@ -0,0 +1,134 @@
+use std::sync::{Arc, Mutex};
+
+use steamstacks::backend::{FakeBackend, FakeFriend};
+use steamstacks::callbacks::EncryptedAppTicketResponse;
+use steamstacks::result::SteamResult;
+use steamstacks::{steam_api, AppId, FriendFlags, SteamId};
+use steamstacks_bindings as bindings;
+
+mod common;
+
+use common::{block_on, init};
+
+#[test]
+fn reports_configured_user_and_apps() {
+    let _guard = init(
+        FakeBackend::new()
+            .with_user(SteamId::from(76561197960287930), "Gabe")
+            .with_app_id(AppId(440))
+            .with_owned_app(AppId(570)),
+    );
+
+    assert_eq!(
+        steam_api::user().get_steam_id(),
+        SteamId::from(76561197960287930)
+    );
+    assert_eq!(steam_api::friends().get_persona_name(), "Gabe");
+    assert_eq!(steam_api::utils().get_app_id(), AppId(440));
+    assert!(steam_api::apps().is_subscribed());
+    assert!(steam_api::apps().is_subscribed_app(AppId(570)));
+    assert!(!steam_api::apps().is_subscribed_app(AppId(730)));
+
+    steam_api::shutdown();
+}
+
+#[test]
+fn filters_friends_by_flags() {
+    let mut blocked = FakeFriend::new(SteamId::from(3), "Troll");
+    blocked.flags = FriendFlags::BLOCKED;
+    let _guard = init(
+        FakeBackend::new()
+            .with_friend(FakeFriend::new(SteamId::from(1), "Alice"))
+            .with_friend(FakeFriend::new(SteamId::from(2), "Bob"))
+            .with_friend(blocked),
+    );
+
+    let friends = steam_api::friends().get_friends(FriendFlags::IMMEDIATE);
+    let names: Vec<_> = friends.iter().map(|f| f.get_persona_name()).collect();
+    assert_eq!(names, ["Alice", "Bob"]);
+    assert_eq!(friends[1].get_steam_id(), SteamId::from(2));
+
+    let blocked = steam_api::friends().get_friends(FriendFlags::BLOCKED);
+    assert_eq!(blocked.len(), 1);
+    assert_eq!(blocked[0].get_persona_name(), "Troll");
+
+    steam_api::shutdown();
+}
+
+#[test]
+fn dispatches_queued_callbacks() {
+    let fake = FakeBackend::new();
+    let _guard = init(fake.clone());
+
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let received_ref = received.clone();
+    let _handle = steam_api::register_callback(move |r: EncryptedAppTicketResponse| {
+        received_ref
+            .lock()
+            .unwrap()
+            .push(SteamResult::from(r.result));
+    });
+
+    fake.queue_callback(EncryptedAppTicketResponse {
+        result: bindings::EResult::k_EResultOK,
+    });
+    assert!(received.lock().unwrap().is_empty());
+
+    steam_api::run_callbacks();
+    assert_eq!(*received.lock().unwrap(), [SteamResult::Ok]);
+
+    steam_api::shutdown();
+}
+
+#[test]
+fn completes_encrypted_app_ticket_requests() {
+    let _guard = init(FakeBackend::new().with_encrypted_app_ticket([1, 2, 3]));
+
+    let user = steam_api::user();
+    assert_eq!(block_on(user.request_encrypted_app_ticket(&[])), Ok(()));
+    assert_eq!(user.get_encrypted_app_ticket(), Ok(vec![1, 2, 3]));
+
+    steam_api::shutdown();
+}
+
+#[test]
+fn encrypted_app_tickets_carry_user_data_and_grow_past_the_buffer() {
+    let ticket: Vec<u8> = (0..2000).map(|i| i as u8).collect();
+    let backend = FakeBackend::new().with_encrypted_app_ticket(ticket.clone());
+    let _guard = init(backend.clone());
+
+    let user = steam_api::user();
+    assert_eq!(
+        block_on(user.request_encrypted_app_ticket_async(b"login-nonce")),
+        Ok(ticket)
+    );
+    assert_eq!(backend.encrypted_app_ticket_data(), b"login-nonce");
+
+    steam_api::shutdown();
+}
+
+#[test]
+fn fails_encrypted_app_ticket_requests_without_a_ticket() {
+    let _guard = init(FakeBackend::new());
+
+    assert_eq!(
+        block_on(steam_api::user().request_encrypted_app_ticket(&[])),
+        Err(SteamResult::Generic)
+    );
+
+    steam_api::shutdown();
+}
+
+#[test]
+fn fails_call_results_that_cannot_be_decoded() {
+    for failed in [false, true] {
+        let backend = FakeBackend::new().with_encrypted_app_ticket([1, 2, 3]);
+        let _guard = init(backend.clone());
+
+        let request = steam_api::user().request_encrypted_app_ticket(&[]);
+        backend.truncate_queued_call_results(failed);
+        assert_eq!(block_on(request), Err(SteamResult::IOFailure));
+
+        steam_api::shutdown();
+    }
+}
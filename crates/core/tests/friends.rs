@@ -0,0 +1,220 @@
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+
+use steamstacks::backend::{FakeBackend, FakeFriend};
+use steamstacks::callbacks::{FriendRichPresenceUpdate, PersonaStateChange};
+use steamstacks::result::SteamResult;
+use steamstacks::{
+    steam_api, AppId, FriendGame, FriendRelationship, Image, ImageSize, PersonaChange,
+    PersonaState, SteamId, MAX_RICH_PRESENCE_KEYS,
+};
+
+mod common;
+
+use common::{block_on, init};
+
+#[test]
+fn rich_presence_is_set_and_read_back() {
+    let me = SteamId::from(1);
+    let friend = SteamId::from(2);
+    let backend = FakeBackend::new()
+        .with_user(me, "Player")
+        .with_friend(FakeFriend::new(friend, "Friend").with_rich_presence("status", "In menus"));
+    let _guard = init(backend.clone());
+    let friends = steam_api::friends();
+
+    let updates = Arc::new(Mutex::new(Vec::new()));
+    let _handle = {
+        let updates = updates.clone();
+        steam_api::register_callback(move |update: FriendRichPresenceUpdate| {
+            updates
+                .lock()
+                .unwrap()
+                .push((update.steam_id, update.app_id));
+        })
+    };
+
+    friends.set_rich_presence("status", "Playing CTF").unwrap();
+    friends
+        .set_rich_presence("steam_display", "#Status")
+        .unwrap();
+    friends.set_rich_presence("steam_display", "").unwrap();
+    let own = friends.get_friend(me);
+    assert_eq!(
+        own.get_rich_presence("status").as_deref(),
+        Some("Playing CTF")
+    );
+    assert_eq!(own.get_rich_presence_keys(), ["status"]);
+
+    assert_eq!(
+        friends.set_rich_presence(&"k".repeat(64), "v"),
+        Err(SteamResult::InvalidParameter)
+    );
+    assert_eq!(
+        friends.set_rich_presence("status", &"v".repeat(256)),
+        Err(SteamResult::InvalidParameter)
+    );
+    assert_eq!(
+        friends.set_rich_presence("sta\0tus", "v"),
+        Err(SteamResult::InvalidParameter)
+    );
+    assert_eq!(
+        friends.set_rich_presence("status", "Play\0ing"),
+        Err(SteamResult::InvalidParameter)
+    );
+    for idx in 1..MAX_RICH_PRESENCE_KEYS {
+        friends
+            .set_rich_presence(&format!("key{idx}"), "v")
+            .unwrap();
+    }
+    assert_eq!(
+        friends.set_rich_presence("one_too_many", "v"),
+        Err(SteamResult::LimitExceeded)
+    );
+    friends.clear_rich_presence();
+    assert!(own.get_rich_presence_keys().is_empty());
+
+    let other = friends.get_friend(friend);
+    assert_eq!(
+        other.get_rich_presence("status").as_deref(),
+        Some("In menus")
+    );
+    backend.queue_rich_presence_update(friend, "status", "In game");
+    other.request_rich_presence();
+    steam_api::run_callbacks();
+    assert_eq!(
+        other.get_rich_presence("status").as_deref(),
+        Some("In game")
+    );
+    assert_eq!(
+        *updates.lock().unwrap(),
+        [(friend, AppId(480)), (friend, AppId(480))]
+    );
+
+    steam_api::shutdown();
+}
+
+#[test]
+fn friend_state_is_reported_and_tracked() {
+    let friend = SteamId::from(2);
+    let stranger = SteamId::from(3);
+    let lobby = SteamId::from(0x0186_0000_0000_0001);
+    let mut fake = FakeFriend::new(friend, "Friend");
+    fake.nickname = Some("Buddy".to_owned());
+    fake.steam_level = 42;
+    let backend = FakeBackend::new().with_friend(fake.clone());
+    let _guard = init(backend.clone());
+    let friends = steam_api::friends();
+
+    let changes = Arc::new(Mutex::new(Vec::new()));
+    let _handle = {
+        let changes = changes.clone();
+        steam_api::register_callback(move |change: PersonaStateChange| {
+            changes
+                .lock()
+                .unwrap()
+                .push((change.steam_id, change.changes));
+        })
+    };
+
+    let handle = friends.get_friend(friend);
+    assert_eq!(handle.persona_state(), PersonaState::Online);
+    assert_eq!(handle.relationship(), FriendRelationship::Friend);
+    assert_eq!(handle.steam_level(), Some(42));
+    assert_eq!(handle.nickname().as_deref(), Some("Buddy"));
+    assert_eq!(handle.game_played(), None);
+
+    let game = FriendGame {
+        app_id: AppId(480),
+        server: Some(SocketAddr::from(([10, 0, 0, 1], 27015))),
+        query_port: 27016,
+        lobby: Some(lobby),
+    };
+    fake.persona_state = PersonaState::LookingToPlay;
+    fake.game = Some(game);
+    backend.queue_persona_change(fake, PersonaChange::STATUS | PersonaChange::GAME_PLAYED);
+
+    assert!(!friends.request_user_information(friend, false));
+    assert!(friends.request_user_information(stranger, true));
+    steam_api::run_callbacks();
+
+    assert_eq!(
+        *changes.lock().unwrap(),
+        [
+            (friend, PersonaChange::STATUS | PersonaChange::GAME_PLAYED),
+            (stranger, PersonaChange::NAME)
+        ]
+    );
+    assert_eq!(handle.persona_state(), PersonaState::LookingToPlay);
+    assert_eq!(handle.game_played(), Some(game));
+
+    let stranger = friends.get_friend(stranger);
+    assert_eq!(stranger.persona_state(), PersonaState::Offline);
+    assert_eq!(stranger.relationship(), FriendRelationship::None);
+    assert_eq!(stranger.steam_level(), None);
+
+    steam_api::shutdown();
+}
+
+fn checkerboard(size: u32) -> Image {
+    let rgba = (0..size * size)
+        .flat_map(|idx| {
+            let shade = if idx % 2 == 0 { 0 } else { 255 };
+            [shade, shade, shade, 255]
+        })
+        .collect();
+    Image {
+        width: size,
+        height: size,
+        rgba,
+    }
+}
+
+#[test]
+fn avatars_use_the_size_steam_reports() {
+    let friend = SteamId::from(2);
+    let stranger = SteamId::from(3);
+    // Not one of the `ImageSize`s, which used to panic
+    let avatar = checkerboard(48);
+    let _guard = init(FakeBackend::new().with_avatar(friend, avatar.clone()));
+    let friends = steam_api::friends();
+
+    assert_eq!(
+        friends.get_avatar(friend, ImageSize::LARGE),
+        Some(avatar.clone())
+    );
+    assert_eq!(friends.get_avatar(friend, ImageSize::SMALL), Some(avatar));
+    assert_eq!(friends.get_avatar(stranger, ImageSize::MEDIUM), None);
+
+    steam_api::shutdown();
+}
+
+#[test]
+fn requested_avatars_wait_for_the_download() {
+    let friend = SteamId::from(2);
+    let other = SteamId::from(3);
+    let backend = FakeBackend::new()
+        .with_loading_avatar(friend)
+        .with_loading_avatar(other);
+    let _guard = init(backend.clone());
+    let friends = steam_api::friends();
+
+    assert_eq!(friends.get_avatar(friend, ImageSize::LARGE), None);
+    let request = friends.request_avatar(friend, ImageSize::LARGE);
+
+    backend.queue_avatar_loaded(other, checkerboard(184));
+    steam_api::run_callbacks();
+    assert_eq!(friends.get_avatar(friend, ImageSize::LARGE), None);
+
+    let avatar = checkerboard(184);
+    backend.queue_avatar_loaded(friend, avatar.clone());
+    assert_eq!(block_on(request), Some(avatar));
+
+    let missing = SteamId::from(4);
+    assert_eq!(
+        block_on(friends.request_avatar(missing, ImageSize::SMALL)),
+        None
+    );
+
+    steam_api::shutdown();
+}
@@ -0,0 +1,180 @@
+use std::sync::{Arc, Mutex};
+
+use steamstacks::backend::{FakeBackend, FakeServer, FakeServerDetails};
+use steamstacks::callbacks::{
+    GSPolicyResponse, SteamServerConnectFailure, SteamServersConnected, ValidateAuthTicketResponse,
+};
+use steamstacks::result::SteamResult;
+use steamstacks::{steam_api, AuthSessionResponse, ServerMode, SteamId};
+
+mod common;
+
+use common::{init, init_server};
+
+#[test]
+fn logs_on_with_a_token() {
+    let backend = FakeServer::new()
+        .with_mode(ServerMode::AuthenticationAndSecure)
+        .with_login_token("valid");
+    let (_guard, server) = init_server(backend.clone());
+
+    let failures = Arc::new(Mutex::new(Vec::new()));
+    let _failure_handle = {
+        let failures = failures.clone();
+        steam_api::register_server_callback(move |failure: SteamServerConnectFailure| {
+            failures.lock().unwrap().push(failure.result);
+        })
+    };
+    let policies = Arc::new(Mutex::new(Vec::new()));
+    let _policy_handle = {
+        let policies = policies.clone();
+        steam_api::register_server_callback(move |policy: GSPolicyResponse| {
+            policies.lock().unwrap().push(policy.secure);
+        })
+    };
+
+    server.set_product("480");
+    server.set_game_description("Spacewar");
+    server.set_dedicated_server(true);
+    server.set_server_name("Test server");
+    server.set_map_name("dust");
+    server.set_max_players(16);
+    server.set_advertise_server_active(true);
+    assert_eq!(
+        backend.details(),
+        FakeServerDetails {
+            product: "480".to_owned(),
+            game_description: "Spacewar".to_owned(),
+            dedicated: true,
+            server_name: "Test server".to_owned(),
+            map_name: "dust".to_owned(),
+            max_players: 16,
+            advertised: true,
+        }
+    );
+
+    server.log_on("revoked");
+    steam_api::run_callbacks();
+    assert_eq!(*failures.lock().unwrap(), [SteamResult::GSLTDenied]);
+    assert!(!server.is_logged_on());
+
+    server.log_on("valid");
+    steam_api::run_callbacks();
+    assert_eq!(*policies.lock().unwrap(), [true]);
+    assert!(server.is_logged_on());
+    assert!(server.is_secure());
+    assert_ne!(server.steam_id(), SteamId::from(0));
+
+    server.log_off();
+    assert!(!server.is_logged_on());
+
+    steam_api::shutdown_server();
+}
+
+#[test]
+fn auth_sessions_are_tracked() {
+    let player = SteamId::from(2);
+    let _guard = init(FakeBackend::new().with_user(player, "Player"));
+    let backend = FakeServer::new();
+    let server = steam_api::init_server_with(backend.clone()).unwrap();
+
+    let responses = Arc::new(Mutex::new(Vec::new()));
+    let _handle = {
+        let responses = responses.clone();
+        steam_api::register_server_callback(move |response: ValidateAuthTicketResponse| {
+            responses
+                .lock()
+                .unwrap()
+                .push((response.steam_id, response.response));
+        })
+    };
+
+    server.log_on_anonymous();
+    assert!(!server.is_secure());
+
+    let ticket = steam_api::user().get_auth_session_ticket().unwrap();
+    server.begin_auth_session(ticket.data(), player).unwrap();
+    assert_eq!(
+        server.begin_auth_session(ticket.data(), player),
+        Err(SteamResult::DuplicateRequest)
+    );
+    assert_eq!(
+        server.begin_auth_session(b"garbage", SteamId::from(3)),
+        Err(SteamResult::InvalidPassword)
+    );
+    assert_eq!(backend.auth_sessions(), [player]);
+    steam_api::run_callbacks();
+    assert_eq!(
+        *responses.lock().unwrap(),
+        [(player, AuthSessionResponse::Ok)]
+    );
+
+    server.end_auth_session(player);
+    assert!(backend.auth_sessions().is_empty());
+
+    steam_api::shutdown_server();
+    steam_api::shutdown();
+}
+
+#[test]
+fn server_callbacks_are_kept_apart_from_the_client() {
+    let _guard = init(FakeBackend::new());
+    let server = steam_api::init_server_with(FakeServer::new()).unwrap();
+
+    let connected = Arc::new(Mutex::new(Vec::new()));
+    let _client_handle = {
+        let connected = connected.clone();
+        steam_api::register_callback(move |_: SteamServersConnected| {
+            connected.lock().unwrap().push("client");
+        })
+    };
+    let _server_handle = {
+        let connected = connected.clone();
+        steam_api::register_server_callback(move |_: SteamServersConnected| {
+            connected.lock().unwrap().push("server");
+        })
+    };
+
+    server.log_on_anonymous();
+    steam_api::run_callbacks();
+    assert_eq!(*connected.lock().unwrap(), ["server"]);
+
+    // The client can go away while the server keeps running
+    steam_api::shutdown();
+    server.log_off();
+    server.log_on_anonymous();
+    steam_api::run_callbacks();
+    assert_eq!(*connected.lock().unwrap(), ["server", "server"]);
+
+    steam_api::shutdown_server();
+}
+
+#[test]
+fn server_call_results_arrive_on_the_server_pipe() {
+    let _guard = init(FakeBackend::new());
+    let server = FakeServer::new();
+    let _server = steam_api::init_server_with(server.clone()).unwrap();
+
+    let results = Arc::new(Mutex::new(Vec::new()));
+    {
+        let results = results.clone();
+        steam_api::register_call_result(7, move |_: Option<&GSPolicyResponse>, _| {
+            results.lock().unwrap().push("client");
+        });
+    }
+    {
+        let results = results.clone();
+        steam_api::register_server_call_result(7, move |r: Option<&GSPolicyResponse>, failed| {
+            assert!(!failed);
+            assert!(r.unwrap().secure);
+            results.lock().unwrap().push("server");
+        });
+    }
+
+    server.queue_call_result(7, GSPolicyResponse { secure: true }, false);
+    steam_api::run_callbacks();
+    assert_eq!(*results.lock().unwrap(), ["server"]);
+
+    steam_api::shutdown_server();
+    steam_api::shutdown();
+}
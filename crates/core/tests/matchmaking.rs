@@ -0,0 +1,202 @@
+use std::sync::{Arc, Mutex};
+
+use steamstacks::backend::{FakeBackend, FakeLobby};
+use steamstacks::callbacks::{LobbyChatMsg, LobbyChatUpdate, LobbyDataUpdate};
+use steamstacks::result::SteamResult;
+use steamstacks::{
+    steam_api, ChatEntryType, ChatMemberStateChange, ChatRoomEnterResponse, LobbyComparison,
+    LobbyType, SteamId,
+};
+
+mod common;
+
+use common::{block_on, init};
+
+#[test]
+fn created_lobbies_share_data_and_chat() {
+    let me = SteamId::from(1);
+    let _guard = init(FakeBackend::new().with_user(me, "Player"));
+    let matchmaking = steam_api::matchmaking();
+
+    let updates = Arc::new(Mutex::new(Vec::new()));
+    let _data_handle = {
+        let updates = updates.clone();
+        steam_api::register_callback(move |update: LobbyDataUpdate| {
+            updates.lock().unwrap().push(update.member);
+        })
+    };
+    let chat = Arc::new(Mutex::new(Vec::new()));
+    let _chat_handle = {
+        let chat = chat.clone();
+        steam_api::register_callback(move |message: LobbyChatMsg| {
+            chat.lock().unwrap().push(message);
+        })
+    };
+
+    let lobby = block_on(matchmaking.create_lobby(LobbyType::Public, 8)).unwrap();
+    assert_eq!(matchmaking.lobby_owner(lobby), me);
+    assert_eq!(matchmaking.lobby_members(lobby), [me]);
+    assert_eq!(matchmaking.lobby_member_limit(lobby), Some(8));
+
+    matchmaking.set_lobby_data(lobby, "map", "dust").unwrap();
+    matchmaking.set_lobby_data(lobby, "mode", "ctf").unwrap();
+    matchmaking.delete_lobby_data(lobby, "mode").unwrap();
+    assert_eq!(
+        matchmaking.lobby_data(lobby, "map").as_deref(),
+        Some("dust")
+    );
+    assert_eq!(matchmaking.lobby_data(lobby, "mode"), None);
+    assert_eq!(
+        matchmaking.all_lobby_data(lobby),
+        [("map".to_owned(), "dust".to_owned())]
+    );
+
+    matchmaking.set_lobby_member_data(lobby, "ready", "1");
+    assert_eq!(
+        matchmaking.lobby_member_data(lobby, me, "ready").as_deref(),
+        Some("1")
+    );
+
+    matchmaking
+        .send_lobby_chat_message(lobby, b"gl hf")
+        .unwrap();
+    assert_eq!(
+        matchmaking.send_lobby_chat_message(lobby, &[0; 4097]),
+        Err(SteamResult::InvalidParameter)
+    );
+    steam_api::run_callbacks();
+
+    assert_eq!(*updates.lock().unwrap(), [lobby, lobby, lobby, me]);
+    let chat = chat.lock().unwrap();
+    assert_eq!(chat.len(), 1);
+    assert_eq!(chat[0].user, me);
+    assert_eq!(chat[0].entry_type, ChatEntryType::ChatMsg);
+    let entry = matchmaking
+        .lobby_chat_entry(lobby, chat[0].chat_id)
+        .unwrap();
+    assert_eq!(entry.sender, me);
+    assert_eq!(entry.data, b"gl hf");
+
+    steam_api::shutdown();
+}
+
+#[test]
+fn lobby_lists_are_filtered_and_sorted() {
+    let host = SteamId::from(2);
+    let lobby = |id: u64, mode: &str, skill: &str| {
+        FakeLobby::new(SteamId::from(id), host)
+            .with_data("mode", mode)
+            .with_data("skill", skill)
+    };
+    let mut full = lobby(13, "ctf", "1000");
+    full.max_members = 1;
+    let mut private = lobby(14, "ctf", "1000");
+    private.lobby_type = LobbyType::Private;
+
+    let _guard = init(
+        FakeBackend::new()
+            .with_user(SteamId::from(1), "Player")
+            .with_lobby(lobby(10, "ctf", "1500"))
+            .with_lobby(lobby(11, "ctf", "1100"))
+            .with_lobby(lobby(12, "dm", "1000"))
+            .with_lobby(full)
+            .with_lobby(private),
+    );
+    let matchmaking = steam_api::matchmaking();
+
+    let lobbies = block_on(
+        matchmaking
+            .request_lobby_list()
+            .string_filter("mode", "ctf", LobbyComparison::Equal)
+            .numerical_filter("skill", 2000, LobbyComparison::LessThan)
+            .near_filter("skill", 1000)
+            .slots_available(1)
+            .send(),
+    )
+    .unwrap();
+    assert_eq!(lobbies, [SteamId::from(11), SteamId::from(10)]);
+
+    let limited = block_on(matchmaking.request_lobby_list().max_results(1).send()).unwrap();
+    assert_eq!(limited, [SteamId::from(10)]);
+
+    let entered = block_on(matchmaking.join_lobby(SteamId::from(11))).unwrap();
+    assert_eq!(entered.response, ChatRoomEnterResponse::Success);
+    assert_eq!(
+        matchmaking.lobby_members(SteamId::from(11)),
+        [host, SteamId::from(1)]
+    );
+    assert_eq!(
+        matchmaking.set_lobby_data(SteamId::from(11), "mode", "dm"),
+        Err(SteamResult::Generic)
+    );
+
+    let responses: Vec<_> = [13, 14, 99]
+        .into_iter()
+        .map(|id| {
+            block_on(matchmaking.join_lobby(SteamId::from(id)))
+                .unwrap()
+                .response
+        })
+        .collect();
+    assert_eq!(
+        responses,
+        [
+            ChatRoomEnterResponse::Full,
+            ChatRoomEnterResponse::NotAllowed,
+            ChatRoomEnterResponse::DoesntExist
+        ]
+    );
+
+    steam_api::shutdown();
+}
+
+#[test]
+fn members_coming_and_going_are_reported() {
+    let me = SteamId::from(1);
+    let friend = SteamId::from(2);
+    let backend = FakeBackend::new().with_user(me, "Player");
+    let _guard = init(backend.clone());
+    let matchmaking = steam_api::matchmaking();
+
+    let changes = Arc::new(Mutex::new(Vec::new()));
+    let _handle = {
+        let changes = changes.clone();
+        steam_api::register_callback(move |update: LobbyChatUpdate| {
+            changes
+                .lock()
+                .unwrap()
+                .push((update.user_changed, update.state_change));
+        })
+    };
+
+    let lobby = block_on(matchmaking.create_lobby(LobbyType::FriendsOnly, 2)).unwrap();
+    backend.queue_lobby_chat_update(lobby, friend, ChatMemberStateChange::ENTERED);
+    backend.queue_lobby_chat_message(lobby, friend, "hey");
+    steam_api::run_callbacks();
+    assert_eq!(matchmaking.lobby_members(lobby), [me, friend]);
+    assert_eq!(
+        matchmaking.lobby_chat_entry(lobby, 0).unwrap().sender,
+        friend
+    );
+
+    matchmaking.set_lobby_owner(lobby, friend).unwrap();
+    assert_eq!(matchmaking.lobby_owner(lobby), friend);
+    assert!(matchmaking.set_lobby_joinable(lobby, false).is_err());
+
+    backend.queue_lobby_chat_update(lobby, friend, ChatMemberStateChange::LEFT);
+    steam_api::run_callbacks();
+    assert_eq!(
+        *changes.lock().unwrap(),
+        [
+            (friend, ChatMemberStateChange::ENTERED),
+            (friend, ChatMemberStateChange::LEFT)
+        ]
+    );
+    assert_eq!(matchmaking.lobby_members(lobby), [me]);
+    assert_eq!(matchmaking.lobby_owner(lobby), me);
+
+    matchmaking.leave_lobby(lobby);
+    assert!(matchmaking.lobby_members(lobby).is_empty());
+
+    steam_api::shutdown();
+}
@@ -0,0 +1,120 @@
+use std::sync::{Arc, Mutex};
+
+use steamstacks::backend::FakeBackend;
+use steamstacks::callbacks::{NetworkingMessagesSessionFailed, NetworkingMessagesSessionRequest};
+use steamstacks::result::SteamResult;
+use steamstacks::{steam_api, NetworkingConnectionState, NetworkingIdentity, SendFlags, SteamId};
+
+mod common;
+
+use common::init;
+
+#[test]
+fn messages_arrive_once_the_session_is_accepted() {
+    let backend = FakeBackend::new().with_user(SteamId::from(1), "Player");
+    let _guard = init(backend.clone());
+    let messages = steam_api::networking_messages();
+    let friend = NetworkingIdentity::from(SteamId::from(2));
+
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let _handle = {
+        let requests = requests.clone();
+        steam_api::register_callback(move |request: NetworkingMessagesSessionRequest| {
+            requests.lock().unwrap().push(request.remote_identity);
+        })
+    };
+
+    backend.queue_networking_message(friend.clone(), 3, b"hi".to_vec());
+    backend.queue_networking_message(friend.clone(), 4, b"other channel".to_vec());
+    steam_api::run_callbacks();
+    assert_eq!(requests.lock().unwrap().len(), 1);
+    assert_eq!(requests.lock().unwrap()[0], friend);
+    assert!(messages.receive_messages_on_channel(3, 10).is_empty());
+    assert_eq!(
+        messages.session_info(&friend).unwrap().state,
+        NetworkingConnectionState::Connecting
+    );
+
+    messages.accept_session(&friend).unwrap();
+    assert_eq!(
+        messages.accept_session(&friend),
+        Err(SteamResult::NoConnection)
+    );
+    let received = messages.receive_messages_on_channel(3, 10);
+    assert_eq!(received.len(), 1);
+    assert_eq!(received[0].data(), b"hi");
+    assert_eq!(*received[0].peer(), friend);
+    assert_eq!(received[0].channel(), 3);
+    assert_eq!(received[0].connection(), None);
+
+    messages
+        .send_message_to_user(&friend, 3, b"hello back", SendFlags::RELIABLE)
+        .unwrap();
+    assert_eq!(
+        backend.take_sent_networking_messages(),
+        [(friend.clone(), 3, b"hello back".to_vec())]
+    );
+
+    messages.close_session(&friend).unwrap();
+    assert!(messages.session_info(&friend).is_none());
+    assert!(messages.receive_messages_on_channel(4, 10).is_empty());
+
+    steam_api::shutdown();
+}
+
+#[test]
+fn messages_to_ourselves_loop_back() {
+    let _guard = init(FakeBackend::new().with_user(SteamId::from(1), "Player"));
+    let messages = steam_api::networking_messages();
+    let me = NetworkingIdentity::from(SteamId::from(1));
+
+    messages
+        .send_message_to_user(&me, 0, b"echo", SendFlags::UNRELIABLE)
+        .unwrap();
+    assert!(messages.receive_messages_on_channel(1, 10).is_empty());
+    let received = messages.receive_messages_on_channel(0, 10);
+    assert_eq!(received[0].data(), b"echo");
+    assert!(!received[0].is_reliable());
+
+    messages.close_channel(&me, 0).unwrap();
+    assert!(messages.session_info(&me).is_none());
+    assert_eq!(
+        messages.send_message_to_user(&NetworkingIdentity::Invalid, 0, b"", SendFlags::RELIABLE),
+        Err(SteamResult::InvalidParameter)
+    );
+
+    steam_api::shutdown();
+}
+
+#[test]
+fn failed_sessions_are_reported() {
+    let backend = FakeBackend::new();
+    let _guard = init(backend.clone());
+    let messages = steam_api::networking_messages();
+    let friend = NetworkingIdentity::from(SteamId::from(2));
+
+    let failures = Arc::new(Mutex::new(Vec::new()));
+    let _handle = {
+        let failures = failures.clone();
+        steam_api::register_callback(move |failed: NetworkingMessagesSessionFailed| {
+            failures.lock().unwrap().push(failed.info);
+        })
+    };
+
+    messages
+        .send_message_to_user(&friend, 0, b"anyone there?", SendFlags::RELIABLE)
+        .unwrap();
+    backend.fail_networking_session(&friend);
+    steam_api::run_callbacks();
+
+    let failures = failures.lock().unwrap();
+    assert_eq!(failures.len(), 1);
+    assert_eq!(failures[0].remote_identity, friend);
+    assert_eq!(
+        failures[0].state,
+        NetworkingConnectionState::ProblemDetectedLocally
+    );
+    assert!(messages.session_info(&friend).is_none());
+
+    steam_api::shutdown();
+}
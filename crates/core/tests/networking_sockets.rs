@@ -0,0 +1,214 @@
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+
+use steamstacks::backend::FakeBackend;
+use steamstacks::callbacks::NetConnectionStatusChanged;
+use steamstacks::result::SteamResult;
+use steamstacks::steam_api::CallbackHandle;
+use steamstacks::{
+    steam_api, ConnectionHandle, NetworkingConnectionState, NetworkingIdentity, NetworkingSockets,
+    SendFlags, SteamId,
+};
+
+mod common;
+
+use common::init;
+
+type Events = Arc<Mutex<Vec<NetConnectionStatusChanged>>>;
+
+fn record_status_changes() -> (Events, CallbackHandle) {
+    let events = Events::default();
+    let handle = {
+        let events = events.clone();
+        steam_api::register_callback(move |event: NetConnectionStatusChanged| {
+            events.lock().unwrap().push(event);
+        })
+    };
+    (events, handle)
+}
+
+/// Accepts every incoming connection reported since the last call
+fn accept_incoming(
+    sockets: &NetworkingSockets,
+    events: &Events,
+) -> Vec<steamstacks::NetConnection> {
+    steam_api::run_callbacks();
+    events
+        .lock()
+        .unwrap()
+        .drain(..)
+        .filter(|event| {
+            event.info.listen_socket.is_some()
+                && event.info.state == NetworkingConnectionState::Connecting
+        })
+        .map(|event| sockets.accept_connection(event.connection).unwrap())
+        .collect()
+}
+
+/// The latest incoming connection reported since the last call
+fn incoming_connection(events: &Events) -> ConnectionHandle {
+    steam_api::run_callbacks();
+    events
+        .lock()
+        .unwrap()
+        .drain(..)
+        .rfind(|event| event.info.listen_socket.is_some())
+        .unwrap()
+        .connection
+}
+
+#[test]
+fn connects_over_ip_and_exchanges_messages() {
+    let _guard = init(FakeBackend::new().with_user(SteamId::from(1), "Player"));
+    let sockets = steam_api::networking_sockets();
+    let (events, _handle) = record_status_changes();
+
+    let bound: SocketAddr = "0.0.0.0:27020".parse().unwrap();
+    let listen = sockets.create_listen_socket_ip(bound).unwrap();
+    assert_eq!(listen.address(), Some(bound));
+    assert!(sockets.create_listen_socket_ip(bound).is_err());
+
+    let client = sockets
+        .connect_by_ip_address("127.0.0.1:27020".parse().unwrap())
+        .unwrap();
+    assert_eq!(
+        client.send_message(b"too early", SendFlags::RELIABLE),
+        Err(SteamResult::InvalidState)
+    );
+
+    let server = accept_incoming(&sockets, &events).remove(0);
+    assert_eq!(server.info().unwrap().listen_socket, Some(listen.handle()));
+    assert_eq!(
+        client.info().unwrap().state,
+        NetworkingConnectionState::Connected
+    );
+
+    assert_eq!(client.send_message(b"hello", SendFlags::RELIABLE), Ok(1));
+    assert_eq!(client.send_message(b"world", SendFlags::UNRELIABLE), Ok(2));
+    client.flush_messages().unwrap();
+
+    let messages = server.receive_messages(1);
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0].data(), b"hello");
+    assert!(messages[0].is_reliable());
+    assert_eq!(messages[0].connection(), Some(server.handle()));
+    assert_eq!(
+        *messages[0].peer(),
+        NetworkingIdentity::SteamId(SteamId::from(1))
+    );
+
+    let messages = server.receive_messages(10);
+    assert_eq!(messages[0].data(), b"world");
+    assert!(!messages[0].is_reliable());
+    assert_eq!(messages[0].message_number(), 2);
+    assert!(server.receive_messages(10).is_empty());
+
+    steam_api::shutdown();
+}
+
+#[test]
+fn poll_groups_receive_from_every_member() {
+    let _guard = init(FakeBackend::new().with_user(SteamId::from(1), "Player"));
+    let sockets = steam_api::networking_sockets();
+    let (events, _handle) = record_status_changes();
+    let me = NetworkingIdentity::from(SteamId::from(1));
+
+    let _listen = sockets.create_listen_socket_p2p(7).unwrap();
+    let first = sockets.connect_p2p(&me, 7).unwrap();
+    let second = sockets.connect_p2p(&me, 7).unwrap();
+
+    let group = sockets.create_poll_group().unwrap();
+    let accepted = accept_incoming(&sockets, &events);
+    assert_eq!(accepted.len(), 2);
+    for connection in &accepted {
+        connection.set_poll_group(&group).unwrap();
+    }
+
+    first.send_message(b"one", SendFlags::RELIABLE).unwrap();
+    second.send_message(b"two", SendFlags::RELIABLE).unwrap();
+
+    let mut received: Vec<Vec<u8>> = group
+        .receive_messages(10)
+        .iter()
+        .map(|message| message.data().to_vec())
+        .collect();
+    received.sort();
+    assert_eq!(received, [b"one".to_vec(), b"two".to_vec()]);
+    assert!(accepted[0].receive_messages(10).is_empty());
+
+    steam_api::shutdown();
+}
+
+#[test]
+fn closing_a_connection_notifies_the_peer() {
+    let _guard = init(FakeBackend::new().with_user(SteamId::from(1), "Player"));
+    let sockets = steam_api::networking_sockets();
+    let (events, _handle) = record_status_changes();
+
+    let _listen = sockets.create_listen_socket_p2p(0).unwrap();
+    let client = sockets.connect_p2p(&SteamId::from(1).into(), 0).unwrap();
+    let server = accept_incoming(&sockets, &events).remove(0);
+
+    server.close(1001, "kicked", false).unwrap();
+    steam_api::run_callbacks();
+    let closed = events.lock().unwrap().pop().unwrap();
+    assert_eq!(closed.connection, client.handle());
+    assert_eq!(closed.old_state, NetworkingConnectionState::Connected);
+    assert_eq!(closed.info.state, NetworkingConnectionState::ClosedByPeer);
+    assert_eq!(closed.info.end_reason, 1001);
+    assert_eq!(closed.info.end_debug, "kicked");
+
+    // Nobody is listening on this port
+    let lost = sockets.connect_p2p(&SteamId::from(1).into(), 5).unwrap();
+    steam_api::run_callbacks();
+    assert_eq!(
+        lost.info().unwrap().state,
+        NetworkingConnectionState::ProblemDetectedLocally
+    );
+
+    steam_api::shutdown();
+}
+
+#[test]
+fn incoming_connections_have_a_single_owner() {
+    let _guard = init(FakeBackend::new().with_user(SteamId::from(1), "Player"));
+    let sockets = steam_api::networking_sockets();
+    let (events, _handle) = record_status_changes();
+
+    let _listen = sockets.create_listen_socket_p2p(0).unwrap();
+    let client = sockets.connect_p2p(&SteamId::from(1).into(), 0).unwrap();
+    let incoming = incoming_connection(&events);
+
+    let server = sockets.accept_connection(incoming).unwrap();
+    assert_eq!(
+        sockets.accept_connection(incoming).err(),
+        Some(SteamResult::InvalidState)
+    );
+    assert_eq!(
+        sockets.reject_connection(incoming, 0, ""),
+        Err(SteamResult::InvalidState)
+    );
+    assert_eq!(
+        sockets.accept_connection(client.handle()).err(),
+        Some(SteamResult::InvalidState)
+    );
+    assert_eq!(
+        sockets.connection_info(incoming).unwrap().state,
+        NetworkingConnectionState::Connected
+    );
+    assert_eq!(
+        server.info().unwrap().state,
+        NetworkingConnectionState::Connected
+    );
+
+    let rejected = sockets.connect_p2p(&SteamId::from(1).into(), 0).unwrap();
+    let incoming = incoming_connection(&events);
+    sockets.reject_connection(incoming, 1002, "full").unwrap();
+    assert_eq!(sockets.connection_info(incoming), None);
+    steam_api::run_callbacks();
+    let info = rejected.info().unwrap();
+    assert_eq!(info.state, NetworkingConnectionState::ClosedByPeer);
+    assert_eq!(info.end_reason, 1002);
+
+    steam_api::shutdown();
+}
@@ -0,0 +1,124 @@
+use std::io::{Read, Write};
+use std::time::{Duration, SystemTime};
+
+use steamstacks::backend::FakeBackend;
+use steamstacks::result::SteamResult;
+use steamstacks::{steam_api, CloudFile, Quota};
+
+mod common;
+
+use common::init;
+
+#[test]
+fn writes_reads_and_deletes_files() {
+    let _guard = init(FakeBackend::new());
+    let storage = steam_api::remote_storage();
+
+    assert!(!storage.file_exists("save.dat"));
+    assert_eq!(
+        storage.file_read("save.dat"),
+        Err(SteamResult::FileNotFound)
+    );
+
+    storage.file_write("save.dat", b"level 3").unwrap();
+    assert!(storage.file_exists("save.dat"));
+    assert_eq!(storage.file_read("save.dat").unwrap(), b"level 3");
+    assert_eq!(storage.file_size("save.dat"), Ok(7));
+
+    let age = SystemTime::now()
+        .duration_since(storage.file_timestamp("save.dat").unwrap())
+        .unwrap();
+    assert!(age < Duration::from_secs(60));
+
+    storage.file_delete("save.dat").unwrap();
+    assert!(!storage.file_exists("save.dat"));
+    assert_eq!(
+        storage.file_delete("save.dat"),
+        Err(SteamResult::FileNotFound)
+    );
+
+    steam_api::shutdown();
+}
+
+#[test]
+fn enumerates_files_and_tracks_quota() {
+    let _guard = init(
+        FakeBackend::new()
+            .with_cloud_quota(16)
+            .with_cloud_file("a.sav", vec![0; 4])
+            .with_cloud_file("b.sav", vec![0; 6]),
+    );
+    let storage = steam_api::remote_storage();
+
+    assert_eq!(
+        storage.files(),
+        [
+            CloudFile {
+                name: "a.sav".into(),
+                size: 4
+            },
+            CloudFile {
+                name: "b.sav".into(),
+                size: 6
+            },
+        ]
+    );
+    assert_eq!(
+        storage.quota(),
+        Ok(Quota {
+            total: 16,
+            available: 6
+        })
+    );
+
+    assert!(storage.file_write("c.sav", &[0; 7]).is_err());
+    storage.file_write("a.sav", &[0; 10]).unwrap();
+    assert_eq!(storage.quota().unwrap().available, 0);
+
+    steam_api::shutdown();
+}
+
+#[test]
+fn streams_files_through_io_traits() {
+    let _guard = init(FakeBackend::new().with_cloud_file("world.sav", b"old"));
+    let storage = steam_api::remote_storage();
+
+    let mut stream = storage.file_write_stream("world.sav").unwrap();
+    stream.write_all(b"chunk one, ").unwrap();
+    write!(stream, "chunk {}", 2).unwrap();
+    assert_eq!(storage.file_read("world.sav").unwrap(), b"old");
+    stream.close().unwrap();
+
+    let mut contents = String::new();
+    storage
+        .buffered_file_reader("world.sav")
+        .unwrap()
+        .read_to_string(&mut contents)
+        .unwrap();
+    assert_eq!(contents, "chunk one, chunk 2");
+
+    let mut cancelled = storage.file_write_stream("world.sav").unwrap();
+    cancelled.write_all(b"discarded").unwrap();
+    drop(cancelled);
+    assert_eq!(
+        storage.file_read("world.sav").unwrap(),
+        b"chunk one, chunk 2"
+    );
+
+    steam_api::shutdown();
+}
+
+#[test]
+fn streams_split_writes_past_the_chunk_limit() {
+    let _guard = init(FakeBackend::new().with_cloud_quota(300 * 1024 * 1024));
+    let storage = steam_api::remote_storage();
+
+    let mut stream = storage.file_write_stream("huge.sav").unwrap();
+    assert_eq!(stream.write(&[]).unwrap(), 0);
+    let data = vec![7; 100 * 1024 * 1024 + 10];
+    assert_eq!(stream.write(&data).unwrap(), data.len());
+    stream.close().unwrap();
+    assert_eq!(storage.file_read("huge.sav").unwrap().len(), data.len());
+
+    steam_api::shutdown();
+}
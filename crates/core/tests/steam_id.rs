@@ -0,0 +1,114 @@
+use steamstacks::{AccountType, ParseSteamIdError, SteamId, Universe};
+
+// Account 1234 on the public universe, a user logged in on the desktop client
+const USER: u64 = 76561197960266962;
+
+#[test]
+fn ids_are_split_into_their_parts() {
+    let id = SteamId::from(USER);
+    assert_eq!(id.account_id(), 1234);
+    assert_eq!(id.instance(), 1);
+    assert_eq!(id.account_type(), AccountType::Individual);
+    assert_eq!(id.universe(), Universe::Public);
+    assert!(id.is_valid());
+    assert_eq!(id.raw(), USER);
+    assert_eq!(u64::from(id), USER);
+
+    assert_eq!(SteamId::from_account_id(1234), id);
+    assert_eq!(
+        SteamId::from_parts(1234, Universe::Public, AccountType::Individual),
+        id
+    );
+    assert_eq!(
+        SteamId::new(1234, 1, AccountType::Individual, Universe::Public),
+        id
+    );
+
+    let clan = SteamId::from_parts(4, Universe::Public, AccountType::Clan);
+    assert_eq!(clan.raw(), 103582791429521412);
+    assert_eq!(clan.instance(), 0);
+    assert!(clan.is_valid());
+}
+
+#[test]
+fn invalid_ids_are_detected() {
+    assert!(!SteamId::from(0).is_valid());
+    assert!(!SteamId::from_account_id(0).is_valid());
+    assert!(!SteamId::from_parts(1234, Universe::Invalid, AccountType::Individual).is_valid());
+    assert!(!SteamId::new(1234, 5, AccountType::Individual, Universe::Public).is_valid());
+    assert!(!SteamId::new(4, 1, AccountType::Clan, Universe::Public).is_valid());
+    assert!(!SteamId::from_parts(0, Universe::Public, AccountType::GameServer).is_valid());
+    assert!(SteamId::from_parts(0, Universe::Public, AccountType::AnonGameServer).is_valid());
+}
+
+#[test]
+fn ids_are_formatted_in_every_form() {
+    let id = SteamId::from(USER);
+    assert_eq!(id.to_string(), "76561197960266962");
+    assert_eq!(id.steam2().to_string(), "STEAM_1:0:617");
+    assert_eq!(id.steam3().to_string(), "[U:1:1234]");
+    assert_eq!(
+        id.community_url(),
+        "https://steamcommunity.com/profiles/76561197960266962"
+    );
+
+    let web = SteamId::new(1234, 4, AccountType::Individual, Universe::Public);
+    assert_eq!(web.steam3().to_string(), "[U:1:1234:4]");
+    let anon = SteamId::new(7, 3, AccountType::AnonGameServer, Universe::Public);
+    assert_eq!(anon.steam3().to_string(), "[A:1:7:3]");
+    let clan = SteamId::from_parts(4, Universe::Public, AccountType::Clan);
+    assert_eq!(clan.steam3().to_string(), "[g:1:4]");
+    let lobby = SteamId::new(99, 0x40000, AccountType::Chat, Universe::Public);
+    assert_eq!(lobby.steam3().to_string(), "[L:1:99]");
+}
+
+#[test]
+fn ids_are_parsed_from_every_form() {
+    let id = SteamId::from(USER);
+    for text in [
+        "76561197960266962",
+        "STEAM_0:0:617",
+        "STEAM_1:0:617",
+        "[U:1:1234]",
+        "U:1:1234",
+        "https://steamcommunity.com/profiles/76561197960266962",
+        "http://www.steamcommunity.com/profiles/76561197960266962/",
+        " steamcommunity.com/profiles/76561197960266962 ",
+    ] {
+        assert_eq!(text.parse::<SteamId>(), Ok(id), "{text}");
+    }
+
+    for id in [
+        SteamId::new(1234, 4, AccountType::Individual, Universe::Public),
+        SteamId::new(7, 3, AccountType::AnonGameServer, Universe::Public),
+        SteamId::from_parts(4, Universe::Public, AccountType::Clan),
+        SteamId::new(99, 0x40000, AccountType::Chat, Universe::Public),
+        SteamId::new(99, 0x80000, AccountType::Chat, Universe::Beta),
+    ] {
+        assert_eq!(id.steam3().to_string().parse::<SteamId>(), Ok(id));
+    }
+    assert_eq!(
+        "STEAM_1:1:617".parse::<SteamId>().unwrap().account_id(),
+        1235
+    );
+}
+
+#[test]
+fn malformed_ids_are_rejected() {
+    for text in [
+        "",
+        "gaben",
+        "STEAM_1:2:617",
+        "STEAM_1:0",
+        "STEAM_1:0:617:1",
+        "STEAM_1:0:4294967295",
+        "[X:1:1234]",
+        "[U:1]",
+        "[U:1:1234:1:1]",
+        "[UU:1:1234]",
+        "https://steamcommunity.com/id/gaben",
+        "https://example.com/profiles/76561197960266962",
+    ] {
+        assert_eq!(text.parse::<SteamId>(), Err(ParseSteamIdError), "{text}");
+    }
+}
@@ -0,0 +1,206 @@
+use std::sync::{Arc, Mutex};
+
+use steamstacks::backend::{FakeBackend, FakeWorkshopItem};
+use steamstacks::callbacks::{DownloadItemResult, ItemInstalled};
+use steamstacks::result::SteamResult;
+use steamstacks::{
+    steam_api, ItemState, PublishedFileId, SteamId, UgcQueryType, UgcType, UpdateStatus, UserList,
+    UserListOrder, Visibility, WorkshopFileType,
+};
+
+mod common;
+
+use common::{block_on, init};
+
+fn item(id: u64, owner: u64, title: &str, tags: &[&str]) -> FakeWorkshopItem {
+    let mut item = FakeWorkshopItem::new(PublishedFileId(id), SteamId::from(owner), title);
+    item.tags = tags.iter().map(|tag| tag.to_string()).collect();
+    item
+}
+
+fn titles(results: steamstacks::QueryResults) -> Vec<String> {
+    results.items.into_iter().map(|item| item.title).collect()
+}
+
+#[test]
+fn queries_filter_by_tags_text_and_user() {
+    let _guard = init(
+        FakeBackend::new()
+            .with_user(SteamId::from(1), "Player")
+            .with_workshop_item(item(10, 1, "Desert Map", &["map", "desert"]))
+            .with_workshop_item(item(11, 2, "Snow Map", &["map", "snow"]))
+            .with_workshop_item(item(12, 2, "Laser Rifle", &["weapon"])),
+    );
+    let ugc = steam_api::ugc();
+
+    let all = block_on(
+        ugc.query_all(UgcQueryType::RankedByVote, UgcType::Items)
+            .send(),
+    )
+    .unwrap();
+    assert_eq!(all.total_matching, 3);
+    assert_eq!(all.items[0].published_file_id, PublishedFileId(10));
+    assert_eq!(all.items[0].tags, ["map", "desert"]);
+
+    let maps = ugc
+        .query_all(UgcQueryType::RankedByVote, UgcType::Items)
+        .require_tag("map")
+        .exclude_tag("snow");
+    assert_eq!(titles(block_on(maps.send()).unwrap()), ["Desert Map"]);
+
+    let any = ugc
+        .query_all(UgcQueryType::RankedByTextSearch, UgcType::Items)
+        .require_tag("snow")
+        .require_tag("weapon")
+        .match_any_tag(true);
+    assert_eq!(
+        titles(block_on(any.send()).unwrap()),
+        ["Snow Map", "Laser Rifle"]
+    );
+
+    let searched = ugc
+        .query_all(UgcQueryType::RankedByTextSearch, UgcType::Items)
+        .search_text("rifle");
+    assert_eq!(titles(block_on(searched.send()).unwrap()), ["Laser Rifle"]);
+
+    let published = ugc.query_user(
+        SteamId::from(2),
+        UserList::Published,
+        UgcType::Items,
+        UserListOrder::CreationOrderDesc,
+    );
+    assert_eq!(
+        titles(block_on(published.send()).unwrap()),
+        ["Snow Map", "Laser Rifle"]
+    );
+
+    let second_page = ugc
+        .query_all(UgcQueryType::RankedByVote, UgcType::Items)
+        .page(2);
+    let second_page = block_on(second_page.send()).unwrap();
+    assert!(second_page.items.is_empty());
+    assert_eq!(second_page.total_matching, 3);
+
+    steam_api::shutdown();
+}
+
+#[test]
+fn query_handles_are_released_however_the_query_ends() {
+    let backend = FakeBackend::new().with_workshop_item(item(10, 1, "Desert Map", &["map"]));
+    let _guard = init(backend.clone());
+    let ugc = steam_api::ugc();
+    let query = || ugc.query_all(UgcQueryType::RankedByVote, UgcType::Items);
+
+    block_on(query().send()).unwrap();
+    assert_eq!(backend.open_ugc_queries(), 0);
+
+    let failed = query().send();
+    backend.truncate_queued_call_results(true);
+    assert_eq!(block_on(failed).err(), Some(SteamResult::IOFailure));
+    assert_eq!(backend.open_ugc_queries(), 0);
+
+    let abandoned = query().send();
+    assert_eq!(backend.open_ugc_queries(), 1);
+    drop(abandoned);
+    assert_eq!(backend.open_ugc_queries(), 0);
+    steam_api::run_callbacks();
+
+    steam_api::shutdown();
+}
+
+#[test]
+fn creates_and_updates_items() {
+    let _guard = init(FakeBackend::new().with_user(SteamId::from(1), "Player"));
+    let ugc = steam_api::ugc();
+
+    let created = block_on(ugc.create_item(WorkshopFileType::Community)).unwrap();
+    assert!(!created.needs_to_accept_agreement);
+    let id = created.published_file_id;
+
+    let update = ugc.start_item_update(id).unwrap();
+    update.set_title("Castle").unwrap();
+    update.set_description("A big castle").unwrap();
+    update.set_tags(&["map", "castle"]).unwrap();
+    update.set_visibility(Visibility::FriendsOnly).unwrap();
+    assert!(update.set_preview("missing.png".as_ref()).is_err());
+
+    let submission = update.submit(Some("First version"));
+    assert_eq!(
+        submission.progress().status,
+        UpdateStatus::CommittingChanges
+    );
+    let submitted = block_on(submission).unwrap();
+    assert_eq!(submitted.published_file_id, id);
+
+    let mine = ugc.query_user(
+        SteamId::from(1),
+        UserList::Published,
+        UgcType::Items,
+        UserListOrder::CreationOrderDesc,
+    );
+    let details = block_on(mine.send()).unwrap().items.remove(0);
+    assert_eq!(details.title, "Castle");
+    assert_eq!(details.description, "A big castle");
+    assert_eq!(details.tags, ["map", "castle"]);
+    assert_eq!(details.visibility, Visibility::FriendsOnly);
+    assert_eq!(details.owner, SteamId::from(1));
+
+    assert!(ugc.start_item_update(PublishedFileId(999)).is_err());
+
+    steam_api::shutdown();
+}
+
+#[test]
+fn subscribes_and_downloads_items() {
+    let _guard = init(FakeBackend::new().with_workshop_item(item(10, 2, "Desert Map", &[])));
+    let ugc = steam_api::ugc();
+    let id = PublishedFileId(10);
+
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let _downloaded = {
+        let events = events.clone();
+        steam_api::register_callback(move |downloaded: DownloadItemResult| {
+            events
+                .lock()
+                .unwrap()
+                .push(("downloaded", downloaded.published_file_id));
+        })
+    };
+    let _installed = {
+        let events = events.clone();
+        steam_api::register_callback(move |installed: ItemInstalled| {
+            events
+                .lock()
+                .unwrap()
+                .push(("installed", installed.published_file_id));
+        })
+    };
+
+    assert!(ugc.download_item(id, true).is_err());
+    assert_eq!(
+        block_on(ugc.subscribe_item(PublishedFileId(11))),
+        Err(SteamResult::FileNotFound)
+    );
+
+    block_on(ugc.subscribe_item(id)).unwrap();
+    assert_eq!(ugc.subscribed_items(), [id]);
+    assert_eq!(ugc.item_state(id), ItemState::SUBSCRIBED);
+    assert_eq!(ugc.item_install_info(id), None);
+
+    ugc.download_item(id, true).unwrap();
+    steam_api::run_callbacks();
+    assert_eq!(
+        *events.lock().unwrap(),
+        [("downloaded", id), ("installed", id)]
+    );
+    assert_eq!(
+        ugc.item_state(id),
+        ItemState::SUBSCRIBED | ItemState::INSTALLED
+    );
+    assert!(ugc.item_install_info(id).unwrap().folder.ends_with("10"));
+
+    block_on(ugc.unsubscribe_item(id)).unwrap();
+    assert!(ugc.subscribed_items().is_empty());
+
+    steam_api::shutdown();
+}
@@ -0,0 +1,212 @@
+use std::sync::{Arc, Mutex};
+
+use steamstacks::backend::{FakeBackend, FakeFriend};
+use steamstacks::callbacks::{UserAchievementStored, UserStatsReceived, UserStatsStored};
+use steamstacks::result::SteamResult;
+use steamstacks::{
+    steam_api, LeaderboardDisplayType, LeaderboardEntry, LeaderboardRange, LeaderboardSortMethod,
+    SteamId, UploadScoreMethod, MAX_LEADERBOARD_DETAILS,
+};
+
+mod common;
+
+use common::{block_on, init};
+
+#[test]
+fn reads_and_writes_typed_stats() {
+    let _guard = init(
+        FakeBackend::new()
+            .with_stat_i32("kills", 3)
+            .with_stat_f32("distance", 1.5),
+    );
+    let stats = steam_api::user_stats();
+
+    assert_eq!(stats.get_stat_i32("kills"), Ok(3));
+    assert_eq!(stats.get_stat_f32("distance"), Ok(1.5));
+
+    stats.set_stat_i32("kills", 4).unwrap();
+    stats.set_stat_f32("distance", 2.25).unwrap();
+    assert_eq!(stats.get_stat_i32("kills"), Ok(4));
+    assert_eq!(stats.get_stat_f32("distance"), Ok(2.25));
+
+    assert_eq!(stats.get_stat_f32("kills"), Err(SteamResult::Generic));
+    assert_eq!(stats.set_stat_i32("deaths", 1), Err(SteamResult::Generic));
+
+    steam_api::shutdown();
+}
+
+#[test]
+fn request_current_stats_sends_received_callback() {
+    let _guard = init(FakeBackend::new().with_user(SteamId::from(7), "Player"));
+
+    let received = Arc::new(Mutex::new(None));
+    let _handle = {
+        let received = received.clone();
+        steam_api::register_callback(move |stats: UserStatsReceived| {
+            *received.lock().unwrap() = Some((stats.result, stats.steam_id, stats.game_id));
+        })
+    };
+
+    steam_api::user_stats().request_current_stats().unwrap();
+    steam_api::run_callbacks();
+    assert_eq!(
+        *received.lock().unwrap(),
+        Some((SteamResult::Ok, SteamId::from(7), 480))
+    );
+
+    steam_api::shutdown();
+}
+
+#[test]
+fn unlocked_achievements_are_reported_when_stored() {
+    let _guard = init(
+        FakeBackend::new()
+            .with_achievement("FIRST_BLOOD", false)
+            .with_achievement("WIN_ONE", false),
+    );
+    let stats = steam_api::user_stats();
+
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let _stored = {
+        let log = log.clone();
+        steam_api::register_callback(move |stored: UserStatsStored| {
+            log.lock()
+                .unwrap()
+                .push(format!("stored {:?}", stored.result));
+        })
+    };
+    let _achievement = {
+        let log = log.clone();
+        steam_api::register_callback(move |achievement: UserAchievementStored| {
+            log.lock().unwrap().push(format!(
+                "{} {}/{}",
+                achievement.achievement_name,
+                achievement.current_progress,
+                achievement.max_progress
+            ));
+        })
+    };
+
+    stats
+        .indicate_achievement_progress("WIN_ONE", 1, 2)
+        .unwrap();
+    stats.set_achievement("FIRST_BLOOD").unwrap();
+    assert_eq!(stats.get_achievement("FIRST_BLOOD"), Ok(true));
+    assert_eq!(stats.get_achievement("WIN_ONE"), Ok(false));
+    stats.store_stats().unwrap();
+    steam_api::run_callbacks();
+
+    assert_eq!(
+        *log.lock().unwrap(),
+        ["WIN_ONE 1/2", "stored Ok", "FIRST_BLOOD 0/0"]
+    );
+
+    stats.clear_achievement("FIRST_BLOOD").unwrap();
+    assert_eq!(stats.get_achievement("FIRST_BLOOD"), Ok(false));
+    assert!(stats.set_achievement("MISSING").is_err());
+
+    steam_api::shutdown();
+}
+
+#[test]
+fn uploads_scores_with_keep_best_and_force_update() {
+    let _guard = init(FakeBackend::new().with_user(SteamId::from(1), "Player"));
+    let stats = steam_api::user_stats();
+
+    let board = block_on(stats.find_or_create_leaderboard(
+        "Fastest",
+        LeaderboardSortMethod::Ascending,
+        LeaderboardDisplayType::TimeMilliSeconds,
+    ))
+    .unwrap();
+
+    let first =
+        block_on(stats.upload_leaderboard_score(board, UploadScoreMethod::KeepBest, 900, &[1, 2]))
+            .unwrap();
+    assert!(first.score_changed);
+    assert_eq!((first.global_rank_previous, first.global_rank_new), (0, 1));
+
+    let slower =
+        block_on(stats.upload_leaderboard_score(board, UploadScoreMethod::KeepBest, 950, &[]))
+            .unwrap();
+    assert!(!slower.score_changed);
+
+    let forced =
+        block_on(stats.upload_leaderboard_score(board, UploadScoreMethod::ForceUpdate, 950, &[]))
+            .unwrap();
+    assert!(forced.score_changed);
+
+    let too_many = [0; MAX_LEADERBOARD_DETAILS + 1];
+    assert_eq!(
+        block_on(stats.upload_leaderboard_score(
+            board,
+            UploadScoreMethod::ForceUpdate,
+            100,
+            &too_many
+        ))
+        .map(|uploaded| uploaded.score_changed),
+        Err(SteamResult::InvalidParameter)
+    );
+
+    let entries =
+        block_on(stats.download_leaderboard_entries(board, LeaderboardRange::Friends)).unwrap();
+    assert_eq!(
+        entries,
+        [LeaderboardEntry {
+            steam_id: SteamId::from(1),
+            global_rank: 1,
+            score: 950,
+            details: Vec::new(),
+        }]
+    );
+
+    steam_api::shutdown();
+}
+
+#[test]
+fn downloads_entry_ranges() {
+    let me = SteamId::from(1);
+    let friend = SteamId::from(2);
+    let _guard = init(
+        FakeBackend::new()
+            .with_user(me, "Player")
+            .with_friend(FakeFriend::new(friend, "Friend"))
+            .with_leaderboard_score("Highscores", SteamId::from(3), 500)
+            .with_leaderboard_score("Highscores", friend, 400)
+            .with_leaderboard_score("Highscores", SteamId::from(4), 300)
+            .with_leaderboard_score("Highscores", SteamId::from(5), 100),
+    );
+    let stats = steam_api::user_stats();
+
+    let board = block_on(stats.find_or_create_leaderboard(
+        "Highscores",
+        LeaderboardSortMethod::Descending,
+        LeaderboardDisplayType::Numeric,
+    ))
+    .unwrap();
+    block_on(stats.upload_leaderboard_score(board, UploadScoreMethod::KeepBest, 200, &[7]))
+        .unwrap();
+
+    let ranks = |range| {
+        block_on(stats.download_leaderboard_entries(board, range))
+            .unwrap()
+            .into_iter()
+            .map(|entry| (entry.global_rank, entry.steam_id))
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        ranks(LeaderboardRange::Global { start: 1, end: 2 }),
+        [(1, SteamId::from(3)), (2, friend)]
+    );
+    assert_eq!(
+        ranks(LeaderboardRange::AroundUser {
+            before: 1,
+            after: 1
+        }),
+        [(3, SteamId::from(4)), (4, me), (5, SteamId::from(5))]
+    );
+    assert_eq!(ranks(LeaderboardRange::Friends), [(2, friend), (4, me)]);
+
+    steam_api::shutdown();
+}
@@ -0,0 +1,209 @@
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, UNIX_EPOCH};
+
+use steamstacks::backend::{FakeBackend, FakeEnvironment, FakeTextInput};
+use steamstacks::callbacks::{AvatarImageLoaded, GamepadTextInputDismissed};
+use steamstacks::result::SteamResult;
+use steamstacks::{
+    steam_api, AppId, FloatingGamepadTextInputMode, GamepadTextInputLineMode, GamepadTextInputMode,
+    Image, Ipv6ConnectivityProtocol, Ipv6ConnectivityState, SteamId, TextFieldRect, Universe,
+};
+
+mod common;
+
+use common::{block_on, init};
+
+#[test]
+fn images_are_read_at_their_reported_size() {
+    let friend = SteamId::from(2);
+    let backend = FakeBackend::new().with_loading_avatar(friend);
+    let _guard = init(backend.clone());
+    let utils = steam_api::utils();
+
+    let handles = Arc::new(Mutex::new(Vec::new()));
+    let _handle = {
+        let handles = handles.clone();
+        steam_api::register_callback(move |avatar: AvatarImageLoaded| {
+            handles.lock().unwrap().push(avatar.image);
+        })
+    };
+
+    let image = Image {
+        width: 3,
+        height: 2,
+        rgba: (0..24).collect(),
+    };
+    backend.queue_avatar_loaded(friend, image.clone());
+    steam_api::run_callbacks();
+
+    let handle = handles.lock().unwrap()[0];
+    assert_eq!(utils.get_image_size(handle), Some((3, 2)));
+    assert_eq!(utils.get_image(handle), Ok(image));
+
+    assert_eq!(utils.get_image_size(0), None);
+    assert_eq!(utils.get_image(0), Err(SteamResult::InvalidParameter));
+    assert_eq!(utils.get_image(-1), Err(SteamResult::InvalidParameter));
+
+    steam_api::shutdown();
+}
+
+#[test]
+fn environment_is_reported_as_configured() {
+    let server_time = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+    let backend = FakeBackend::new().with_environment(FakeEnvironment {
+        server_time: Some(server_time),
+        ip_country: String::from("DE"),
+        ui_language: String::from("german"),
+        universe: Universe::Beta,
+        seconds_since_app_active: 12,
+        seconds_since_computer_active: 34,
+        battery_power: Some(80),
+        steam_deck: true,
+        big_picture: true,
+        ipv6_udp: Ipv6ConnectivityState::Bad,
+        ..Default::default()
+    });
+    let _guard = init(backend);
+    let utils = steam_api::utils();
+
+    assert_eq!(utils.get_server_real_time(), server_time);
+    assert_eq!(utils.get_ip_country(), "DE");
+    assert_eq!(utils.get_steam_ui_language(), "german");
+    assert_eq!(utils.get_connected_universe(), Universe::Beta);
+    assert_eq!(utils.get_seconds_since_app_active(), 12);
+    assert_eq!(utils.get_seconds_since_computer_active(), 34);
+    assert_eq!(utils.get_current_battery_power(), Some(80));
+    assert!(utils.is_steam_running_on_steam_deck());
+    assert!(utils.is_steam_in_big_picture_mode());
+    assert!(!utils.is_steam_running_in_vr());
+    assert!(!utils.is_steam_china_launcher());
+    assert_eq!(
+        utils.get_ipv6_connectivity_state(Ipv6ConnectivityProtocol::Http),
+        Ipv6ConnectivityState::Unknown
+    );
+    assert_eq!(
+        utils.get_ipv6_connectivity_state(Ipv6ConnectivityProtocol::Udp),
+        Ipv6ConnectivityState::Bad
+    );
+
+    steam_api::shutdown();
+}
+
+#[test]
+fn battery_power_is_none_on_ac_power() {
+    let _guard = init(FakeBackend::new());
+
+    assert_eq!(steam_api::utils().get_current_battery_power(), None);
+
+    steam_api::shutdown();
+}
+
+#[test]
+fn gamepad_text_is_read_after_the_keyboard_closes() {
+    let backend = FakeBackend::new();
+    let _guard = init(backend.clone());
+    let utils = steam_api::utils();
+
+    let dismissals = Arc::new(Mutex::new(Vec::new()));
+    let _handle = {
+        let dismissals = dismissals.clone();
+        steam_api::register_callback(move |input: GamepadTextInputDismissed| {
+            dismissals.lock().unwrap().push(input);
+        })
+    };
+
+    assert!(utils.show_gamepad_text_input(
+        GamepadTextInputMode::Password,
+        GamepadTextInputLineMode::SingleLine,
+        "Password",
+        8,
+        "hunter",
+    ));
+    assert_eq!(
+        backend.text_input(),
+        Some(FakeTextInput {
+            mode: GamepadTextInputMode::Password,
+            line_mode: GamepadTextInputLineMode::SingleLine,
+            description: String::from("Password"),
+            max_chars: 8,
+            existing: String::from("hunter"),
+        })
+    );
+    // Only one keyboard can be open at a time
+    assert!(!utils.show_gamepad_text_input(
+        GamepadTextInputMode::Normal,
+        GamepadTextInputLineMode::SingleLine,
+        "",
+        8,
+        "",
+    ));
+
+    backend.queue_text_input_submitted("hunter2isgood");
+    steam_api::run_callbacks();
+
+    let input = dismissals.lock().unwrap().remove(0);
+    assert!(input.submitted);
+    assert_eq!(input.text_len, 8);
+    assert_eq!(input.app_id, AppId(480));
+    assert_eq!(
+        utils.get_entered_gamepad_text().as_deref(),
+        Some("hunter2i")
+    );
+    assert_eq!(backend.text_input(), None);
+
+    steam_api::shutdown();
+}
+
+#[test]
+fn text_input_requests_resolve_when_dismissed() {
+    let backend = FakeBackend::new();
+    let _guard = init(backend.clone());
+    let utils = steam_api::utils();
+
+    let request = utils.request_text_input(
+        GamepadTextInputMode::Normal,
+        GamepadTextInputLineMode::MultipleLines,
+        "Message",
+        256,
+        "",
+    );
+    backend.queue_text_input_submitted("gg wp");
+    assert_eq!(block_on(request), Some(String::from("gg wp")));
+
+    let request = utils.request_text_input(
+        GamepadTextInputMode::Normal,
+        GamepadTextInputLineMode::SingleLine,
+        "Name",
+        32,
+        "",
+    );
+    backend.queue_text_input_cancelled();
+    assert_eq!(block_on(request), None);
+
+    steam_api::shutdown();
+}
+
+#[test]
+fn floating_keyboard_is_shown_and_dismissed() {
+    let backend = FakeBackend::new();
+    let _guard = init(backend.clone());
+    let utils = steam_api::utils();
+
+    let field = TextFieldRect {
+        x: 100,
+        y: 200,
+        width: 300,
+        height: 40,
+    };
+    assert!(utils.show_floating_gamepad_text_input(FloatingGamepadTextInputMode::Email, field));
+    assert_eq!(
+        backend.floating_text_input(),
+        Some((FloatingGamepadTextInputMode::Email, field))
+    );
+
+    assert!(utils.dismiss_floating_gamepad_text_input());
+    assert_eq!(backend.floating_text_input(), None);
+    assert!(!utils.dismiss_floating_gamepad_text_input());
+
+    steam_api::shutdown();
+}
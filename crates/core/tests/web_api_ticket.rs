@@ -0,0 +1,43 @@
+#![cfg(feature = "web-api-ticket")]
+
+use std::future::Future;
+use std::task::{Context, Waker};
+
+use steamstacks::backend::FakeBackend;
+use steamstacks::steam_api;
+
+mod common;
+
+use common::{block_on, init};
+
+#[test]
+fn web_api_tickets_resolve_with_their_data() {
+    let backend = FakeBackend::new();
+    let _guard = init(backend.clone());
+    let user = steam_api::user();
+
+    let ticket = block_on(user.get_auth_ticket_for_web_api("my-backend")).unwrap();
+    assert!(ticket.data().ends_with(b"my-backend"));
+    assert_eq!(backend.auth_tickets(), [ticket.handle()]);
+
+    drop(ticket);
+    assert!(backend.auth_tickets().is_empty());
+
+    steam_api::shutdown();
+}
+
+#[test]
+fn web_api_tickets_are_cancelled_when_the_request_is_dropped() {
+    let backend = FakeBackend::new();
+    let _guard = init(backend.clone());
+
+    let mut request = Box::pin(steam_api::user().get_auth_ticket_for_web_api("my-backend"));
+    let mut cx = Context::from_waker(Waker::noop());
+    assert!(request.as_mut().poll(&mut cx).is_pending());
+    assert_eq!(backend.auth_tickets().len(), 1);
+
+    drop(request);
+    assert!(backend.auth_tickets().is_empty());
+
+    steam_api::shutdown();
+}
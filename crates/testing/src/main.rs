@@ -1,4 +1,9 @@
-use steamstacks::{callbacks::EncryptedAppTicketResponse, result::SteamResult, steam_api};
+use std::future::Future;
+use std::pin::pin;
+use std::task::{Context, Poll, Waker};
+use std::time::Duration;
+
+use steamstacks::steam_api;
 
 fn main() {
     steam_api::init().unwrap();
@@ -8,18 +13,10 @@ fn main() {
     let apps = steam_api::apps();
     // let utils = steam_api::utils();
 
-    let api_call = user.request_encrypted_app_ticket();
+    // Writes the ticket there, e.g. to refresh the core crate's test fixture
+    let ticket_path = std::env::args().nth(1);
 
-    steam_api::register_call_result::<EncryptedAppTicketResponse, _>(api_call, move |r, _| {
-        let result: SteamResult = r.result.into();
-        match result {
-            SteamResult::Ok => {
-                let ticket = user.get_encrypted_app_ticket().unwrap();
-                println!("Ticket: {:?}", ticket);
-            }
-            _ => println!("Error: {:?}", result),
-        }
-    });
+    let mut ticket_request = pin!(user.request_encrypted_app_ticket(b"steamstacks fixture"));
 
     // println!("{}", utils.get_app_id());
 
@@ -31,8 +28,25 @@ fn main() {
     println!("Owns Subnautica: {}", apps.is_subscribed_app(848450.into()));
     println!("Owns Rust: {}", apps.is_subscribed_app(252490.into()));
 
+    let mut cx = Context::from_waker(Waker::noop());
     loop {
         steam_api::run_callbacks();
+
+        if let Poll::Ready(result) = ticket_request.as_mut().poll(&mut cx) {
+            match result {
+                Ok(()) => {
+                    let ticket = user.get_encrypted_app_ticket().unwrap();
+                    println!("Ticket: {:?}", ticket);
+                    if let Some(path) = &ticket_path {
+                        std::fs::write(path, &ticket).unwrap();
+                    }
+                }
+                Err(err) => println!("Error: {:?}", err),
+            }
+            break;
+        }
+
+        std::thread::sleep(Duration::from_millis(16));
     }
 
     steam_api::shutdown();